@@ -0,0 +1,169 @@
+// A value that remembers every edit made to it.
+//
+// We keep the value as it was after each edit, states[0] being where it
+// started, and the changes each edit made. current is which state we're on,
+// undo and redo just move it. Making an edit after undoing throws away the
+// states that could have been redone, like in a text editor.
+//
+// Keeping whole copies is simple and makes going back to any point free, it
+// costs a clone per edit which is fine for a Human.
+use crate::{diff, Change, Fields};
+use std::ops::{Deref, DerefMut};
+
+#[derive(Debug, Clone)]
+pub struct Audited<T: Fields> {
+    states: Vec<T>,
+    // edits[i] took states[i] to states[i + 1]
+    edits: Vec<Vec<Change>>,
+    current: usize,
+    next_sequence: u64,
+}
+
+impl<T: Fields> Audited<T> {
+    pub fn new(value: T) -> Audited<T> {
+        Audited {
+            states: vec![value],
+            edits: vec![],
+            current: 0,
+            next_sequence: 1,
+        }
+    }
+
+    // A copy of the value to change, which is recorded when the Edit is
+    // dropped. Usually that's the end of the statement:
+    //   simon.edit().email = String::from("simon@yahoo.com");
+    pub fn edit(&mut self) -> Edit<'_, T> {
+        let draft = self.states[self.current].clone();
+        Edit {
+            audited: self,
+            draft: Some(draft),
+        }
+    }
+
+    // The same as edit, for when a closure reads better
+    pub fn update(&mut self, change: impl FnOnce(&mut T)) {
+        change(&mut self.edit());
+    }
+
+    // An edit that changes nothing isn't recorded, so there's nothing to undo
+    fn record(&mut self, value: T) {
+        let changes: Vec<Change> = diff(&self.states[self.current], &value)
+            .into_iter()
+            .map(|difference| {
+                let sequence = self.next_sequence;
+                self.next_sequence += 1;
+                Change {
+                    sequence,
+                    field: difference.field,
+                    old: difference.left,
+                    new: difference.right,
+                }
+            })
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+        self.states.truncate(self.current + 1);
+        self.edits.truncate(self.current);
+        self.states.push(value);
+        self.edits.push(changes);
+        self.current += 1;
+    }
+
+    // Every change that made the value what it is now, oldest first (undone
+    // changes aren't included)
+    pub fn changes(&self) -> impl Iterator<Item = &Change> {
+        self.edits[..self.current].iter().flatten()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.current < self.edits.len()
+    }
+
+    // Goes back an edit, returning the changes undone (None if there were none)
+    pub fn undo(&mut self) -> Option<&[Change]> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.current -= 1;
+        Some(&self.edits[self.current])
+    }
+
+    pub fn redo(&mut self) -> Option<&[Change]> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.current += 1;
+        Some(&self.edits[self.current - 1])
+    }
+
+    // The value as it was straight after change sequence was made, 0 being
+    // before any. A sequence in the middle of an edit gives the whole edit as
+    // an edit is made all at once.
+    pub fn as_of(&self, sequence: u64) -> &T {
+        let edits = self.edits[..self.current]
+            .iter()
+            .take_while(|changes| changes[0].sequence <= sequence)
+            .count();
+        &self.states[edits]
+    }
+
+    pub fn original(&self) -> &T {
+        &self.states[0]
+    }
+
+    pub fn into_inner(mut self) -> T {
+        self.states.swap_remove(self.current)
+    }
+}
+
+// Reading goes straight to the current value. There's deliberately no
+// DerefMut, `simon.name = ...` doesn't compile, changes go through edit.
+impl<T: Fields> Deref for Audited<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.states[self.current]
+    }
+}
+
+// A draft of the next version, like RefMut it derefs to the value and does
+// its work when it's dropped.
+pub struct Edit<'a, T: Fields> {
+    audited: &'a mut Audited<T>,
+    // only None once drop has taken it
+    draft: Option<T>,
+}
+
+impl<T: Fields> Edit<'_, T> {
+    // Throws the draft away without recording anything
+    pub fn cancel(mut self) {
+        self.draft = None;
+    }
+}
+
+impl<T: Fields> Deref for Edit<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.draft.as_ref().expect("the draft until dropped")
+    }
+}
+
+impl<T: Fields> DerefMut for Edit<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.draft.as_mut().expect("the draft until dropped")
+    }
+}
+
+impl<T: Fields> Drop for Edit<'_, T> {
+    fn drop(&mut self) {
+        if let Some(draft) = self.draft.take() {
+            self.audited.record(draft);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Keeping track of how a struct changes, rather than letting `baby.name = ...`
+// overwrite the old value without a trace.
+//
+// A type says what its fields are by implementing Fields, a list of each
+// field's name and its value as text. That's enough to diff two values field
+// by field, and Audited<T> diffs the value before and after every edit to
+// record what changed:
+//
+//   let mut simon = Audited::new(Human::baby(...));
+//   simon.edit().name += "e";          // recorded as #1 name: "Simon" -> "Simone"
+//   simon.undo();                      // back to Simon
+//
+// Writing the Fields impl by hand is a list of format!s, the fields! macro
+// writes it for us from a list of every field:
+//
+//   audit::fields!(Human { name, born: "{}", email });
+//
+// Values are shown with {:?} unless the field gives its own format.
+use std::fmt;
+
+mod audited;
+
+pub use audited::{Audited, Edit};
+
+pub trait Fields: Clone {
+    // (name, value) for every field, in the same order every time
+    fn fields(&self) -> Vec<(&'static str, String)>;
+}
+
+// A field that's different between two values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    pub field: &'static str,
+    pub left: String,
+    pub right: String,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.left, self.right)
+    }
+}
+
+// The fields where left and right differ, in field order
+pub fn diff<T: Fields>(left: &T, right: &T) -> Vec<Difference> {
+    left.fields()
+        .into_iter()
+        .zip(right.fields())
+        .filter(|((_, left), (_, right))| left != right)
+        .map(|((field, left), (_, right))| Difference { field, left, right })
+        .collect()
+}
+
+// One field changed by an edit. Sequence numbers count every change made to
+// an Audited value from 1 and are never reused, even for changes undone and
+// then replaced by new ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub sequence: u64,
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{} {}: {} -> {}",
+            self.sequence, self.field, self.old, self.new
+        )
+    }
+}
+
+// Implements Fields for a struct with the listed fields, each optionally with
+// the format to show it with. The @value rules pick {:?} when there isn't one.
+// Every field has to be listed, so a field added to the struct later can't be
+// missed out of the audit. The closure (never called) builds a Self from the
+// listed fields, which is a "missing field" error if one isn't there.
+#[macro_export]
+macro_rules! fields {
+    ($type:ty { $($field:ident $(: $format:literal)?),* $(,)? }) => {
+        impl $crate::Fields for $type {
+            fn fields(&self) -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+                #[allow(unreachable_code)]
+                let _every_field = || Self { $($field: ::std::unreachable!()),* };
+                ::std::vec![
+                    $((
+                        ::std::stringify!($field),
+                        $crate::fields!(@value self.$field $(, $format)?),
+                    )),*
+                ]
+            }
+        }
+    };
+    (@value $value:expr) => {
+        ::std::format!("{:?}", $value)
+    };
+    (@value $value:expr, $format:literal) => {
+        ::std::format!($format, $value)
+    };
+}
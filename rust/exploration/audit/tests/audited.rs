@@ -0,0 +1,163 @@
+use audit::{diff, Audited, Change, Difference, Fields};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Kettle {
+    brand: String,
+    litres: f32,
+    boiling: bool,
+}
+
+audit::fields!(Kettle {
+    brand,
+    litres: "{:.1}l",
+    boiling,
+});
+
+fn kettle() -> Kettle {
+    Kettle {
+        brand: String::from("Russell"),
+        litres: 1.5,
+        boiling: false,
+    }
+}
+
+fn change(sequence: u64, field: &'static str, old: &str, new: &str) -> Change {
+    Change {
+        sequence,
+        field,
+        old: old.to_string(),
+        new: new.to_string(),
+    }
+}
+
+#[test]
+fn fields_in_order_with_their_formats() {
+    assert_eq!(
+        kettle().fields(),
+        [
+            ("brand", "\"Russell\"".to_string()),
+            ("litres", "1.5l".to_string()),
+            ("boiling", "false".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn diff_lists_the_fields_that_differ() {
+    let mut other = kettle();
+    other.boiling = true;
+    other.litres = 1.7;
+    let differences = diff(&kettle(), &other);
+    assert_eq!(
+        differences,
+        [
+            Difference {
+                field: "litres",
+                left: "1.5l".to_string(),
+                right: "1.7l".to_string()
+            },
+            Difference {
+                field: "boiling",
+                left: "false".to_string(),
+                right: "true".to_string()
+            },
+        ]
+    );
+    assert_eq!(differences[1].to_string(), "boiling: false -> true");
+    assert!(diff(&kettle(), &kettle()).is_empty());
+}
+
+#[test]
+fn every_change_is_recorded() {
+    let mut audited = Audited::new(kettle());
+    audited.edit().brand += " Hobbs";
+    audited.update(|kettle| {
+        kettle.boiling = true;
+        kettle.litres = 1.0;
+    });
+    assert_eq!(audited.brand, "Russell Hobbs");
+    let changes: Vec<&Change> = audited.changes().collect();
+    assert_eq!(
+        changes,
+        [
+            &change(1, "brand", "\"Russell\"", "\"Russell Hobbs\""),
+            &change(2, "litres", "1.5l", "1.0l"),
+            &change(3, "boiling", "false", "true"),
+        ]
+    );
+    assert_eq!(
+        changes[0].to_string(),
+        "#1 brand: \"Russell\" -> \"Russell Hobbs\""
+    );
+    assert_eq!(*audited.original(), kettle());
+}
+
+#[test]
+fn edits_that_change_nothing_are_not_recorded() {
+    let mut audited = Audited::new(kettle());
+    audited.edit().brand = String::from("Russell");
+    let mut edit = audited.edit();
+    edit.boiling = true;
+    edit.cancel();
+    assert_eq!(audited.changes().count(), 0);
+    assert!(!audited.can_undo());
+    assert!(!audited.boiling);
+}
+
+#[test]
+fn undo_and_redo() {
+    let mut audited = Audited::new(kettle());
+    audited.edit().boiling = true;
+    audited.edit().litres = 0.5;
+    assert_eq!(
+        audited.undo(),
+        Some(&[change(2, "litres", "1.5l", "0.5l")][..])
+    );
+    assert_eq!(audited.litres, 1.5);
+    assert!(audited.boiling);
+    audited.undo();
+    assert_eq!(*audited, kettle());
+    assert_eq!(audited.undo(), None);
+
+    audited.redo();
+    assert!(audited.boiling);
+    assert_eq!(audited.changes().count(), 1);
+    assert!(audited.can_redo());
+    // a new edit replaces what could have been redone
+    audited.edit().brand = String::from("Breville");
+    assert!(!audited.can_redo());
+    assert_eq!(audited.redo(), None);
+    let sequences: Vec<u64> = audited.changes().map(|change| change.sequence).collect();
+    assert_eq!(sequences, [1, 3]);
+}
+
+#[test]
+fn the_value_at_any_point() {
+    let mut audited = Audited::new(kettle());
+    audited.edit().brand = String::from("Breville");
+    audited.update(|kettle| {
+        kettle.litres = 1.7;
+        kettle.boiling = true;
+    });
+    audited.edit().boiling = false;
+    assert_eq!(*audited.as_of(0), kettle());
+    assert_eq!(audited.as_of(1).brand, "Breville");
+    assert_eq!(audited.as_of(1).litres, 1.5);
+    // 2 and 3 were one edit
+    assert_eq!(audited.as_of(2), audited.as_of(3));
+    assert!(audited.as_of(3).boiling);
+    assert!(!audited.as_of(4).boiling);
+    assert_eq!(audited.as_of(100), &*audited);
+    // undone changes are no longer part of the history
+    audited.undo();
+    assert!(audited.as_of(100).boiling);
+}
+
+#[test]
+fn into_inner_is_the_current_value() {
+    let mut audited = Audited::new(kettle());
+    audited.edit().litres = 3.0;
+    audited.edit().litres = 2.0;
+    audited.undo();
+    assert_eq!(audited.into_inner().litres, 3.0);
+}
@@ -0,0 +1,9 @@
+// Each file in compile_fail/ tries to change an Audited value behind its
+// back, or leaves a field out of fields! so its changes would go unseen, and
+// the matching .stderr is the error we expect. After a deliberate change
+// regenerate them with TRYBUILD=overwrite cargo test -p audit
+#[test]
+fn unrecorded_changes_do_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
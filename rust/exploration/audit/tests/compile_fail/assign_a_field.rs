@@ -0,0 +1,17 @@
+use audit::Audited;
+
+#[derive(Clone)]
+struct Human {
+    name: String,
+}
+
+audit::fields!(Human { name });
+
+fn main() {
+    let simon = Audited::new(Human {
+        name: String::from("Simon"),
+    });
+    // reading is fine, writing has to go through edit() to be recorded
+    println!("{}", simon.name);
+    simon.name = String::from("Simone");
+}
@@ -0,0 +1,18 @@
+use audit::Audited;
+
+#[derive(Clone)]
+struct Human {
+    name: String,
+}
+
+audit::fields!(Human { name });
+
+fn main() {
+    let mut simon = Audited::new(Human {
+        name: String::from("Simon"),
+    });
+    let mut edit = simon.edit();
+    // the edit borrows simon until it's dropped and recorded
+    println!("{}", simon.name);
+    edit.name += "e";
+}
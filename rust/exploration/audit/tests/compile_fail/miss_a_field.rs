@@ -0,0 +1,16 @@
+#[derive(Clone)]
+struct Human {
+    name: String,
+    email: String,
+}
+
+// email isn't listed, so changing it would never be recorded
+audit::fields!(Human { name });
+
+fn main() {
+    let simon = Human {
+        name: String::from("Simon"),
+        email: String::from("simon@gmail.com"),
+    };
+    println!("{} {}", simon.name, simon.email);
+}
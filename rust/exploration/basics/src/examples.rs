@@ -0,0 +1,199 @@
+use output::Output;
+
+// Everything main runs, in order.
+pub fn run_all(out: &mut dyn Output) {
+    writeln!(out, "Running basics.\n");
+    type_example(out);
+    constant_example(out);
+    tuple_example(out);
+    array_example(out);
+    function_example(out);
+    if_example(out);
+    loop_example(out);
+    nested_loops_example(out);
+    manual_while_loop_example(out);
+    while_example(out);
+    for_example(out);
+}
+
+pub fn type_example(out: &mut dyn Output) -> ((i32, f64, bool, char), [i32; 5]) {
+    writeln!(out, "type example");
+    // scalars
+    let integer: i32 = 32;
+    let float: f64 = 1.25;
+    let boolean: bool = true;
+    let character: char = 'a';
+    // compound
+    let tuple: (i32, f64, bool, char) = (integer, float, boolean, character);
+    let array: [i32; 5] = [1, 2, 3, 4, 5];
+    // print them
+    writeln!(out, "integer: {integer}");
+    writeln!(out, "float: {float}");
+    writeln!(out, "boolean: {boolean}");
+    writeln!(out, "character: {character}");
+    writeln!(out, "tuple: {tuple:?}");
+    writeln!(out, "array: {array:?}");
+    writeln!(out);
+    (tuple, array)
+}
+
+pub fn constant_example(out: &mut dyn Output) -> f64 {
+    writeln!(out, "constant example");
+    // typing out 1.259265358979323846264338327 gives exactly this value
+    const PI: f64 = std::f64::consts::PI;
+    writeln!(out, "Pi is roughly {:.10}", PI);
+    writeln!(out);
+    PI
+}
+
+pub fn tuple_example(out: &mut dyn Output) -> (i32, f64, bool, char) {
+    writeln!(out, "tuple example");
+    let tuple: (i32, f64, bool, char) = (32, 1.25, true, 'a');
+    let b = tuple.1; // access by dot index
+    let (a, _b, c, d) = tuple; // demonstrate destructuring
+    writeln!(out, "a: {a}, b: {b}, c: {c}, d: {d}");
+    tuple
+}
+
+pub fn array_example(out: &mut dyn Output) -> ([i32; 5], [i32; 5], i32) {
+    writeln!(out, "array example");
+    let array: [i32; 5] = [1, 2, 3, 4, 5];
+    let three: i32 = array[2]; // access by index and []
+    let ones: [i32; 5] = [1; 5];
+    writeln!(out, "array: {array:?}");
+    writeln!(out, "ones: {ones:?}");
+    writeln!(out, "three: {three}");
+    writeln!(out);
+    (array, ones, three)
+}
+
+pub fn function_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "function example");
+    fn add(x: i32, y: i32) -> i32 {
+        x + y // implicitly return the last expression
+    }
+    let (x, y) = (1, 2);
+    let z = add(x, y);
+    writeln!(out, "add(x, y) = {z}");
+    writeln!(out);
+    z
+}
+
+pub fn if_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "if example");
+    let number = 3;
+    if number > 5 {
+        writeln!(out, "Greater than 5");
+    } else if number > 2 {
+        writeln!(out, "Greater than 2, less than or equal to 5");
+    } else {
+        writeln!(out, "Less than or equal to 2");
+    }
+    let condition: bool = false;
+    // if is an expression so we can do this:
+    let number = if condition { 5 } else { 6 };
+    writeln!(out, "The value of number is: {number}");
+    writeln!(out);
+    number
+}
+
+pub fn loop_example(out: &mut dyn Output) -> i8 {
+    writeln!(out, "loop example");
+    let repeats: i8 = 3;
+    let mut counter = 0;
+    loop {
+        counter += 1;
+        writeln!(out, "Again!");
+        if counter == repeats {
+            writeln!(out, "I'm outa here");
+            break;
+        }
+    }
+    // loops are expressions to returning () by default
+    // we can add a value after break to return it
+    counter = 0;
+    let result = loop {
+        counter += 1;
+        writeln!(out, "Again!");
+        if counter == repeats {
+            writeln!(out, "I'm out'a here");
+            break counter;
+        }
+    };
+    writeln!(
+        out,
+        "Now the result is {result} as we return the counter from the loop block"
+    );
+    writeln!(out);
+    result
+}
+
+pub fn nested_loops_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "nested loops example");
+    // We can break a specific loop by labeling it.
+    let mut simon = 0;
+    'outer: loop {
+        simon += 1;
+        writeln!(out, "simon = {simon}");
+        let mut inner = 0;
+        'inner: loop {
+            inner += 1;
+            writeln!(out, "inner = {inner}");
+            if inner == simon {
+                writeln!(out, "-----\n");
+                break 'inner;
+            }
+            if inner == 2 {
+                writeln!(out, "----------\n");
+                break 'outer;
+            }
+        }
+    }
+    writeln!(out);
+    simon
+}
+
+pub fn manual_while_loop_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "manual while loop example");
+    let mut x = 0;
+    loop {
+        if x == 5 {
+            break;
+        } else {
+            writeln!(out, "{x}");
+            x += 1;
+        }
+    }
+    writeln!(out);
+    x
+}
+
+pub fn while_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "while example");
+    let mut x: i32 = 0;
+    let mut counter: i32 = 1;
+    const N: i32 = 4;
+    while counter < N {
+        x += counter;
+        counter += 1;
+    }
+    writeln!(out, "{x} ");
+    writeln!(out);
+    x
+}
+
+pub fn for_example(out: &mut dyn Output) -> Vec<i32> {
+    writeln!(out, "for example");
+    let a = [10, 20, 30, 40, 50];
+    for element in a {
+        writeln!(out, "The value is: {element}");
+    }
+    let mut countdown = vec![];
+    for number in (1..4).rev() {
+        writeln!(out, "{number}!");
+        countdown.push(number);
+    }
+    writeln!(out, "LIFTOFF!!!");
+    writeln!(out);
+    countdown
+}
@@ -0,0 +1,3 @@
+// The examples live in the library so they can be called (and tested) from
+// elsewhere, main.rs just runs them in order.
+pub mod examples;
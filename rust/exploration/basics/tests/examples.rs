@@ -0,0 +1,38 @@
+use basics::examples::*;
+use output::Buffer;
+
+#[test]
+fn values() {
+    assert_eq!(
+        type_example(&mut Buffer::new()),
+        ((32, 1.25, true, 'a'), [1, 2, 3, 4, 5])
+    );
+    assert_eq!(tuple_example(&mut Buffer::new()), (32, 1.25, true, 'a'));
+    assert_eq!(
+        format!("{:.10}", constant_example(&mut Buffer::new())),
+        "3.1415926536"
+    );
+    assert_eq!(
+        array_example(&mut Buffer::new()),
+        ([1, 2, 3, 4, 5], [1; 5], 3)
+    );
+    assert_eq!(function_example(&mut Buffer::new()), 3);
+    assert_eq!(if_example(&mut Buffer::new()), 6);
+}
+
+#[test]
+fn loops() {
+    assert_eq!(loop_example(&mut Buffer::new()), 3);
+    // inner only reaches 2 before simon on the third time round
+    assert_eq!(nested_loops_example(&mut Buffer::new()), 3);
+    assert_eq!(manual_while_loop_example(&mut Buffer::new()), 5);
+    assert_eq!(while_example(&mut Buffer::new()), 1 + 2 + 3);
+    assert_eq!(for_example(&mut Buffer::new()), [3, 2, 1]);
+}
+
+#[test]
+fn loop_output() {
+    let mut out = Buffer::new();
+    for_example(&mut out);
+    assert_eq!(out.lines()[6..], ["3!", "2!", "1!", "LIFTOFF!!!", ""]);
+}
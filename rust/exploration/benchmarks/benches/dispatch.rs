@@ -0,0 +1,29 @@
+// Summing areas with static dispatch (a Vec<Shape> through a generic
+// function) against dynamic dispatch (the same shapes as Box<dyn Measurable>,
+// each area() a vtable call). The boxes are also scattered around the heap
+// while the Vec<Shape> is one block, which is part of the price of dyn.
+use benchmarks::{random_shapes, SIZES};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use enums::measurable::{total_area_dynamic, total_area_static, Measurable};
+
+fn bench_dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dispatch");
+    for size in SIZES {
+        let shapes = random_shapes(size);
+        let boxed: Vec<Box<dyn Measurable>> = random_shapes(size)
+            .into_iter()
+            .map(|shape| Box::new(shape) as Box<dyn Measurable>)
+            .collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("static", size), &shapes, |b, shapes| {
+            b.iter(|| total_area_static(black_box(shapes)))
+        });
+        group.bench_with_input(BenchmarkId::new("dynamic", size), &boxed, |b, boxed| {
+            b.iter(|| total_area_dynamic(black_box(boxed)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dispatch);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+// The take / drain / retain ways of writing CarPark::filter_old.
+use benchmarks::{random_car_park, SIZES};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use errors::CarPark;
+
+type FilterOld = fn(&mut CarPark, i32);
+
+fn bench_filter_old(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter_old");
+    let variants: [(&str, FilterOld); 3] = [
+        ("take", CarPark::filter_old),
+        ("drain", CarPark::filter_old_drain),
+        ("retain", CarPark::filter_old_retain),
+    ];
+    for size in SIZES {
+        let lot = random_car_park(size);
+        group.throughput(Throughput::Elements(size as u64));
+        for (name, filter) in variants {
+            group.bench_with_input(BenchmarkId::new(name, size), &lot, |b, lot| {
+                b.iter_batched_ref(
+                    || CarPark {
+                        cars: lot.cars.clone(),
+                    },
+                    |lot| filter(lot, 5),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_filter_old);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+// The hand-written OpenMap against std's HashMap (both SwissTables, std's
+// scans a group with SIMD instructions and doesn't use Option slots).
+use benchmarks::{random_i32s, SIZES};
+use collections::open_map::OpenMap;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::collections::HashMap;
+
+// Just enough of the map API for the workloads, so each one is written once.
+trait Map: Default {
+    fn insert(&mut self, key: i32, value: i32);
+    fn get(&self, key: i32) -> Option<i32>;
+    fn remove(&mut self, key: i32);
+}
+
+impl Map for HashMap<i32, i32> {
+    fn insert(&mut self, key: i32, value: i32) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: i32) -> Option<i32> {
+        HashMap::get(self, &key).copied()
+    }
+
+    fn remove(&mut self, key: i32) {
+        HashMap::remove(self, &key);
+    }
+}
+
+impl Map for OpenMap<i32, i32> {
+    fn insert(&mut self, key: i32, value: i32) {
+        OpenMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: i32) -> Option<i32> {
+        OpenMap::get(self, &key).copied()
+    }
+
+    fn remove(&mut self, key: i32) {
+        OpenMap::remove(self, &key);
+    }
+}
+
+fn filled<M: Map>(keys: &[i32]) -> M {
+    let mut map = M::default();
+    for key in keys {
+        map.insert(*key, *key);
+    }
+    map
+}
+
+fn insert<M: Map>(keys: &[i32]) -> M {
+    filled(keys)
+}
+
+// half the lookups hit, half miss (the odd keys are never inserted)
+fn lookup<M: Map>(map: &M, keys: &[i32]) -> usize {
+    keys.iter()
+        .filter(|key| map.get(**key).or(map.get(**key + 1)).is_some())
+        .count()
+}
+
+// keeps the map the same size while replacing every key, so the
+// tombstones pile up
+fn churn<M: Map>(map: &mut M, keys: &[i32]) {
+    for key in keys {
+        map.remove(*key);
+        map.insert(*key + 1, *key);
+        map.remove(*key + 1);
+        map.insert(*key, *key);
+    }
+}
+
+fn bench_workloads<M: Map>(c: &mut Criterion, name: &str) {
+    for size in SIZES {
+        let keys: Vec<i32> = random_i32s(size, 1_000_000).iter().map(|x| x * 2).collect();
+        let throughput = Throughput::Elements(size as u64);
+
+        let mut group = c.benchmark_group("hash_map_insert");
+        group.throughput(throughput.clone());
+        group.bench_with_input(BenchmarkId::new(name, size), &keys, |b, keys| {
+            b.iter(|| insert::<M>(black_box(keys)))
+        });
+        group.finish();
+
+        let map: M = filled(&keys);
+        let mut group = c.benchmark_group("hash_map_lookup");
+        group.throughput(throughput.clone());
+        group.bench_with_input(BenchmarkId::new(name, size), &keys, |b, keys| {
+            b.iter(|| lookup(&map, black_box(keys)))
+        });
+        group.finish();
+
+        let mut map: M = filled(&keys);
+        let mut group = c.benchmark_group("hash_map_delete");
+        group.throughput(throughput);
+        group.bench_with_input(BenchmarkId::new(name, size), &keys, |b, keys| {
+            b.iter(|| churn(&mut map, black_box(keys)))
+        });
+        group.finish();
+    }
+}
+
+fn bench_hash_map(c: &mut Criterion) {
+    bench_workloads::<HashMap<i32, i32>>(c, "std");
+    bench_workloads::<OpenMap<i32, i32>>(c, "open_map");
+}
+
+criterion_group!(benches, bench_hash_map);
+criterion_main!(benches);
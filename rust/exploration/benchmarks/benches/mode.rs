@@ -0,0 +1,25 @@
+// The two mode algorithms from collection_questions::question_1.
+use benchmarks::{random_i32s, SIZES};
+use collections::collection_questions::{mode_counting_loop, mode_max_by_key};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn bench_mode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mode");
+    for size in SIZES {
+        // Few distinct values so there are plenty of repeats to count.
+        let input = random_i32s(size, 50);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("counting_loop", size),
+            &input,
+            |b, input| b.iter(|| mode_counting_loop(black_box(input))),
+        );
+        group.bench_with_input(BenchmarkId::new("max_by_key", size), &input, |b, input| {
+            b.iter(|| mode_max_by_key(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mode);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+// shuffle_i32_slice vs the generic shuffle (they should be identical after monomorphisation).
+use benchmarks::{random_i32s, rng, SIZES};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use generics::{shuffle, shuffle_i32_slice};
+
+fn bench_shuffle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shuffle");
+    let mut rng = rng();
+    for size in SIZES {
+        let input = random_i32s(size, 1_000);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("shuffle_i32_slice", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| {
+                        shuffle_i32_slice(v, &mut rng);
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("shuffle", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| shuffle(v, &mut rng),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_shuffle);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+// The brute force two sum against the hash map and two pointer versions.
+use benchmarks::{random_i32s, SIZES};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use leet::two_sum::{brute_force, hash_map, two_pointer};
+
+type TwoSum = fn(&[i32], i32) -> Option<(usize, usize)>;
+
+fn bench_two_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("two_sum");
+    let variants: [(&str, TwoSum); 3] = [
+        ("brute_force", brute_force),
+        ("hash_map", hash_map),
+        ("two_pointer", two_pointer),
+    ];
+    for size in SIZES {
+        // The values are all even and the target odd so there is no answer,
+        // this is the worst case where every variant has to look at everything.
+        let input: Vec<i32> = random_i32s(size, 1_000_000).iter().map(|x| x * 2).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        for (name, solver) in variants {
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter(|| solver(black_box(input), black_box(1)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_two_sum);
+criterion_main!(benches);
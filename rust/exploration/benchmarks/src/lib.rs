@@ -0,0 +1,52 @@
+// Inputs shared by the benchmarks in `benches/`.
+//
+// Run them all with `cargo bench -p benchmarks` (or one with `--bench mode`).
+// Criterion writes a comparison report for each group to
+// `target/criterion/<group>/report/index.html`.
+use enums::shape::Shape;
+use errors::{Car, CarColour, CarPark};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// The input sizes every group is run at.
+pub const SIZES: [usize; 4] = [10, 100, 1_000, 10_000];
+
+// Seeded so every run (and every variant) sees the same input.
+pub fn rng() -> StdRng {
+    StdRng::seed_from_u64(28)
+}
+
+pub fn random_i32s(len: usize, max: i32) -> Vec<i32> {
+    let mut rng = rng();
+    (0..len).map(|_| rng.gen_range(0..max)).collect()
+}
+
+// Roughly half the cars are older than 5 so filtering keeps half.
+pub fn random_car_park(len: usize) -> CarPark {
+    let mut rng = rng();
+    let cars = (0..len)
+        .map(|i| Car {
+            number_plate: format!("RG54 {i}PQ"),
+            age: rng.gen_range(0..10),
+            colour: CarColour::Silver,
+        })
+        .collect();
+    CarPark { cars }
+}
+
+// A mix of rectangles and circles up to 100 across.
+pub fn random_shapes(len: usize) -> Vec<Shape> {
+    let mut rng = rng();
+    (0..len)
+        .map(|_| {
+            if rng.gen_bool(0.5) {
+                Shape::Rectangle {
+                    height: rng.gen_range(1..100),
+                    width: rng.gen_range(1..100),
+                }
+            } else {
+                Shape::Circle(rng.gen_range(1..50))
+            }
+        })
+        .collect()
+}
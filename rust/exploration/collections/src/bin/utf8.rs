@@ -0,0 +1,79 @@
+// Run with one of
+//   cargo run -p collections --bin utf8 -- "Yes ✅"
+//   cargo run -p collections --bin utf8 -- --file some.txt
+// and optionally --chars 0..3 or --graphemes 0..3 to print just that part.
+// The text can be any bytes, not just UTF-8, so the arguments are OsStrings.
+use collections::utf8::{char_substring, decode, grapheme_substring, report};
+use std::ffi::OsString;
+use std::ops::Range;
+use std::process::ExitCode;
+
+fn parse_range(text: &str) -> Option<Range<usize>> {
+    let (start, end) = text.split_once("..")?;
+    Some(start.parse().ok()?..end.parse().ok()?)
+}
+
+const USAGE: &str = "usage: utf8 <text> | --file <path> [--chars a..b | --graphemes a..b]";
+
+// The bytes to look at, and "chars" or "graphemes" and the range to print
+type Args = (Vec<u8>, Option<(&'static str, Range<usize>)>);
+
+fn parse_args(args: impl IntoIterator<Item = OsString>) -> Result<Args, String> {
+    let mut bytes: Option<Vec<u8>> = None;
+    let mut slice = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let text = match arg.to_str() {
+            Some("--file") => {
+                let path = args.next().ok_or("--file needs a path")?;
+                std::fs::read(&path)
+                    .map_err(|error| format!("could not read {}: {error}", path.display()))?
+            }
+            Some(flag @ ("--chars" | "--graphemes")) => {
+                let range = args.next().and_then(|range| parse_range(range.to_str()?));
+                let kind = if flag == "--chars" {
+                    "chars"
+                } else {
+                    "graphemes"
+                };
+                slice = Some((kind, range.ok_or("expected a range like 0..3")?));
+                continue;
+            }
+            // the bytes as given, on Unix that's whatever was typed
+            _ => arg.as_encoded_bytes().to_vec(),
+        };
+        // one text only, rather than quietly keeping the last one
+        if bytes.replace(text).is_some() {
+            return Err("only one text or --file at a time, quote text with spaces in".into());
+        }
+    }
+    Ok((bytes.ok_or("nothing to look at")?, slice))
+}
+
+fn main() -> ExitCode {
+    let (bytes, slice) = match parse_args(std::env::args_os().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", report(&bytes));
+    if let Some((kind, range)) = slice {
+        let (text, _) = decode(&bytes);
+        let part = match kind {
+            "chars" => char_substring(&text, range.clone()),
+            _ => grapheme_substring(&text, range.clone()),
+        };
+        match part {
+            Some(part) => println!("{kind} {range:?}: {part:?}"),
+            None => {
+                eprintln!("{range:?} is out of range");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
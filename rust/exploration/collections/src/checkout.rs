@@ -0,0 +1,436 @@
+// A supermarket checkout built on the basket from hash_examples.
+//
+// - a Catalogue of products priced in pence, either each or per kg
+// - a Basket of product code -> quantity (items, or grams for per kg)
+// - Offers like "3 for 2" which can overlap, so best_discounts tries every
+//   combination and keeps the cheapest for the customer
+// - a Receipt listing the lines, the savings and the total
+//
+// Money is always whole pence (u64) so there is no floating point rounding.
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ordered_map::OrderedMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Each,
+    // priced per kg, quantities are in grams
+    PerKg,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Product {
+    pub code: String,
+    pub name: String,
+    pub price: u64,
+    pub unit: Unit,
+}
+
+impl Product {
+    pub fn new(code: &str, name: &str, price: u64, unit: Unit) -> Product {
+        Product {
+            code: code.to_string(),
+            name: name.to_string(),
+            price,
+            unit,
+        }
+    }
+
+    // Per kg prices are rounded to the nearest penny.
+    pub fn price_of(&self, quantity: u32) -> u64 {
+        match self.unit {
+            Unit::Each => self.price * quantity as u64,
+            Unit::PerKg => (self.price * quantity as u64 + 500) / 1000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckoutError {
+    UnknownProduct(String),
+    // Offers count items so can't be on something sold by weight.
+    OfferOnWeighedProduct(String),
+}
+
+impl fmt::Display for CheckoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckoutError::UnknownProduct(code) => write!(f, "no product with code {code}"),
+            CheckoutError::OfferOnWeighedProduct(code) => {
+                write!(f, "{code} is sold by weight so can't be in an offer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckoutError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Offer {
+    // "3 for 2": buy `buy` of them and pay for `pay_for`.
+    MultiBuy {
+        code: String,
+        buy: u32,
+        pay_for: u32,
+    },
+    // "3 for £1": `count` of them for a fixed `price`.
+    MultiPrice {
+        code: String,
+        count: u32,
+        price: u64,
+    },
+    // "buy apples get bananas half price": each `trigger` bought takes
+    // `percent_off` off one `target`.
+    Linked {
+        trigger: String,
+        target: String,
+        percent_off: u64,
+    },
+}
+
+impl Offer {
+    fn codes(&self) -> Vec<&str> {
+        match self {
+            Offer::MultiBuy { code, .. } | Offer::MultiPrice { code, .. } => vec![code],
+            Offer::Linked {
+                trigger, target, ..
+            } => vec![trigger, target],
+        }
+    }
+
+    // What one use of the offer uses up from the basket.
+    fn uses(&self) -> Vec<(&str, u32)> {
+        match self {
+            Offer::MultiBuy { code, buy, .. } => vec![(code, *buy)],
+            Offer::MultiPrice { code, count, .. } => vec![(code, *count)],
+            Offer::Linked {
+                trigger, target, ..
+            } if trigger == target => vec![(trigger, 2)],
+            Offer::Linked {
+                trigger, target, ..
+            } => vec![(trigger, 1), (target, 1)],
+        }
+    }
+
+    // How much one use of the offer saves. The products are public so one
+    // could have been taken out of the catalogue since the offer was added.
+    fn saving(&self, catalogue: &Catalogue) -> Result<u64, CheckoutError> {
+        let price = |code: &str| Ok(catalogue.get(code)?.price);
+        Ok(match self {
+            Offer::MultiBuy { code, buy, pay_for } => {
+                price(code)? * buy.saturating_sub(*pay_for) as u64
+            }
+            Offer::MultiPrice {
+                code,
+                count,
+                price: bundle,
+            } => (price(code)? * *count as u64).saturating_sub(*bundle),
+            Offer::Linked {
+                target,
+                percent_off,
+                ..
+            } => price(target)? * percent_off.min(&100) / 100,
+        })
+    }
+
+    pub fn describe(&self, catalogue: &Catalogue) -> Result<String, CheckoutError> {
+        let name = |code: &str| Ok(&catalogue.get(code)?.name);
+        Ok(match self {
+            Offer::MultiBuy { code, buy, pay_for } => {
+                format!("{} {buy} for {pay_for}", name(code)?)
+            }
+            Offer::MultiPrice { code, count, price } => {
+                format!("{} {count} for {}", name(code)?, pence(*price))
+            }
+            Offer::Linked {
+                trigger,
+                target,
+                percent_off,
+            } => format!(
+                "{} {percent_off}% off with {}",
+                name(target)?,
+                name(trigger)?
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Catalogue {
+    pub products: OrderedMap<String, Product>,
+    pub offers: Vec<Offer>,
+}
+
+impl Catalogue {
+    pub fn new() -> Catalogue {
+        Catalogue::default()
+    }
+
+    pub fn add_product(&mut self, product: Product) {
+        self.products.insert(product.code.clone(), product);
+    }
+
+    pub fn get(&self, code: &str) -> Result<&Product, CheckoutError> {
+        self.products
+            .get(code)
+            .ok_or_else(|| CheckoutError::UnknownProduct(code.to_string()))
+    }
+
+    pub fn add_offer(&mut self, offer: Offer) -> Result<(), CheckoutError> {
+        for code in offer.codes() {
+            if self.get(code)?.unit == Unit::PerKg {
+                return Err(CheckoutError::OfferOnWeighedProduct(code.to_string()));
+            }
+        }
+        self.offers.push(offer);
+        Ok(())
+    }
+}
+
+// Product code -> quantity (items, or grams for per kg products).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Basket {
+    pub items: OrderedMap<String, u32>,
+}
+
+impl Basket {
+    pub fn new() -> Basket {
+        Basket::default()
+    }
+
+    pub fn add(
+        &mut self,
+        catalogue: &Catalogue,
+        code: &str,
+        quantity: u32,
+    ) -> Result<(), CheckoutError> {
+        catalogue.get(code)?;
+        *self.items.entry(code.to_string()).or_insert(0) += quantity;
+        Ok(())
+    }
+
+    // Takes away up to `quantity`, dropping the line when it gets to zero.
+    pub fn remove(&mut self, code: &str, quantity: u32) {
+        if let Some(current) = self.items.get_mut(code) {
+            *current = current.saturating_sub(quantity);
+            if *current == 0 {
+                self.items.shift_remove(code);
+            }
+        }
+    }
+
+    // Set the quantity outright, zero removes the line.
+    pub fn update(
+        &mut self,
+        catalogue: &Catalogue,
+        code: &str,
+        quantity: u32,
+    ) -> Result<(), CheckoutError> {
+        catalogue.get(code)?;
+        if quantity == 0 {
+            self.items.shift_remove(code);
+        } else {
+            self.items.insert(code.to_string(), quantity);
+        }
+        Ok(())
+    }
+
+    pub fn quantity(&self, code: &str) -> u32 {
+        self.items.get(code).copied().unwrap_or(0)
+    }
+
+    pub fn subtotal(&self, catalogue: &Catalogue) -> Result<u64, CheckoutError> {
+        self.items
+            .iter()
+            .map(|(code, quantity)| Ok(catalogue.get(code)?.price_of(*quantity)))
+            .sum()
+    }
+}
+
+// An offer and how many times it was used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedOffer {
+    pub offer: Offer,
+    pub times: u32,
+    pub saving: u64,
+}
+
+// Offers can compete for the same items (apples in a "3 for 2" and in "buy
+// apples get bananas half price") so greedily taking the biggest saving first
+// isn't always best. Try every number of uses of every offer and keep the
+// combination saving the most. Offers on products no longer in the catalogue
+// are skipped.
+pub fn best_discounts(catalogue: &Catalogue, basket: &Basket) -> Vec<AppliedOffer> {
+    let offers: Vec<(&Offer, u64)> = catalogue
+        .offers
+        .iter()
+        .filter_map(|offer| Some((offer, offer.saving(catalogue).ok()?)))
+        .filter(|(_, saving)| *saving > 0)
+        .collect();
+    let mut remaining = basket.items.clone();
+    let (_, best) = search(&offers, 0, &mut remaining, &mut HashMap::new());
+
+    offers
+        .iter()
+        .zip(best)
+        .filter(|(_, times)| *times > 0)
+        .map(|((offer, saving), times)| AppliedOffer {
+            offer: (*offer).clone(),
+            times,
+            saving: saving * times as u64,
+        })
+        .collect()
+}
+
+// (next offer, quantities left) -> the best (saving, times used) from there
+type Memo = HashMap<(usize, Vec<u32>), (u64, Vec<u32>)>;
+
+// The best (saving, times used) for offers[next..] with what's left in the
+// basket. Lots of ways of using the earlier offers leave the same items over,
+// so the answer for each (next, remaining) is remembered rather than worked
+// out again. The work then grows with the number of different leftovers, not
+// with every combination of uses of every offer.
+fn search(
+    offers: &[(&Offer, u64)],
+    next: usize,
+    remaining: &mut OrderedMap<String, u32>,
+    memo: &mut Memo,
+) -> (u64, Vec<u32>) {
+    if next == offers.len() {
+        return (0, vec![]);
+    }
+    // offers only take items away so the keys (and their order) never change
+    let key = (next, remaining.values().copied().collect());
+    if let Some(best) = memo.get(&key) {
+        return best.clone();
+    }
+    let (offer, offer_saving) = offers[next];
+    let uses = offer.uses();
+    // Don't use this offer at all, then once, twice, ... while there are items left.
+    let mut best = (0, vec![]);
+    let mut used = 0;
+    loop {
+        let (saving, mut times) = search(offers, next + 1, remaining, memo);
+        let saving = saving + offer_saving * used as u64;
+        if used == 0 || saving > best.0 {
+            times.insert(0, used);
+            best = (saving, times);
+        }
+        if !uses
+            .iter()
+            .all(|(code, count)| remaining.get(*code).copied().unwrap_or(0) >= *count)
+        {
+            break;
+        }
+        for (code, count) in &uses {
+            remaining[*code] -= count;
+        }
+        used += 1;
+    }
+    for (code, count) in &uses {
+        if let Some(left) = remaining.get_mut(*code) {
+            *left += count * used;
+        }
+    }
+    memo.insert(key, best.clone());
+    best
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptLine {
+    pub name: String,
+    pub quantity: String,
+    pub price: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    pub lines: Vec<ReceiptLine>,
+    pub savings: Vec<(String, u64)>,
+    pub subtotal: u64,
+    pub total_savings: u64,
+    pub total: u64,
+}
+
+pub fn checkout(catalogue: &Catalogue, basket: &Basket) -> Result<Receipt, CheckoutError> {
+    let mut lines = vec![];
+    for (code, quantity) in &basket.items {
+        let product = catalogue.get(code)?;
+        let quantity_text = match product.unit {
+            Unit::Each => format!("{quantity} @ {}", pence(product.price)),
+            Unit::PerKg => format!(
+                "{}.{:03} kg @ {}/kg",
+                quantity / 1000,
+                quantity % 1000,
+                pence(product.price)
+            ),
+        };
+        lines.push(ReceiptLine {
+            name: product.name.clone(),
+            quantity: quantity_text,
+            price: product.price_of(*quantity),
+        });
+    }
+    let savings = best_discounts(catalogue, basket)
+        .into_iter()
+        .map(|applied| {
+            let times = if applied.times > 1 {
+                format!(" x{}", applied.times)
+            } else {
+                String::new()
+            };
+            Ok((
+                format!("{}{times}", applied.offer.describe(catalogue)?),
+                applied.saving,
+            ))
+        })
+        .collect::<Result<Vec<(String, u64)>, CheckoutError>>()?;
+    let subtotal: u64 = lines.iter().map(|line| line.price).sum();
+    let total_savings: u64 = savings.iter().map(|(_, saving)| saving).sum();
+    Ok(Receipt {
+        lines,
+        savings,
+        subtotal,
+        total_savings,
+        total: subtotal - total_savings,
+    })
+}
+
+// 1234 -> "£12.34"
+pub fn pence(amount: u64) -> String {
+    format!("£{}.{:02}", amount / 100, amount % 100)
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(
+                f,
+                "{:<16}{:>22}{:>10}",
+                line.name,
+                line.quantity,
+                pence(line.price)
+            )?;
+        }
+        writeln!(f, "{:<38}{:>10}", "Subtotal", pence(self.subtotal))?;
+        if !self.savings.is_empty() {
+            writeln!(f, "Savings")?;
+            for (description, saving) in &self.savings {
+                writeln!(
+                    f,
+                    "  {:<36}{:>10}",
+                    description,
+                    format!("-{}", pence(*saving))
+                )?;
+            }
+            writeln!(
+                f,
+                "{:<38}{:>10}",
+                "Total savings",
+                format!("-{}", pence(self.total_savings))
+            )?;
+        }
+        write!(f, "{:<38}{:>10}", "Total to pay", pence(self.total))
+    }
+}
@@ -0,0 +1,201 @@
+use crate::checkout::{checkout, Basket, Catalogue, Offer, Product, Receipt, Unit};
+use crate::collection_questions;
+use crate::my_vec::MyVec;
+use crate::open_map::OpenMap;
+use crate::ordered_map::OrderedMap;
+use crate::utf8;
+use output::Output;
+
+// Everything main runs, in order.
+pub fn run_all(out: &mut dyn Output) {
+    writeln!(out, "Collection examples");
+    writeln!(out);
+    vector_examples(out);
+    string_examples(out);
+    hash_examples(out);
+    checkout_example(out);
+    writeln!(out);
+    collection_questions::run_questions(out);
+}
+
+// returns v and the MyVec version of it
+pub fn vector_examples(out: &mut dyn Output) -> (Vec<i32>, MyVec<i32>) {
+    writeln!(out, "Vector example");
+    let mut v: Vec<i32> = Vec::new(); // initialise empty vector
+    let v2 = vec![1, 2, 3]; // initialise vector with values using vec! macro
+    v.push(1); // append
+    v.push(20); // append
+    v.extend(&v2); // extend
+    for i in &mut v {
+        // loop
+        *i += 10;
+    }
+
+    let thirty = &v[1]; // gets value at index 1
+    let not_long_enough = v.get(100); // gets value at index 100 or None
+    writeln!(out, "thirty is {thirty}");
+    match not_long_enough {
+        Some(value) => writeln!(out, "not_long_enough is {value}"),
+        None => writeln!(out, "not_long_enough is None"),
+    }
+    writeln!(out, "v is {v:?}");
+
+    // the same again with the hand-written version in my_vec.rs
+    let mut mine: MyVec<i32> = MyVec::new();
+    mine.push(1);
+    mine.push(20);
+    mine.extend(&v2);
+    for i in &mut mine {
+        *i += 10;
+    }
+    writeln!(
+        out,
+        "mine is {mine:?}, len {} capacity {}",
+        mine.len(),
+        mine.capacity()
+    );
+    writeln!(out);
+    (v, mine)
+}
+
+#[allow(unused_variables)]
+// returns here_we_go_again and contains_emoji's length in bytes and chars
+pub fn string_examples(out: &mut dyn Output) -> (String, usize, usize) {
+    writeln!(out, "String examples");
+    let empty_immutable_string = String::new(); // initialise empty String
+    let mut s = String::from("simon"); // initialise String from &str
+    let mut si = "simon".to_string(); // also initialise String from &str
+                                      // string modifying
+    if s == si {
+        writeln!(out, "Before modifying s and si are equal");
+    }
+    s.push_str(" says hello"); // push_str appends a literal to a String
+    si.push('!'); // push appends a single character to a String
+    writeln!(out, "s is \"{s}\"");
+    writeln!(out, "si is \"{si}\"");
+    let here = String::from("here");
+    let we = String::from("we");
+    let go = String::from("go");
+    let here_we_go = String::new() + &here + " " + &we + " " + &go + "!";
+    // this is a little unwieldy so we can use format!
+    let here_we_go_again = format!("{here} {we} {go} again!");
+    writeln!(out, "{here_we_go}");
+    writeln!(out, "{here_we_go_again}");
+
+    // emojis
+    let contains_emoji = "Yes ✅";
+    writeln!(out, "contains_emoji is \"{contains_emoji}\"");
+    let len = contains_emoji.len();
+    let char_len = contains_emoji.chars().collect::<Vec<char>>().len();
+    for (i, character) in contains_emoji.chars().enumerate() {
+        writeln!(out, "{i}, char:'{character}'");
+        let t = character as u32;
+        writeln!(out, "{t}")
+    }
+    for (i, byte) in contains_emoji.bytes().enumerate() {
+        writeln!(out, "{i}, byte:{byte}");
+    }
+    writeln!(out, "{}", contains_emoji);
+    writeln!(out, "contains_emoji.len() is {len}");
+    writeln!(out, "contains_emoji.len() is {char_len}");
+    // the utf8 module does all of the above as a table, and the utf8 bin
+    // does it for any string: cargo run -p collections --bin utf8 -- "Yes ✅"
+    writeln!(out, "{}", utf8::table(contains_emoji));
+    writeln!(
+        out,
+        "first 4 chars are {:?}",
+        utf8::char_substring(contains_emoji, 0..4)
+    );
+    writeln!(out);
+    (here_we_go_again, len, char_len)
+}
+
+pub fn hash_examples(out: &mut dyn Output) -> OrderedMap<String, i32> {
+    writeln!(out, "Hash examples");
+    // OrderedMap has the same API as std::collections::HashMap (swap it back to
+    // see) but remembers insertion order, so the printing below is reproducible.
+    let mut basket: OrderedMap<String, i32> = OrderedMap::new();
+    let apple: String = "apple".to_owned();
+    let banana: String = String::from("banana");
+    let apple_clone = apple.clone();
+    basket.insert(apple, 3);
+    basket.insert(banana, 2);
+    // For types implementing Copy trait, like i32, the values are copied
+    // For types not implementing Copy trait, like String, the values are moved
+    // So we cannot reference apple now!
+    writeln!(out, "basket={basket:?}");
+
+    // Access values
+    let _a = basket.get(&apple_clone).expect("should be there");
+    let _b = basket["banana"];
+
+    // Overwriting a value
+    basket.insert(apple_clone, 10);
+    writeln!(out, "basket={basket:?}");
+    // adding if not there
+    basket.entry(String::from("orange")).or_insert(0);
+    basket.entry(String::from("banana")).or_insert(0);
+    writeln!(out, "basket={basket:?}");
+    // updating a value based on old value
+    basket.entry(String::from("orange")).and_modify(|v| *v += 1);
+    let bananas_entry = basket.entry(String::from("banana")).or_insert(0);
+    *bananas_entry += 100;
+    writeln!(out, "basket={basket:?}");
+
+    // Iterating over a std hash map happens in an arbitrary order,
+    // an OrderedMap goes in insertion order.
+    for (key, value) in &basket {
+        writeln!(out, "{key}={value}");
+    }
+
+    // OpenMap (open_map.rs) is a hand-written version of what HashMap does
+    // inside, with the same basket API.
+    let mut open_basket: OpenMap<String, i32> = basket.clone().into_iter().collect();
+    open_basket.entry(String::from("pear")).or_insert(5);
+    open_basket.remove("orange");
+    writeln!(
+        out,
+        "open_basket has {} items in {} buckets, banana={}",
+        open_basket.len(),
+        open_basket.buckets(),
+        open_basket["banana"]
+    );
+    basket
+}
+
+pub fn checkout_example(out: &mut dyn Output) -> Receipt {
+    writeln!(out, "Checkout example");
+    // The basket again but with prices and offers (see checkout.rs)
+    let mut catalogue = Catalogue::new();
+    catalogue.add_product(Product::new("apple", "Apple", 50, Unit::Each));
+    catalogue.add_product(Product::new("banana", "Banana", 30, Unit::Each));
+    catalogue.add_product(Product::new("orange", "Orange", 40, Unit::Each));
+    catalogue.add_product(Product::new("grapes", "Grapes", 399, Unit::PerKg));
+    let offers = [
+        Offer::MultiBuy {
+            code: "apple".to_string(),
+            buy: 3,
+            pay_for: 2,
+        },
+        Offer::Linked {
+            trigger: "apple".to_string(),
+            target: "banana".to_string(),
+            percent_off: 50,
+        },
+    ];
+    for offer in offers {
+        catalogue
+            .add_offer(offer)
+            .expect("products are in the catalogue");
+    }
+    let mut basket = Basket::new();
+    for (code, quantity) in [("apple", 10), ("banana", 2), ("orange", 1), ("grapes", 450)] {
+        basket
+            .add(&catalogue, code, quantity)
+            .expect("products are in the catalogue");
+    }
+    let receipt = checkout(&catalogue, &basket).expect("products are in the catalogue");
+    writeln!(out, "{receipt}");
+    writeln!(out);
+    receipt
+}
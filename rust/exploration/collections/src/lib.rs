@@ -0,0 +1,126 @@
+pub mod checkout;
+pub mod examples;
+pub mod my_vec;
+pub mod open_map;
+pub mod ordered_map;
+pub mod utf8;
+
+pub mod collection_questions {
+    use output::Output;
+    use std::collections::{HashMap, VecDeque};
+
+    pub fn question_1(vector: &[i32]) -> (f64, i32) {
+        // Given a list of integers, use a vector and return the median
+        // (when sorted, the value in the middle position) and mode
+        // (the value that occurs most often; a hash map will be helpful here) of the list.
+
+        // median
+        let mut vector = vector.to_vec();
+        vector.sort();
+        let len = vector.len();
+        let median = match len.is_multiple_of(2) {
+            true => (vector[len / 2] + vector[(len / 2) - 1]) as f64 / 2.0,
+            false => vector[(len - 1) / 2] as f64,
+        };
+
+        // return (median, mode_counting_loop(&vector));
+        (median, mode_max_by_key(&vector))
+    }
+
+    // mode
+    pub fn mode_counting_loop(vector: &[i32]) -> i32 {
+        let mut value_counts: HashMap<i32, i32> = HashMap::new();
+        for value in vector {
+            let count = value_counts.entry(*value).or_insert(0);
+            *count += 1;
+        }
+        let mut mode_pair = (0, 0);
+        for (value, count) in value_counts {
+            if count > mode_pair.1 {
+                mode_pair = (value, count);
+            }
+        }
+        mode_pair.0
+    }
+
+    // another mode implementation (slicker)
+    pub fn mode_max_by_key(vector: &[i32]) -> i32 {
+        let mut counts = HashMap::new();
+        let mode = vector
+            .iter()
+            .max_by_key(|a| {
+                let count = counts.entry(*a).or_insert(0);
+                *count += 1;
+                *count
+            })
+            .unwrap();
+        *mode
+    }
+
+    fn _get_words(string: &str) -> Vec<String> {
+        let mut passing_word = true;
+        let mut words: Vec<String> = Vec::new();
+        let mut word: Vec<char> = Vec::new();
+        for (i, letter) in string.chars().enumerate() {
+            if i == 0 {
+                passing_word = letter.is_alphabetic();
+            }
+            if letter.is_alphabetic() && passing_word {
+                word.push(letter);
+            } else if letter.is_alphabetic() && !passing_word {
+                words.push(word.iter().collect());
+                word = vec![letter];
+                passing_word = true;
+            } else if !letter.is_alphabetic() && passing_word {
+                words.push(word.iter().collect()); //push word
+                word = vec![letter];
+                passing_word = false;
+            } else if !letter.is_alphabetic() && !passing_word {
+                word.push(letter);
+            }
+        }
+        if !word.is_empty() {
+            words.push(word.iter().collect());
+        }
+        words
+    }
+
+    fn _convert_word_to_pig(string: &str) -> String {
+        let mut chars = string.chars().collect::<VecDeque<char>>();
+        let first = chars.pop_front().unwrap();
+        let vowels = ['a', 'e', 'i', 'o', 'u'];
+        if vowels.contains(&first) {
+            chars.extend("hay".chars());
+        } else {
+            chars.extend((first.to_string() + "ay").chars());
+        }
+        chars.iter().collect::<String>()
+    }
+
+    pub fn question_2(string: &str) -> String {
+        // Convert strings to pig latin. The first consonant of each word is moved
+        // to the end of the word and “ay” is added, so “first” becomes “irst-fay.”
+        // Words that start with a vowel have “hay” added to the end instead
+        // (“apple” becomes “apple-hay”). Keep in mind the details about UTF-8 encoding!
+        let out = _get_words(string)
+            .iter()
+            .map(|x| {
+                if x.chars().next().unwrap().is_alphabetic() {
+                    _convert_word_to_pig(x)
+                } else {
+                    x.to_string()
+                }
+            })
+            .collect::<String>();
+        out
+    }
+    pub fn run_questions(out: &mut dyn Output) {
+        let example = vec![1, 2, 3, 4, 5, 6, 6, 6, 7, 8];
+        let (median, mode) = question_1(&example);
+        writeln!(out, "median, mode is is {median}, {mode}");
+
+        let example = "!!!";
+        let pig_latin = question_2(example);
+        writeln!(out, "pig_latin: {pig_latin}");
+    }
+}
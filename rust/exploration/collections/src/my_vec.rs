@@ -0,0 +1,450 @@
+// A Vec written from scratch, to see what vector_examples is really doing.
+//
+// A Vec is three things: a pointer to a heap allocation, how many T's fit in
+// it (capacity) and how many are actually there (len). Pushing past capacity
+// allocates a bigger block (double the size) and moves everything across.
+// Everything below len is initialised, everything above it is garbage, so all
+// the unsafe code is about keeping that true.
+//
+// Zero sized types (like ()) never need memory, so the capacity is usize::MAX
+// from the start and the pointer is just a dangling one that's never read.
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds};
+use std::ptr::{self, NonNull};
+use std::slice::SliceIndex;
+
+// Just the allocation, it doesn't know how many T's are initialised so it
+// only frees the memory and never drops any T's.
+struct RawVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    // tells the drop checker we own T's, NonNull on its own doesn't
+    _owns: PhantomData<T>,
+}
+
+// Same as Vec, it owns its T's so it can be sent if they can.
+unsafe impl<T: Send> Send for RawVec<T> {}
+unsafe impl<T: Sync> Sync for RawVec<T> {}
+
+impl<T> RawVec<T> {
+    fn new() -> Self {
+        let cap = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            0
+        };
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap,
+            _owns: PhantomData,
+        }
+    }
+
+    fn grow_to(&mut self, new_cap: usize) {
+        // only reachable for zero sized types when len would pass usize::MAX
+        assert!(mem::size_of::<T>() != 0, "capacity overflow");
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        assert!(
+            new_layout.size() <= isize::MAX as usize,
+            "allocation too large"
+        );
+
+        let new_ptr = if self.cap == 0 {
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.cap).unwrap();
+            unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
+        };
+        self.ptr = match NonNull::new(new_ptr as *mut T) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(new_layout),
+        };
+        self.cap = new_cap;
+    }
+
+    fn grow(&mut self) {
+        let new_cap = if self.cap == 0 { 4 } else { self.cap * 2 };
+        self.grow_to(new_cap);
+    }
+}
+
+impl<T> Drop for RawVec<T> {
+    fn drop(&mut self) {
+        if self.cap != 0 && mem::size_of::<T>() != 0 {
+            let layout = Layout::array::<T>(self.cap).unwrap();
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout) }
+        }
+    }
+}
+
+pub struct MyVec<T> {
+    buf: RawVec<T>,
+    len: usize,
+}
+
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        MyVec {
+            buf: RawVec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> MyVec<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.cap
+    }
+
+    // makes room for at least additional more without reallocating
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len.checked_add(additional).expect("capacity overflow");
+        if needed > self.buf.cap {
+            self.buf.grow_to(needed.max(self.buf.cap * 2));
+        }
+    }
+
+    fn ptr(&self) -> *mut T {
+        self.buf.ptr.as_ptr()
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap {
+            self.buf.grow();
+        }
+        unsafe { ptr::write(self.ptr().add(self.len), value) }
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr().add(self.len))) }
+    }
+
+    // shifts everything from index onwards up one, panics if index > len
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "insertion index out of bounds");
+        if self.len == self.buf.cap {
+            self.buf.grow();
+        }
+        unsafe {
+            let at = self.ptr().add(index);
+            ptr::copy(at, at.add(1), self.len - index);
+            ptr::write(at, value);
+        }
+        self.len += 1;
+    }
+
+    // shifts everything after index down one, panics if index >= len
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds");
+        unsafe {
+            self.len -= 1;
+            let at = self.ptr().add(index);
+            let value = ptr::read(at);
+            ptr::copy(at.add(1), at, self.len - index);
+            value
+        }
+    }
+
+    // drops everything from len onwards, keeps the capacity
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(unsafe { self.ptr().add(len) }, self.len - len);
+        // set len first so a panicking drop can't cause a double drop
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    // Removes the range and gives back its values as an iterator. The vec
+    // can't be used until the Drain is dropped, at which point the tail after
+    // the range is moved down to close the gap.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "drain start is after its end");
+        assert!(end <= self.len, "drain end is out of bounds");
+        let tail_len = self.len - end;
+        // If the Drain is leaked (mem::forget) the drained values and the
+        // tail are leaked too, but nothing is read twice.
+        self.len = start;
+        Drain {
+            vec: self,
+            next: start,
+            end,
+            tail_start: end,
+            tail_len,
+        }
+    }
+}
+
+impl<T> Drop for MyVec<T> {
+    fn drop(&mut self) {
+        // drops the T's, then the RawVec frees the memory
+        unsafe { ptr::drop_in_place(self.deref_mut()) }
+    }
+}
+
+impl<T> Deref for MyVec<T> {
+    type Target = [T];
+
+    // everything slices can do (get, iter, iter_mut, len, sort...) comes from here
+    fn deref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for MyVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr(), self.len) }
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> Index<I> for MyVec<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.deref(), index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for MyVec<T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.deref_mut(), index)
+    }
+}
+
+impl<T> Extend<T> for MyVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+// so v.extend(&other) works like it does on a Vec<i32>
+impl<'a, T: Copy + 'a> Extend<&'a T> for MyVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for MyVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = MyVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: Clone> Clone for MyVec<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.deref(), f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for MyVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: Eq> Eq for MyVec<T> {}
+
+impl<T: PartialEq> PartialEq<[T]> for MyVec<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.deref() == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for MyVec<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.deref() == other.as_slice()
+    }
+}
+
+// the my_vec! version of vec!
+#[macro_export]
+macro_rules! my_vec {
+    ($($value:expr),* $(,)?) => {{
+        let mut vec = $crate::my_vec::MyVec::new();
+        $(vec.push($value);)*
+        vec
+    }};
+}
+
+// for value in my_vec, takes ownership of the values
+pub struct IntoIter<T> {
+    buf: RawVec<T>,
+    next: usize,
+    end: usize,
+}
+
+impl<T> IntoIterator for MyVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        // take the buffer out without running MyVec's drop
+        let vec = mem::ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&vec.buf) };
+        IntoIter {
+            buf,
+            next: 0,
+            end: vec.len,
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        self.next += 1;
+        unsafe { Some(ptr::read(self.buf.ptr.as_ptr().add(self.next - 1))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        self.end -= 1;
+        unsafe { Some(ptr::read(self.buf.ptr.as_ptr().add(self.end))) }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // drop whatever wasn't iterated over, the RawVec frees the memory
+        for _ in &mut *self {}
+    }
+}
+
+// for value in &my_vec and &mut my_vec just use the slice iterators
+impl<'a, T> IntoIterator for &'a MyVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MyVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+pub struct Drain<'a, T> {
+    vec: &'a mut MyVec<T>,
+    // the values still to hand out are next..end
+    next: usize,
+    end: usize,
+    // the values after the drained range that need moving back down
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        self.next += 1;
+        unsafe { Some(ptr::read(self.vec.ptr().add(self.next - 1))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        self.end -= 1;
+        unsafe { Some(ptr::read(self.vec.ptr().add(self.end))) }
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // drop anything not taken, then close the gap
+        for _ in &mut *self {}
+        let start = self.vec.len;
+        unsafe {
+            let base = self.vec.ptr();
+            ptr::copy(base.add(self.tail_start), base.add(start), self.tail_len);
+        }
+        self.vec.len = start + self.tail_len;
+    }
+}
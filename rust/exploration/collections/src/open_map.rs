@@ -0,0 +1,522 @@
+// A hash map written from scratch, to see what HashMap is doing in hash_examples.
+//
+// std's HashMap is a SwissTable (the hashbrown crate) and this is a simple
+// version of the same idea. Every key/value lives directly in one big array
+// of slots (open addressing, no linked lists) and next to it is an array of
+// one byte "control" tags, one per slot:
+//
+//   EMPTY    never used, a search can stop here
+//   DELETED  a tombstone, something was removed so a search has to carry on
+//   0..=127  full, and the number is 7 bits of the key's hash
+//
+// A lookup hashes the key, uses the rest of the hash to pick where to start,
+// then scans the control bytes a group of 16 at a time only comparing keys
+// whose 7 bits match, so most slots are skipped without touching the key.
+// If the group has no match and no EMPTY it jumps further each time (1, 2,
+// 3... groups along) which covers the whole table when its size is a power
+// of two.
+//
+// Tombstones are needed because emptying a slot could cut off a search for a
+// key that was pushed past it. They do fill up the table, so the resize
+// policy counts them: once full + tombstones passes 7/8 of the slots it
+// either rebuilds at the same size (mostly tombstones) or doubles.
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+use std::ops::Index;
+
+const EMPTY: u8 = 0xFF;
+const DELETED: u8 = 0x80;
+const GROUP: usize = 16;
+
+fn is_full(control: u8) -> bool {
+    control & 0x80 == 0
+}
+
+// 7/8 of the slots can be used before resizing
+fn max_load(buckets: usize) -> usize {
+    buckets / 8 * 7
+}
+
+// the top 7 bits go in the control byte, the rest picks the start position
+fn split_hash(hash: u64) -> (usize, u8) {
+    (hash as usize, (hash >> 57) as u8)
+}
+
+// The slots a search for a hash visits, in order.
+struct Probe {
+    position: usize,
+    stride: usize,
+    mask: usize,
+}
+
+impl Probe {
+    fn new(h1: usize, buckets: usize) -> Self {
+        Probe {
+            position: h1 & (buckets - 1),
+            stride: 0,
+            mask: buckets - 1,
+        }
+    }
+
+    // the slot indexes of the next group
+    fn next_group(&mut self) -> impl Iterator<Item = usize> {
+        let (start, mask) = (self.position, self.mask);
+        self.stride += GROUP;
+        self.position = (self.position + self.stride) & self.mask;
+        (0..GROUP).map(move |i| (start + i) & mask)
+    }
+}
+
+pub struct OpenMap<K, V, S = RandomState> {
+    control: Vec<u8>,
+    slots: Vec<Option<(K, V)>>,
+    len: usize,
+    tombstones: usize,
+    hasher: S,
+}
+
+impl<K, V> OpenMap<K, V, RandomState> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self
+    where
+        K: Hash + Eq,
+    {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, S: Default> Default for OpenMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> OpenMap<K, V, S> {
+    // Any BuildHasher works, e.g. BuildHasherDefault<SomeFasterHasher> or a
+    // deliberately bad one to see what collisions do.
+    pub fn with_hasher(hasher: S) -> Self {
+        OpenMap {
+            control: vec![],
+            slots: vec![],
+            len: 0,
+            tombstones: 0,
+            hasher,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // how many it can hold before it next resizes
+    pub fn capacity(&self) -> usize {
+        max_load(self.buckets())
+    }
+
+    // the number of slots, always 0 or a power of two of at least 16
+    pub fn buckets(&self) -> usize {
+        self.control.len()
+    }
+
+    pub fn tombstones(&self) -> usize {
+        self.tombstones
+    }
+
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    pub fn clear(&mut self) {
+        self.control.fill(EMPTY);
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+        self.tombstones = 0;
+    }
+
+    // in whatever order they are in the table
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.slots
+            .iter_mut()
+            .filter_map(|slot| slot.as_mut().map(|(key, value)| (&*key, value)))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OpenMap<K, V, S> {
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        let mut map = Self::with_hasher(hasher);
+        map.reserve(capacity);
+        map
+    }
+
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    // the slot holding key
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if self.len == 0 {
+            return None;
+        }
+        let (h1, h2) = split_hash(hash);
+        let mut probe = Probe::new(h1, self.buckets());
+        // there's always an EMPTY somewhere so this stops
+        loop {
+            for index in probe.next_group() {
+                match self.control[index] {
+                    EMPTY => return None,
+                    control if control == h2 => {
+                        let (k, _) = self.slots[index].as_ref().unwrap();
+                        if k.borrow() == key {
+                            return Some(index);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // the first EMPTY or DELETED slot a search for hash would reach
+    fn find_free(&self, hash: u64) -> usize {
+        let (h1, _) = split_hash(hash);
+        let mut probe = Probe::new(h1, self.buckets());
+        loop {
+            if let Some(index) = probe
+                .next_group()
+                .find(|index| !is_full(self.control[*index]))
+            {
+                return index;
+            }
+        }
+    }
+
+    // makes room for additional more without resizing
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len + additional;
+        if needed + self.tombstones <= self.capacity() {
+            return;
+        }
+        let buckets = if needed <= self.capacity() / 2 {
+            // mostly tombstones, clearing them out is enough
+            self.buckets()
+        } else {
+            let mut buckets = self.buckets().max(GROUP);
+            while max_load(buckets) < needed {
+                buckets *= 2;
+            }
+            buckets
+        };
+        self.rebuild(buckets);
+    }
+
+    // moves everything into a fresh table, which drops the tombstones
+    fn rebuild(&mut self, buckets: usize) {
+        let old = mem::take(&mut self.slots);
+        self.control = vec![EMPTY; buckets];
+        self.slots = (0..buckets).map(|_| None).collect();
+        self.tombstones = 0;
+        for (key, value) in old.into_iter().flatten() {
+            let hash = self.hash(&key);
+            let index = self.find_free(hash);
+            self.put(index, hash, key, value);
+        }
+    }
+
+    fn put(&mut self, index: usize, hash: u64, key: K, value: V) {
+        if self.control[index] == DELETED {
+            self.tombstones -= 1;
+        }
+        self.control[index] = split_hash(hash).1;
+        self.slots[index] = Some((key, value));
+    }
+
+    // Only ever called when the key isn't there.
+    fn insert_new(&mut self, hash: u64, key: K, value: V) -> usize {
+        self.reserve(1);
+        let index = self.find_free(hash);
+        self.put(index, hash, key, value);
+        self.len += 1;
+        index
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hash(key), key)?;
+        self.slots[index].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hash(key), key)?;
+        self.slots[index].as_mut().map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hash(&key);
+        match self.find(hash, &key) {
+            Some(index) => {
+                let (_, old) = self.slots[index].as_mut().unwrap();
+                Some(mem::replace(old, value))
+            }
+            None => {
+                self.insert_new(hash, key, value);
+                None
+            }
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hash(key), key)?;
+        self.control[index] = DELETED;
+        self.tombstones += 1;
+        self.len -= 1;
+        self.slots[index].take().map(|(_, value)| value)
+    }
+
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut keep: F) {
+        for index in 0..self.buckets() {
+            if let Some((key, value)) = &mut self.slots[index] {
+                if !keep(key, value) {
+                    self.slots[index] = None;
+                    self.control[index] = DELETED;
+                    self.tombstones += 1;
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash(&key);
+        match self.find(hash, &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                key,
+            }),
+        }
+    }
+}
+
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut OpenMap<K, V, S>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut OpenMap<K, V, S>,
+    // worked out already by entry() so inserting doesn't hash again
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> Entry<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, modify: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            modify(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    fn pair(&self) -> &(K, V) {
+        self.map.slots[self.index].as_ref().unwrap()
+    }
+
+    pub fn key(&self) -> &K {
+        &self.pair().0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.pair().1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().1
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.insert_new(self.hash, self.key, value);
+        &mut self.map.slots[index].as_mut().unwrap().1
+    }
+}
+
+impl<K, V, S, Q> Index<&Q> for OpenMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not in map")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for OpenMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for OpenMap<K, V, S> {
+    fn clone(&self) -> Self {
+        OpenMap {
+            control: self.control.clone(),
+            slots: self.slots.clone(),
+            len: self.len,
+            tombstones: self.tombstones,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+// equal if they have the same pairs, wherever they are in the table
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher> PartialEq for OpenMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for OpenMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = OpenMap::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for OpenMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S> IntoIterator for OpenMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<(K, V)>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.into_iter().flatten()
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    slots: std::slice::Iter<'a, Option<(K, V)>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots
+            .find_map(|slot| slot.as_ref().map(|(key, value)| (key, value)))
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a OpenMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,248 @@
+// Looking inside UTF-8 strings, the table version of string_examples.
+//
+// A String is a Vec<u8> of UTF-8. One char (a unicode code point) takes 1 to 4
+// bytes, and what a person sees as one character (a grapheme cluster) can be
+// several chars, e.g. "é" written as "e" + a combining accent, or a flag made
+// of two regional indicators. So for "Yes ✅" len() is 7 bytes but 5 chars.
+//
+// Slicing by byte range can land in the middle of a char (a panic with &s[..]),
+// so the substring functions here count in chars or graphemes instead.
+use std::ops::Range;
+
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodePoint {
+    pub char_index: usize,
+    pub byte_offset: usize,
+    pub value: char,
+    pub category: GeneralCategory,
+}
+
+impl CodePoint {
+    // U+2705
+    pub fn notation(&self) -> String {
+        format!("U+{:04X}", self.value as u32)
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut buffer = [0; 4];
+        self.value.encode_utf8(&mut buffer).as_bytes().to_vec()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grapheme {
+    pub index: usize,
+    pub byte_range: Range<usize>,
+    pub text: String,
+    pub code_points: Vec<CodePoint>,
+}
+
+pub fn graphemes(text: &str) -> Vec<Grapheme> {
+    let mut char_index = 0;
+    text.grapheme_indices(true)
+        .enumerate()
+        .map(|(index, (byte_offset, grapheme))| {
+            let code_points = grapheme
+                .char_indices()
+                .map(|(offset, value)| {
+                    let code_point = CodePoint {
+                        char_index,
+                        byte_offset: byte_offset + offset,
+                        value,
+                        category: get_general_category(value),
+                    };
+                    char_index += 1;
+                    code_point
+                })
+                .collect();
+            Grapheme {
+                index,
+                byte_range: byte_offset..byte_offset + grapheme.len(),
+                text: grapheme.to_string(),
+                code_points,
+            }
+        })
+        .collect()
+}
+
+// Every byte offset you could slice at, including the end.
+pub fn char_boundaries(text: &str) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|i| text.is_char_boundary(*i))
+        .collect()
+}
+
+// Like &text[range] but counting chars, None if the range is past the end.
+pub fn char_substring(text: &str, range: Range<usize>) -> Option<&str> {
+    substring(text.char_indices().map(|(offset, _)| offset), text, range)
+}
+
+// Like &text[range] but counting graphemes, so a flag or accent is never split.
+pub fn grapheme_substring(text: &str, range: Range<usize>) -> Option<&str> {
+    substring(
+        text.grapheme_indices(true).map(|(offset, _)| offset),
+        text,
+        range,
+    )
+}
+
+fn substring<I: Iterator<Item = usize>>(
+    starts: I,
+    text: &str,
+    range: Range<usize>,
+) -> Option<&str> {
+    if range.start > range.end {
+        return None;
+    }
+    // the start offset of each piece, then the end of the text
+    let offsets: Vec<usize> = starts.chain([text.len()]).collect();
+    Some(&text[*offsets.get(range.start)?..*offsets.get(range.end)?])
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidBytes {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+// Splits bytes into the valid text and the runs of bytes that aren't UTF-8,
+// with their offsets. The text gets a U+FFFD � in place of each invalid run.
+pub fn decode(bytes: &[u8]) -> (String, Vec<InvalidBytes>) {
+    let mut text = String::new();
+    let mut invalid = vec![];
+    let mut offset = 0;
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(chunk.valid());
+        offset += chunk.valid().len();
+        if !chunk.invalid().is_empty() {
+            text.push(char::REPLACEMENT_CHARACTER);
+            invalid.push(InvalidBytes {
+                offset,
+                bytes: chunk.invalid().to_vec(),
+            });
+            offset += chunk.invalid().len();
+        }
+    }
+    (text, invalid)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let pairs: Vec<String> = bytes.iter().map(|byte| format!("{byte:02X}")).collect();
+    pairs.join(" ")
+}
+
+// Control characters would mess up the table so show them escaped.
+fn printable(text: &str) -> String {
+    if text.chars().any(char::is_control) {
+        text.escape_debug().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// A stretch of the text and where it really starts in the bytes it was
+// decoded from. An invalid run is a piece of its own, a U+FFFD in the text
+// standing in for the 1 to 3 bytes that were there.
+struct Piece {
+    text_offset: usize,
+    byte_offset: usize,
+    invalid: Option<Vec<u8>>,
+}
+
+// One row per code point, grouped by grapheme.
+pub fn table(text: &str) -> String {
+    let whole = Piece {
+        text_offset: 0,
+        byte_offset: 0,
+        invalid: None,
+    };
+    table_of(text, &[whole], text.len())
+}
+
+// The table for text decoded from `length` bytes, with the byte offsets and
+// UTF-8 of the bytes rather than of the decoded text.
+fn table_of(text: &str, pieces: &[Piece], length: usize) -> String {
+    // the byte offset of a char in the text, and its bytes
+    let original = |code_point: &CodePoint| {
+        let index = pieces.partition_point(|piece| piece.text_offset <= code_point.byte_offset);
+        let piece = &pieces[index - 1];
+        let offset = piece.byte_offset + code_point.byte_offset - piece.text_offset;
+        let bytes = piece.invalid.clone().unwrap_or_else(|| code_point.bytes());
+        (offset, bytes)
+    };
+    let mut lines = vec![format!(
+        "{:>8} {:<8} {:>5} {:>5} {:<9} {:<12} {}",
+        "grapheme", "text", "char", "byte", "code", "utf-8", "category"
+    )];
+    let mut boundaries = vec![];
+    for grapheme in graphemes(text) {
+        for (i, code_point) in grapheme.code_points.iter().enumerate() {
+            // only label the first row of each grapheme
+            let (index, shown) = if i == 0 {
+                (grapheme.index.to_string(), printable(&grapheme.text))
+            } else {
+                (String::new(), String::new())
+            };
+            let (offset, bytes) = original(code_point);
+            boundaries.push(offset);
+            lines.push(format!(
+                "{:>8} {:<8} {:>5} {:>5} {:<9} {:<12} {} ({:?})",
+                index,
+                shown,
+                code_point.char_index,
+                offset,
+                code_point.notation(),
+                hex(&bytes),
+                code_point.category.abbreviation(),
+                code_point.category
+            ));
+        }
+    }
+    boundaries.push(length);
+    lines.push(format!(
+        "{} bytes, {} chars, {} graphemes",
+        length,
+        text.chars().count(),
+        text.graphemes(true).count()
+    ));
+    lines.push(format!("char boundaries: {boundaries:?}"));
+    lines.join("\n")
+}
+
+// The table, with any invalid UTF-8 reported first. This walks the chunks
+// itself rather than using decode, to keep track of where each piece of the
+// text came from: after an invalid run the offsets in the decoded text are
+// no longer the offsets in the bytes.
+pub fn report(bytes: &[u8]) -> String {
+    let mut lines = vec![];
+    let mut text = String::new();
+    let mut pieces = vec![];
+    let mut offset = 0;
+    for chunk in bytes.utf8_chunks() {
+        pieces.push(Piece {
+            text_offset: text.len(),
+            byte_offset: offset,
+            invalid: None,
+        });
+        text.push_str(chunk.valid());
+        offset += chunk.valid().len();
+        if !chunk.invalid().is_empty() {
+            lines.push(format!(
+                "invalid UTF-8 at byte {offset}: {}",
+                hex(chunk.invalid())
+            ));
+            pieces.push(Piece {
+                text_offset: text.len(),
+                byte_offset: offset,
+                invalid: Some(chunk.invalid().to_vec()),
+            });
+            text.push(char::REPLACEMENT_CHARACTER);
+            offset += chunk.invalid().len();
+        }
+    }
+    lines.push(table_of(&text, &pieces, bytes.len()));
+    lines.join("\n")
+}
@@ -0,0 +1,237 @@
+use collections::checkout::{
+    best_discounts, checkout, pence, Basket, Catalogue, CheckoutError, Offer, Product, Unit,
+};
+
+fn catalogue() -> Catalogue {
+    let mut catalogue = Catalogue::new();
+    catalogue.add_product(Product::new("apple", "Apple", 50, Unit::Each));
+    catalogue.add_product(Product::new("banana", "Banana", 30, Unit::Each));
+    catalogue.add_product(Product::new("orange", "Orange", 40, Unit::Each));
+    catalogue.add_product(Product::new("grapes", "Grapes", 399, Unit::PerKg));
+    catalogue
+}
+
+#[test]
+fn prices_each_and_by_weight() {
+    let catalogue = catalogue();
+    assert_eq!(catalogue.get("apple").unwrap().price_of(3), 150);
+    // 399p/kg * 0.25 kg = 99.75p, rounded
+    assert_eq!(catalogue.get("grapes").unwrap().price_of(250), 100);
+    assert_eq!(catalogue.get("grapes").unwrap().price_of(1_001), 399);
+    assert_eq!(pence(1_234), "£12.34");
+    assert_eq!(pence(5), "£0.05");
+}
+
+#[test]
+fn basket_add_remove_update() {
+    let catalogue = catalogue();
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 2).unwrap();
+    basket.add(&catalogue, "apple", 1).unwrap();
+    basket.add(&catalogue, "banana", 4).unwrap();
+    assert_eq!(basket.quantity("apple"), 3);
+    assert_eq!(
+        basket.add(&catalogue, "kiwi", 1),
+        Err(CheckoutError::UnknownProduct("kiwi".to_string()))
+    );
+    basket.remove("banana", 1);
+    assert_eq!(basket.quantity("banana"), 3);
+    basket.remove("banana", 10);
+    assert_eq!(basket.quantity("banana"), 0);
+    assert!(!basket.items.contains_key("banana"));
+    basket.update(&catalogue, "orange", 5).unwrap();
+    basket.update(&catalogue, "apple", 0).unwrap();
+    assert_eq!(basket.items.keys().collect::<Vec<_>>(), vec!["orange"]);
+    assert_eq!(basket.subtotal(&catalogue), Ok(200));
+}
+
+#[test]
+fn offers_cannot_be_on_weighed_products() {
+    let mut catalogue = catalogue();
+    let offer = Offer::MultiBuy {
+        code: "grapes".to_string(),
+        buy: 3,
+        pay_for: 2,
+    };
+    assert_eq!(
+        catalogue.add_offer(offer),
+        Err(CheckoutError::OfferOnWeighedProduct("grapes".to_string()))
+    );
+}
+
+#[test]
+fn three_for_two() {
+    let mut catalogue = catalogue();
+    catalogue
+        .add_offer(Offer::MultiBuy {
+            code: "apple".to_string(),
+            buy: 3,
+            pay_for: 2,
+        })
+        .unwrap();
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 7).unwrap();
+    let receipt = checkout(&catalogue, &basket).unwrap();
+    assert_eq!(receipt.subtotal, 350);
+    assert_eq!(receipt.savings, vec![("Apple 3 for 2 x2".to_string(), 100)]);
+    assert_eq!(receipt.total, 250);
+}
+
+#[test]
+fn best_combination_beats_greedy() {
+    let mut catalogue = catalogue();
+    // On its own 3 for 2 saves 50p, the biggest single saving...
+    catalogue
+        .add_offer(Offer::MultiBuy {
+            code: "apple".to_string(),
+            buy: 3,
+            pay_for: 2,
+        })
+        .unwrap();
+    // ...but with 3 apples and 3 bananas, three of these save 3 x 30p = 90p.
+    catalogue
+        .add_offer(Offer::Linked {
+            trigger: "apple".to_string(),
+            target: "banana".to_string(),
+            percent_off: 100,
+        })
+        .unwrap();
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 3).unwrap();
+    basket.add(&catalogue, "banana", 3).unwrap();
+    let applied = best_discounts(&catalogue, &basket);
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].times, 3);
+    assert_eq!(applied[0].saving, 90);
+
+    // With 6 apples it's best to use both.
+    basket.add(&catalogue, "apple", 3).unwrap();
+    let saving: u64 = best_discounts(&catalogue, &basket)
+        .iter()
+        .map(|a| a.saving)
+        .sum();
+    assert_eq!(saving, 50 + 90);
+}
+
+#[test]
+fn half_price_and_multi_price() {
+    let mut catalogue = catalogue();
+    catalogue
+        .add_offer(Offer::Linked {
+            trigger: "apple".to_string(),
+            target: "banana".to_string(),
+            percent_off: 50,
+        })
+        .unwrap();
+    catalogue
+        .add_offer(Offer::MultiPrice {
+            code: "orange".to_string(),
+            count: 3,
+            price: 100,
+        })
+        .unwrap();
+    // A "deal" that costs more than paying full price is never used.
+    catalogue
+        .add_offer(Offer::MultiPrice {
+            code: "banana".to_string(),
+            count: 2,
+            price: 100,
+        })
+        .unwrap();
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 1).unwrap();
+    basket.add(&catalogue, "banana", 2).unwrap();
+    basket.add(&catalogue, "orange", 4).unwrap();
+    basket.add(&catalogue, "grapes", 500).unwrap();
+    let receipt = checkout(&catalogue, &basket).unwrap();
+    assert_eq!(receipt.subtotal, 50 + 60 + 160 + 200);
+    assert_eq!(receipt.total_savings, 15 + 20);
+    assert_eq!(receipt.total, receipt.subtotal - 35);
+    assert_eq!(
+        receipt.to_string(),
+        "\
+Apple                        1 @ £0.50     £0.50
+Banana                       2 @ £0.30     £0.60
+Orange                       4 @ £0.40     £1.60
+Grapes             0.500 kg @ £3.99/kg     £2.00
+Subtotal                                   £4.70
+Savings
+  Banana 50% off with Apple               -£0.15
+  Orange 3 for £1.00                      -£0.20
+Total savings                             -£0.35
+Total to pay                               £4.35"
+    );
+}
+
+#[test]
+fn empty_basket() {
+    let receipt = checkout(&catalogue(), &Basket::new()).unwrap();
+    assert_eq!(receipt.total, 0);
+    assert!(receipt.savings.is_empty());
+}
+
+#[test]
+fn big_baskets_with_several_offers() {
+    let mut catalogue = catalogue();
+    for offer in [
+        Offer::MultiBuy {
+            code: "apple".to_string(),
+            buy: 3,
+            pay_for: 2,
+        },
+        Offer::MultiPrice {
+            code: "apple".to_string(),
+            count: 2,
+            price: 90,
+        },
+        Offer::Linked {
+            trigger: "apple".to_string(),
+            target: "banana".to_string(),
+            percent_off: 50,
+        },
+        Offer::Linked {
+            trigger: "orange".to_string(),
+            target: "banana".to_string(),
+            percent_off: 25,
+        },
+        Offer::MultiBuy {
+            code: "orange".to_string(),
+            buy: 2,
+            pay_for: 1,
+        },
+    ] {
+        catalogue.add_offer(offer).unwrap();
+    }
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 60).unwrap();
+    basket.add(&catalogue, "banana", 40).unwrap();
+    basket.add(&catalogue, "orange", 30).unwrap();
+    // every apple in a 3 for 2 (50p / 3 each) beats 2 for 90p (5p each) and
+    // the linked offer (15p each), and 2 for 1 oranges (20p each) beats 25%
+    // off a banana (7p each)
+    let applied = best_discounts(&catalogue, &basket);
+    let times: Vec<u32> = applied.iter().map(|applied| applied.times).collect();
+    assert_eq!(times, [20, 15]);
+    let saving: u64 = applied.iter().map(|applied| applied.saving).sum();
+    assert_eq!(saving, 20 * 50 + 15 * 40);
+}
+
+#[test]
+fn offers_on_products_taken_out_are_skipped() {
+    let mut catalogue = catalogue();
+    let offer = Offer::MultiBuy {
+        code: "orange".to_string(),
+        buy: 2,
+        pay_for: 1,
+    };
+    catalogue.add_offer(offer.clone()).unwrap();
+    catalogue.products.shift_remove("orange");
+    let mut basket = Basket::new();
+    basket.add(&catalogue, "apple", 2).unwrap();
+    assert!(best_discounts(&catalogue, &basket).is_empty());
+    assert_eq!(checkout(&catalogue, &basket).unwrap().total, 100);
+    assert_eq!(
+        offer.describe(&catalogue),
+        Err(CheckoutError::UnknownProduct("orange".to_string()))
+    );
+}
@@ -0,0 +1,38 @@
+use collections::collection_questions::{question_1, question_2, run_questions};
+use collections::examples::*;
+use output::Buffer;
+
+#[test]
+fn vectors_and_strings() {
+    let (v, mine) = vector_examples(&mut Buffer::new());
+    assert_eq!(v, [11, 30, 11, 12, 13]);
+    assert_eq!(mine, v);
+    assert_eq!(
+        string_examples(&mut Buffer::new()),
+        ("here we go again!".to_string(), 7, 5)
+    );
+}
+
+#[test]
+fn basket_and_checkout() {
+    let basket = hash_examples(&mut Buffer::new());
+    let items: Vec<(&str, i32)> = basket.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(items, [("apple", 10), ("banana", 102), ("orange", 1)]);
+
+    let receipt = checkout_example(&mut Buffer::new());
+    assert_eq!(receipt.subtotal, 780);
+    assert_eq!(receipt.total_savings, 165);
+    assert_eq!(receipt.total, 615);
+}
+
+#[test]
+fn questions() {
+    assert_eq!(question_1(&[1, 2, 3, 4, 5, 6, 6, 6, 7, 8]), (5.5, 6));
+    assert_eq!(question_1(&[3, 1, 2, 3]).0, 2.5);
+    assert_eq!(question_2("!!!"), "!!!");
+    assert_eq!(question_2("first fox!"), "irstfay oxfay!");
+
+    let mut out = Buffer::new();
+    run_questions(&mut out);
+    assert_eq!(out.lines(), ["median, mode is is 5.5, 6", "pig_latin: !!!"]);
+}
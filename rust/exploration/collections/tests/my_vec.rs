@@ -0,0 +1,243 @@
+use collections::my_vec;
+use collections::my_vec::MyVec;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+// Keeps a count of how many are alive so the tests can check nothing is
+// dropped twice (count goes negative) or leaked (count stays above zero).
+#[derive(Debug)]
+struct Tracked {
+    value: u32,
+    live: Rc<Cell<isize>>,
+}
+
+impl Tracked {
+    fn new(value: u32, live: &Rc<Cell<isize>>) -> Self {
+        live.set(live.get() + 1);
+        Tracked {
+            value,
+            live: Rc::clone(live),
+        }
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        Tracked::new(self.value, &self.live)
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+// The vector_examples steps on both.
+#[test]
+fn vector_examples_match_vec() {
+    let mut v: MyVec<i32> = MyVec::new();
+    let v2 = my_vec![1, 2, 3];
+    v.push(1);
+    v.push(20);
+    v.extend(&*v2);
+    for i in &mut v {
+        *i += 10;
+    }
+    assert_eq!(v[1], 30);
+    assert_eq!(v.get(100), None);
+    assert_eq!(v, vec![11, 30, 11, 12, 13]);
+    assert_eq!(format!("{v:?}"), "[11, 30, 11, 12, 13]");
+    assert_eq!(&v[1..3], &[30, 11]);
+    assert_eq!(v.iter().sum::<i32>(), 77);
+}
+
+#[test]
+fn insert_remove_and_drain() {
+    let mut v: MyVec<String> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    v.insert(0, "start".to_string());
+    v.insert(6, "end".to_string());
+    assert_eq!(v.remove(3), "c");
+    let drained: Vec<String> = v.drain(1..3).collect();
+    assert_eq!(drained, ["a", "b"]);
+    assert_eq!(
+        v.iter().map(String::as_str).collect::<Vec<_>>(),
+        ["start", "d", "e", "end"]
+    );
+    assert_eq!(v.drain(..).next_back().as_deref(), Some("end"));
+    assert!(v.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "insertion index out of bounds")]
+fn insert_past_the_end_panics() {
+    let mut v = my_vec![1, 2];
+    v.insert(3, 3);
+}
+
+#[test]
+#[should_panic(expected = "drain end is out of bounds")]
+fn drain_past_the_end_panics() {
+    let mut v = my_vec![1, 2];
+    v.drain(1..3);
+}
+
+#[test]
+fn capacity_doubles() {
+    let mut v = MyVec::new();
+    assert_eq!(v.capacity(), 0);
+    let mut capacities = vec![];
+    for i in 0..20 {
+        v.push(i);
+        if capacities.last() != Some(&v.capacity()) {
+            capacities.push(v.capacity());
+        }
+    }
+    assert_eq!(capacities, [4, 8, 16, 32]);
+    assert!(MyVec::<u8>::with_capacity(100).capacity() >= 100);
+}
+
+#[test]
+fn zero_sized_types() {
+    let mut v: MyVec<()> = MyVec::new();
+    assert_eq!(v.capacity(), usize::MAX);
+    for _ in 0..1_000 {
+        v.push(());
+    }
+    v.insert(500, ());
+    v.remove(0);
+    assert_eq!(v.len(), 1_000);
+    assert_eq!(v.drain(10..20).count(), 10);
+    assert_eq!(v.pop(), Some(()));
+    assert_eq!(v.into_iter().count(), 989);
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    {
+        let mut v: MyVec<Tracked> = (0..10).map(|i| Tracked::new(i, &live)).collect();
+        assert_eq!(live.get(), 10);
+        v.truncate(8);
+        assert_eq!(live.get(), 8);
+        // only take one value out of the drain, the rest get dropped with it
+        assert_eq!(v.drain(2..5).next().map(|t| t.value), Some(2));
+        assert_eq!(live.get(), 5);
+        let values: Vec<u32> = v.iter().map(|t| t.value).collect();
+        assert_eq!(values, [0, 1, 5, 6, 7]);
+
+        // the drained values and tail leak, but nothing is dropped twice
+        std::mem::forget(v.drain(1..2));
+        assert_eq!(v.len(), 1);
+
+        let clone = v.clone();
+        let mut iter = clone.into_iter();
+        iter.next();
+    }
+    // the forgotten drain leaked 4: the one it held and the tail of 3
+    assert_eq!(live.get(), 4);
+}
+
+#[test]
+fn zero_sized_types_are_dropped() {
+    #[derive(Clone, Debug)]
+    struct Zst;
+    thread_local!(static DROPS: Cell<usize> = const { Cell::new(0) });
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROPS.with(|drops| drops.set(drops.get() + 1));
+        }
+    }
+    let mut v: MyVec<Zst> = (0..10).map(|_| Zst).collect();
+    v.remove(0);
+    v.truncate(5);
+    v.into_iter().next();
+    assert_eq!(DROPS.with(Cell::get), 10);
+}
+
+// Does the same random things to a MyVec and a Vec and checks they agree
+// after every step. make turns a random number into a T.
+fn differential<T: PartialEq + Debug, F: FnMut(u32) -> T>(seed: u64, steps: usize, mut make: F) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut mine: MyVec<T> = MyVec::new();
+    let mut std: Vec<T> = Vec::new();
+    for step in 0..steps {
+        let value: u32 = rng.gen_range(0..1_000);
+        let len = std.len();
+        match rng.gen_range(0..10) {
+            0..=2 => {
+                mine.push(make(value));
+                std.push(make(value));
+            }
+            3 => assert_eq!(mine.pop(), std.pop()),
+            4 => {
+                let index = rng.gen_range(0..=len);
+                mine.insert(index, make(value));
+                std.insert(index, make(value));
+            }
+            5 if len > 0 => {
+                let index = rng.gen_range(0..len);
+                assert_eq!(mine.remove(index), std.remove(index));
+            }
+            6 => {
+                let count = rng.gen_range(0..5);
+                mine.extend((0..count).map(|i| make(value + i)));
+                std.extend((0..count).map(|i| make(value + i)));
+            }
+            7 => {
+                let start = rng.gen_range(0..=len);
+                let end = rng.gen_range(start..=len);
+                // sometimes only part of the drain is used
+                let take = rng.gen_range(0..=end - start);
+                let a: Vec<T> = mine.drain(start..end).take(take).collect();
+                let b: Vec<T> = std.drain(start..end).take(take).collect();
+                assert_eq!(a, b);
+            }
+            8 if len > 0 => {
+                let index = rng.gen_range(0..len);
+                mine[index] = make(value);
+                std[index] = make(value);
+            }
+            9 if rng.gen_ratio(1, 10) => {
+                let new_len = rng.gen_range(0..=len);
+                mine.truncate(new_len);
+                std.truncate(new_len);
+            }
+            _ => {
+                let index = rng.gen_range(0..=len);
+                assert_eq!(mine.get(index), std.get(index));
+            }
+        }
+        assert_eq!(mine.len(), std.len(), "step {step}");
+        assert_eq!(&*mine, std.as_slice(), "step {step}");
+    }
+    let taken: Vec<T> = mine.into_iter().rev().collect();
+    std.reverse();
+    assert_eq!(taken, std);
+}
+
+#[test]
+fn random_operations_match_vec() {
+    differential(38, 5_000, |value| value);
+    differential(39, 5_000, |value| value.to_string());
+    differential(40, 5_000, |_| ());
+}
+
+#[test]
+fn random_operations_drop_everything() {
+    let live = Rc::new(Cell::new(0));
+    differential(41, 5_000, |value| Tracked::new(value, &live));
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,168 @@
+use collections::open_map::{Entry, OpenMap};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+// Every key hashes the same, so every lookup has to walk past all the others.
+#[derive(Default)]
+struct Terrible;
+
+impl Hasher for Terrible {
+    fn finish(&self) -> u64 {
+        42
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+type Colliding = BuildHasherDefault<Terrible>;
+
+fn sorted<K: Ord + Clone, V: Clone>(pairs: impl Iterator<Item = (K, V)>) -> Vec<(K, V)> {
+    let mut pairs: Vec<(K, V)> = pairs.collect();
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    pairs
+}
+
+// The steps from hash_examples.
+#[test]
+fn basket() {
+    let mut basket: OpenMap<String, i32> = OpenMap::new();
+    let apple = "apple".to_string();
+    assert_eq!(basket.insert(apple.clone(), 3), None);
+    assert_eq!(basket.insert("banana".to_string(), 2), None);
+    assert_eq!(basket.get(&apple), Some(&3));
+    assert_eq!(basket["banana"], 2);
+    assert_eq!(basket.insert(apple, 10), Some(3));
+    basket.entry("orange".to_string()).or_insert(0);
+    basket.entry("banana".to_string()).or_insert(0);
+    basket.entry("orange".to_string()).and_modify(|v| *v += 1);
+    *basket.entry("banana".to_string()).or_insert(0) += 100;
+    assert_eq!(
+        sorted(basket.iter().map(|(k, v)| (k.as_str(), *v))),
+        [("apple", 10), ("banana", 102), ("orange", 1)]
+    );
+    assert_eq!(basket.remove("apple"), Some(10));
+    assert_eq!(basket.remove("apple"), None);
+    assert!(!basket.contains_key("apple"));
+    assert_eq!(basket.len(), 2);
+}
+
+#[test]
+fn entries() {
+    let mut map: OpenMap<&str, Vec<i32>> = OpenMap::new();
+    match map.entry("a") {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &"a");
+            entry.insert(vec![1]);
+        }
+        Entry::Occupied(_) => panic!("should be vacant"),
+    }
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            entry.get_mut().push(2);
+            assert_eq!(entry.insert(vec![3]), vec![1, 2]);
+        }
+        Entry::Vacant(_) => panic!("should be occupied"),
+    }
+    map.entry("b").or_default().push(4);
+    assert_eq!(map["a"], vec![3]);
+    assert_eq!(map["b"], vec![4]);
+}
+
+#[test]
+fn grows_in_powers_of_two() {
+    let mut map = OpenMap::new();
+    assert_eq!(map.buckets(), 0);
+    let mut buckets = vec![];
+    for i in 0..100 {
+        map.insert(i, i);
+        if buckets.last() != Some(&map.buckets()) {
+            buckets.push(map.buckets());
+        }
+    }
+    assert_eq!(buckets, [16, 32, 64, 128]);
+    let map: OpenMap<u8, u8> = OpenMap::with_capacity(100);
+    assert!(map.capacity() >= 100);
+}
+
+#[test]
+fn tombstones_are_cleared_without_growing() {
+    let mut map = OpenMap::new();
+    for i in 0..10 {
+        map.insert(i, ());
+    }
+    let buckets = map.buckets();
+    // keep ten in the map while churning through thousands of keys
+    for i in 10..5_000 {
+        map.insert(i, ());
+        map.remove(&(i - 10));
+        assert!(map.tombstones() + map.len() <= map.capacity());
+    }
+    assert_eq!(map.buckets(), buckets);
+    assert_eq!(map.len(), 10);
+    assert_eq!(map.keys().min(), Some(&4_990));
+}
+
+#[test]
+fn works_when_everything_collides() {
+    let mut map: OpenMap<u32, u32, Colliding> = OpenMap::default();
+    for i in 0..200 {
+        map.insert(i, i * 2);
+    }
+    map.retain(|key, _| key % 3 != 0);
+    for i in 0..200 {
+        assert_eq!(map.get(&i), (i % 3 != 0).then_some(&(i * 2)));
+    }
+    assert_eq!(map.len(), 133);
+}
+
+#[test]
+fn equality_ignores_layout() {
+    let a: OpenMap<i32, i32> = (0..50).map(|i| (i, i)).collect();
+    let mut b: OpenMap<i32, i32> = (0..100).rev().map(|i| (i, i)).collect();
+    b.retain(|key, _| *key < 50);
+    assert_eq!(a, b);
+    b.insert(0, 1);
+    assert_ne!(a, b);
+}
+
+// Does the same random things to an OpenMap and a HashMap and checks they
+// agree after every step.
+fn differential<S: std::hash::BuildHasher + Default>(seed: u64, steps: usize, keys: u16) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut open: OpenMap<u16, u32, S> = OpenMap::default();
+    let mut std: HashMap<u16, u32> = HashMap::new();
+    for step in 0..steps {
+        let key = rng.gen_range(0..keys);
+        let value = rng.gen();
+        match rng.gen_range(0..7) {
+            0 | 1 => assert_eq!(open.insert(key, value), std.insert(key, value)),
+            2 | 3 => assert_eq!(open.remove(&key), std.remove(&key)),
+            4 => {
+                let a = *open.entry(key).and_modify(|v| *v /= 2).or_insert(value);
+                let b = *std.entry(key).and_modify(|v| *v /= 2).or_insert(value);
+                assert_eq!(a, b);
+            }
+            5 if rng.gen_ratio(1, 50) => {
+                open.retain(|k, _| k % 2 == 0);
+                std.retain(|k, _| k % 2 == 0);
+            }
+            _ => assert_eq!(open.get(&key), std.get(&key)),
+        }
+        assert_eq!(open.len(), std.len(), "step {step}");
+    }
+    assert_eq!(
+        sorted(open.iter().map(|(k, v)| (*k, *v))),
+        sorted(std.iter().map(|(k, v)| (*k, *v)))
+    );
+    let rebuilt: OpenMap<u16, u32, S> = std.into_iter().collect();
+    assert!(rebuilt == open);
+}
+
+#[test]
+fn random_operations_match_hash_map() {
+    differential::<std::collections::hash_map::RandomState>(39, 20_000, 1_000);
+    differential::<std::collections::hash_map::RandomState>(40, 20_000, 20);
+    differential::<Colliding>(41, 3_000, 100);
+}
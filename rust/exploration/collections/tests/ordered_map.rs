@@ -0,0 +1,178 @@
+use collections::ordered_map::{Entry, OrderedMap};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+fn keys<V>(map: &OrderedMap<String, V>) -> Vec<&str> {
+    map.keys().map(|key| key.as_str()).collect()
+}
+
+// The steps from hash_examples, done to both kinds of map.
+#[test]
+fn basket_matches_hash_map() {
+    let mut ordered: OrderedMap<String, i32> = OrderedMap::new();
+    let mut hashed: HashMap<String, i32> = HashMap::new();
+    macro_rules! both {
+        ($map:ident => $body:expr) => {{
+            let $map = &mut ordered;
+            let a = $body;
+            let $map = &mut hashed;
+            let b = $body;
+            assert_eq!(a, b);
+        }};
+    }
+    both!(m => m.insert("apple".to_string(), 3));
+    both!(m => m.insert("banana".to_string(), 2));
+    both!(m => m.insert("apple".to_string(), 10));
+    both!(m => *m.entry("orange".to_string()).or_insert(0));
+    both!(m => *m.entry("banana".to_string()).or_insert(0));
+    both!(m => { m.entry("orange".to_string()).and_modify(|v| *v += 1); });
+    both!(m => { *m.entry("banana".to_string()).or_insert(0) += 100; });
+    both!(m => m["banana"]);
+    both!(m => m.get("apple").copied());
+    both!(m => m.get("pear").copied());
+    both!(m => m.len());
+
+    assert_eq!(keys(&ordered), vec!["apple", "banana", "orange"]);
+    assert_eq!(
+        format!("{ordered:?}"),
+        r#"{"apple": 10, "banana": 102, "orange": 1}"#
+    );
+}
+
+#[test]
+fn overwriting_keeps_position() {
+    let mut map: OrderedMap<String, i32> = [("a", 1), ("b", 2), ("c", 3)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+    assert_eq!(map.insert("a".to_string(), 100), Some(1));
+    assert_eq!(keys(&map), vec!["a", "b", "c"]);
+    assert_eq!(map.first(), Some((&"a".to_string(), &100)));
+    assert_eq!(map.last(), Some((&"c".to_string(), &3)));
+    map["b"] += 1;
+    for (_, value) in &mut map {
+        *value *= 2;
+    }
+    let values: Vec<i32> = map.values().copied().collect();
+    assert_eq!(values, vec![200, 6, 6]);
+}
+
+#[test]
+fn shift_and_swap_remove() {
+    let letters = ["a", "b", "c", "d", "e"];
+    let mut map: OrderedMap<String, usize> = letters
+        .iter()
+        .enumerate()
+        .map(|(i, k)| (k.to_string(), i))
+        .collect();
+
+    assert_eq!(map.shift_remove("b"), Some(1));
+    assert_eq!(keys(&map), vec!["a", "c", "d", "e"]);
+    assert_eq!(map.get_index_of("e"), Some(3));
+
+    assert_eq!(map.swap_remove("a"), Some(0));
+    assert_eq!(keys(&map), vec!["e", "c", "d"]);
+    assert_eq!(map.get_index_of("e"), Some(0));
+    assert_eq!(map["e"], 4);
+
+    assert_eq!(map.swap_remove("d"), Some(3));
+    assert_eq!(map.shift_remove("zzz"), None);
+    assert_eq!(keys(&map), vec!["e", "c"]);
+}
+
+#[test]
+fn sorting() {
+    let mut map: OrderedMap<String, i32> = [("pear", 2), ("apple", 5), ("fig", 2), ("kiwi", 1)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+    map.sort_keys();
+    assert_eq!(keys(&map), vec!["apple", "fig", "kiwi", "pear"]);
+    map.sort_values();
+    // stable, fig was before pear
+    assert_eq!(keys(&map), vec!["kiwi", "fig", "pear", "apple"]);
+    map.sort_by(|_, a, _, b| b.cmp(a));
+    assert_eq!(keys(&map), vec!["apple", "fig", "pear", "kiwi"]);
+    assert_eq!(map["kiwi"], 1);
+    assert_eq!(map.get_index_of("pear"), Some(2));
+    map.retain(|_, v| *v == 2);
+    assert_eq!(keys(&map), vec!["fig", "pear"]);
+    assert_eq!(map.get_index_of("pear"), Some(1));
+}
+
+#[test]
+fn entry_api() {
+    let mut map: OrderedMap<&str, Vec<i32>> = OrderedMap::new();
+    map.entry("a").or_default().push(1);
+    map.entry("a").or_default().push(2);
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.index(), 0);
+            assert_eq!(entry.insert(vec![9]), vec![1, 2]);
+        }
+        Entry::Vacant(_) => panic!("a is there"),
+    }
+    match map.entry("b") {
+        Entry::Vacant(entry) => assert_eq!(*entry.key(), "b"),
+        Entry::Occupied(_) => panic!("b is not there"),
+    }
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.entry("c").or_insert_with(|| vec![3]), &vec![3]);
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        vec![("a", vec![9]), ("c", vec![3])]
+    );
+}
+
+// Random inserts / removes / updates on both, checking they always agree
+// and that the OrderedMap order is what a Vec of keys would say.
+#[test]
+fn random_operations_match_hash_map() {
+    let mut rng = StdRng::seed_from_u64(35);
+    let mut ordered: OrderedMap<u8, u32> = OrderedMap::new();
+    let mut hashed: HashMap<u8, u32> = HashMap::new();
+    let mut order: Vec<u8> = Vec::new();
+    for step in 0..5_000 {
+        let key = rng.gen_range(0..40);
+        let value = rng.gen();
+        match rng.gen_range(0..5) {
+            0 => {
+                assert_eq!(ordered.insert(key, value), hashed.insert(key, value));
+                if !order.contains(&key) {
+                    order.push(key);
+                }
+            }
+            1 => {
+                assert_eq!(ordered.shift_remove(&key), hashed.remove(&key));
+                order.retain(|k| *k != key);
+            }
+            2 => {
+                assert_eq!(ordered.swap_remove(&key), hashed.remove(&key));
+                if let Some(i) = order.iter().position(|k| *k == key) {
+                    order.swap_remove(i);
+                }
+            }
+            3 => {
+                let a = *ordered.entry(key).and_modify(|v| *v /= 2).or_insert(value);
+                let b = *hashed.entry(key).and_modify(|v| *v /= 2).or_insert(value);
+                assert_eq!(a, b);
+                if !order.contains(&key) {
+                    order.push(key);
+                }
+            }
+            _ => assert_eq!(ordered.get(&key), hashed.get(&key)),
+        }
+        assert_eq!(ordered.len(), hashed.len(), "step {step}");
+        assert_eq!(
+            ordered.keys().copied().collect::<Vec<_>>(),
+            order,
+            "step {step}"
+        );
+        for (i, key) in order.iter().enumerate() {
+            assert_eq!(ordered.get_index_of(key), Some(i));
+        }
+    }
+    let rebuilt: OrderedMap<u8, u32> = hashed.clone().into_iter().collect();
+    assert_eq!(rebuilt, ordered);
+}
@@ -0,0 +1,17 @@
+// Everything the collections binary prints, checked against tests/snapshots.
+// After a deliberate change run `UPDATE_SNAPSHOTS=1 cargo test` to update it.
+use collections::examples::run_all;
+use output::{assert_snapshot, Buffer};
+
+#[test]
+fn full_output() {
+    let mut out = Buffer::new();
+    run_all(&mut out);
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/collections.txt"
+        ),
+        out.as_str(),
+    );
+}
@@ -0,0 +1,128 @@
+use collections::utf8::{
+    char_boundaries, char_substring, decode, grapheme_substring, graphemes, report, table,
+    InvalidBytes,
+};
+use std::ops::Range;
+use unicode_general_category::GeneralCategory;
+
+// "e" + combining acute accent, then the GB flag (two regional indicators)
+const MIXED: &str = "Yes ✅ e\u{301}🇬🇧";
+
+#[test]
+fn graphemes_group_code_points() {
+    let graphemes = graphemes(MIXED);
+    assert_eq!(graphemes.len(), 8);
+    assert_eq!(MIXED.chars().count(), 10);
+
+    let accented = &graphemes[6];
+    assert_eq!(accented.text, "e\u{301}");
+    assert_eq!(accented.byte_range, 8..11);
+    assert_eq!(accented.code_points.len(), 2);
+    assert_eq!(accented.code_points[1].notation(), "U+0301");
+    assert_eq!(
+        accented.code_points[1].category,
+        GeneralCategory::NonspacingMark
+    );
+
+    let flag = &graphemes[7];
+    assert_eq!(flag.code_points[0].char_index, 8);
+    assert_eq!(flag.code_points[1].byte_offset, 15);
+    assert_eq!(flag.code_points[0].bytes(), vec![0xF0, 0x9F, 0x87, 0xAC]);
+}
+
+#[test]
+fn code_point_bytes_match_the_string() {
+    let bytes: Vec<u8> = graphemes(MIXED)
+        .iter()
+        .flat_map(|grapheme| grapheme.code_points.iter().flat_map(|c| c.bytes()))
+        .collect();
+    assert_eq!(bytes, MIXED.as_bytes());
+}
+
+#[test]
+fn boundaries_include_both_ends() {
+    assert_eq!(char_boundaries(""), vec![0]);
+    assert_eq!(char_boundaries("Yes ✅"), vec![0, 1, 2, 3, 4, 7]);
+}
+
+#[test]
+fn substrings_by_char() {
+    assert_eq!(char_substring(MIXED, 4..5), Some("✅"));
+    // splits the accent off the e, which is allowed when counting chars
+    assert_eq!(char_substring(MIXED, 6..7), Some("e"));
+    assert_eq!(char_substring(MIXED, 0..10), Some(MIXED));
+    assert_eq!(char_substring(MIXED, 10..10), Some(""));
+    assert_eq!(char_substring(MIXED, 0..11), None);
+    assert_eq!(char_substring(MIXED, Range { start: 3, end: 2 }), None);
+}
+
+#[test]
+fn substrings_by_grapheme() {
+    assert_eq!(grapheme_substring(MIXED, 6..7), Some("e\u{301}"));
+    assert_eq!(grapheme_substring(MIXED, 7..8), Some("🇬🇧"));
+    assert_eq!(grapheme_substring(MIXED, 0..8), Some(MIXED));
+    assert_eq!(grapheme_substring(MIXED, 8..9), None);
+}
+
+#[test]
+fn decode_reports_invalid_offsets() {
+    let (text, invalid) = decode(b"ab\xFF\xFEcd\xE2\x9C");
+    assert_eq!(text, "ab\u{FFFD}\u{FFFD}cd\u{FFFD}");
+    assert_eq!(
+        invalid,
+        vec![
+            InvalidBytes {
+                offset: 2,
+                bytes: vec![0xFF]
+            },
+            InvalidBytes {
+                offset: 3,
+                bytes: vec![0xFE]
+            },
+            InvalidBytes {
+                offset: 6,
+                bytes: vec![0xE2, 0x9C]
+            },
+        ]
+    );
+
+    let (text, invalid) = decode(MIXED.as_bytes());
+    assert_eq!(text, MIXED);
+    assert!(invalid.is_empty());
+}
+
+#[test]
+fn table_lists_every_code_point() {
+    let table = table("a\té");
+    assert!(table.contains("U+0061"));
+    assert!(table.contains("\\t"));
+    assert!(table.contains("C3 A9"));
+    assert!(table.ends_with("4 bytes, 3 chars, 3 graphemes\nchar boundaries: [0, 1, 2, 4]"));
+
+    let report = report(b"\xC0ok");
+    assert!(report.starts_with("invalid UTF-8 at byte 0: C0\n"));
+}
+
+#[test]
+fn report_offsets_are_in_the_original_bytes() {
+    // "aé", a 2 byte invalid run (the start of ✅ cut short), then "é✅". The
+    // run is one U+FFFD, 3 bytes in the decoded text.
+    let report = report(b"a\xC3\xA9\xE2\x9C\xC3\xA9\xE2\x9C\x85");
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "invalid UTF-8 at byte 3: E2 9C");
+    // grapheme, text, char, byte, code, utf-8 for each code point
+    let rows: Vec<Vec<&str>> = lines[2..7]
+        .iter()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    assert_eq!(rows[1][..6], ["1", "\u{e9}", "1", "1", "U+00E9", "C3"]);
+    assert_eq!(
+        rows[2][..7],
+        ["2", "\u{fffd}", "2", "3", "U+FFFD", "E2", "9C"]
+    );
+    assert_eq!(rows[3][..6], ["3", "\u{e9}", "3", "5", "U+00E9", "C3"]);
+    assert_eq!(rows[4][..6], ["4", "✅", "4", "7", "U+2705", "E2"]);
+    assert!(
+        report.ends_with("10 bytes, 5 chars, 5 graphemes\nchar boundaries: [0, 1, 3, 5, 7, 10]")
+    );
+}
@@ -0,0 +1,22 @@
+// A describe() that reads like a sentence, rather than Debug's struct dump.
+//
+// Write it by hand, or derive it (the derive lives in describe_derive as
+// proc macros have to be in a crate of their own):
+//
+//   #[derive(Describe)]
+//   #[describe(format = "{name}, aged {age} with email {email}.")]
+//   pub struct Human { name: String, age: usize, email: String }
+//
+// Without a format it lists the fields, "Car: number_plate RG54 1PQ, age 3".
+// The attributes are
+//   on a struct   format = "..." (using field names, or {0} for tuple structs)
+//                 bound = "T: Debug" (extra where clauses for generics)
+//   on a variant  rename = "...", format = "..."
+//   on a field    skip, rename = "...", format = "{:?}", nested (use the
+//                 field's own describe())
+// A unit variant like Bed::SuperKing is described as "super king".
+pub use describe_derive::Describe;
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
@@ -0,0 +1,8 @@
+// Each file in compile_fail/ misuses the derive and the matching .stderr is
+// the error we expect. After changing an error message regenerate them with
+// TRYBUILD=overwrite cargo test -p describe
+#[test]
+fn misuse_is_a_compile_error() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use describe::Describe;
+
+#[derive(Describe)]
+struct Human {
+    #[describe(rename = "aged", rename = "years")]
+    age: usize,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use describe::Describe;
+
+#[derive(Describe)]
+struct Bag {
+    items: Vec<char>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use describe::Describe;
+
+#[derive(Describe)]
+#[describe(format = "a {0} bed")]
+enum Bed {
+    Single,
+    Double,
+}
+
+fn main() {}
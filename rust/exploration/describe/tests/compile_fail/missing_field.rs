@@ -0,0 +1,9 @@
+use describe::Describe;
+
+#[derive(Describe)]
+#[describe(format = "{name}, aged {age}")]
+struct Human {
+    name: String,
+}
+
+fn main() {}
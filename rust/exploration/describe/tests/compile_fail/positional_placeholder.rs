@@ -0,0 +1,10 @@
+use describe::Describe;
+
+#[derive(Describe)]
+#[describe(format = "{} with email {email}")]
+struct Human {
+    name: String,
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use describe::Describe;
+
+#[derive(Describe)]
+struct Human {
+    #[describe(skip, rename = "aged")]
+    age: usize,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use describe::Describe;
+
+#[derive(Describe)]
+enum CarColour {
+    Red,
+    #[describe(skip)]
+    Green,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use describe::Describe;
+
+#[derive(Describe)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
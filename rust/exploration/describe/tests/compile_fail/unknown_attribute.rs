@@ -0,0 +1,9 @@
+use describe::Describe;
+
+#[derive(Describe)]
+struct Human {
+    #[describe(hide)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,148 @@
+use describe::Describe;
+use std::fmt::Debug;
+
+#[derive(Describe)]
+#[describe(format = "{name}, aged {age} with email {email}.")]
+struct Human {
+    name: String,
+    age: usize,
+    email: String,
+}
+
+#[test]
+fn struct_with_a_format() {
+    let human = Human {
+        name: String::from("Simon"),
+        age: 31,
+        email: String::from("simon@example.com"),
+    };
+    assert_eq!(
+        human.describe(),
+        "Simon, aged 31 with email simon@example.com."
+    );
+}
+
+#[derive(Describe)]
+struct Car {
+    #[describe(rename = "plate")]
+    number_plate: String,
+    #[describe(rename = "aged")]
+    age: u32,
+    #[describe(skip)]
+    _mileage: u32,
+    #[describe(nested)]
+    colour: Colour,
+    #[describe(format = "{:.1}l")]
+    engine: f64,
+}
+
+#[derive(Describe)]
+enum Colour {
+    Red,
+    DarkBlue,
+    #[describe(rename = "bright yellow")]
+    Yellow,
+}
+
+#[test]
+fn struct_lists_its_fields() {
+    let car = Car {
+        number_plate: String::from("RG54 1PQ"),
+        age: 3,
+        _mileage: 30_000,
+        colour: Colour::DarkBlue,
+        engine: 1.6,
+    };
+    assert_eq!(
+        car.describe(),
+        "Car: plate RG54 1PQ, aged 3, colour dark blue, engine 1.6l"
+    );
+}
+
+#[test]
+fn unit_variants_are_split_into_words() {
+    assert_eq!(Colour::Red.describe(), "red");
+    assert_eq!(Colour::DarkBlue.describe(), "dark blue");
+    assert_eq!(Colour::Yellow.describe(), "bright yellow");
+}
+
+#[derive(Describe)]
+enum Shape {
+    Rectangle {
+        height: u32,
+        width: u32,
+    },
+    #[describe(format = "circle of radius {0}")]
+    Circle(u32),
+    #[describe(rename = "dot")]
+    Point(i32, #[describe(rename = "and")] i32),
+}
+
+#[test]
+fn variants_with_fields() {
+    assert_eq!(
+        Shape::Rectangle {
+            height: 2,
+            width: 3
+        }
+        .describe(),
+        "Rectangle: height 2, width 3"
+    );
+    assert_eq!(Shape::Circle(4).describe(), "circle of radius 4");
+    assert_eq!(Shape::Point(-1, 2).describe(), "dot: -1, and 2");
+}
+
+#[derive(Describe)]
+struct Coordinate(i32, i32);
+
+#[derive(Describe)]
+#[describe(format = "({0}, {1}) then ({0})")]
+struct Pair(i32, i32);
+
+#[derive(Describe)]
+struct Nothing;
+
+#[test]
+fn tuple_and_unit_structs() {
+    assert_eq!(Coordinate(1, 2).describe(), "Coordinate: 1, 2");
+    assert_eq!(Pair(1, 2).describe(), "(1, 2) then (1)");
+    assert_eq!(Nothing.describe(), "Nothing");
+}
+
+#[derive(Describe)]
+#[describe(format = "{{{count:>3}}} of {type}")]
+struct Braces {
+    count: u8,
+    r#type: &'static str,
+}
+
+#[test]
+fn format_specs_escapes_and_raw_names() {
+    let braces = Braces {
+        count: 7,
+        r#type: "curly",
+    };
+    assert_eq!(braces.describe(), "{  7} of curly");
+}
+
+#[derive(Describe)]
+#[describe(bound = "T: Debug")]
+struct Bag<T> {
+    #[describe(format = "{:?}")]
+    items: Vec<T>,
+}
+
+#[test]
+fn generic_structs_take_a_bound() {
+    let bag = Bag {
+        items: vec!['a', 'b'],
+    };
+    assert_eq!(bag.describe(), "Bag: items ['a', 'b']");
+}
+
+#[test]
+fn works_as_a_trait_object() {
+    let things: Vec<Box<dyn Describe>> = vec![Box::new(Nothing), Box::new(Colour::Red)];
+    let descriptions: Vec<String> = things.iter().map(|thing| thing.describe()).collect();
+    assert_eq!(descriptions, ["Nothing", "red"]);
+}
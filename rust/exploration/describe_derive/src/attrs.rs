@@ -0,0 +1,128 @@
+use proc_macro2::Span;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, LitStr, Result, Token, WherePredicate};
+
+// Everything that can go inside #[describe(...)]. We parse the same set
+// wherever the attribute appears and then check only the ones that make
+// sense there were used, so misuse gets a clear error rather than being
+// silently ignored.
+#[derive(Default)]
+pub struct Attrs {
+    pub skip: Option<Span>,
+    pub nested: Option<Span>,
+    pub rename: Option<LitStr>,
+    pub format: Option<LitStr>,
+    pub bound: Option<(LitStr, Punctuated<WherePredicate, Token![,]>)>,
+}
+
+impl Attrs {
+    pub fn parse(attributes: &[Attribute]) -> Result<Attrs> {
+        let mut attrs = Attrs::default();
+        for attribute in attributes {
+            if !attribute.path().is_ident("describe") {
+                continue;
+            }
+            attribute.parse_nested_meta(|meta| {
+                let Some(ident) = meta.path.get_ident() else {
+                    return Err(meta.error("expected a describe attribute like skip"));
+                };
+                let (key, span) = (ident.to_string(), ident.span());
+                let duplicate =
+                    || Error::new(span, format!("duplicate describe attribute `{key}`"));
+                match key.as_str() {
+                    "skip" => {
+                        if attrs.skip.replace(span).is_some() {
+                            return Err(duplicate());
+                        }
+                    }
+                    "nested" => {
+                        if attrs.nested.replace(span).is_some() {
+                            return Err(duplicate());
+                        }
+                    }
+                    "rename" => {
+                        let value: LitStr = meta.value()?.parse()?;
+                        if attrs.rename.replace(value).is_some() {
+                            return Err(duplicate());
+                        }
+                    }
+                    "format" => {
+                        let value: LitStr = meta.value()?.parse()?;
+                        if attrs.format.replace(value).is_some() {
+                            return Err(duplicate());
+                        }
+                    }
+                    "bound" => {
+                        let value: LitStr = meta.value()?.parse()?;
+                        let predicates = value.parse_with(Punctuated::parse_terminated)?;
+                        if attrs.bound.replace((value, predicates)).is_some() {
+                            return Err(duplicate());
+                        }
+                    }
+                    _ => {
+                        return Err(meta.error(concat!(
+                            "unknown describe attribute, ",
+                            "expected one of skip, rename, format, nested or bound"
+                        )))
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+
+    // Errors on the first attribute that isn't in `allowed`, e.g.
+    // attrs.allow(&["rename", "format"], "a variant")
+    pub fn allow(&self, allowed: &[&str], place: &str) -> Result<()> {
+        for (name, span) in self.used() {
+            if !allowed.contains(&name) {
+                return Err(Error::new(
+                    span,
+                    format!("`{name}` can't be used on {place}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // skip leaves nothing to format so it can't be mixed with the others,
+    // and a field is either nested or formatted, not both
+    pub fn check_field(&self) -> Result<()> {
+        if self.skip.is_some() {
+            if let Some((name, span)) = self.used().into_iter().find(|(name, _)| *name != "skip") {
+                return Err(Error::new(
+                    span,
+                    format!("`{name}` does nothing on a skipped field"),
+                ));
+            }
+        }
+        if let (Some(_), Some(format)) = (self.nested, &self.format) {
+            return Err(Error::new(
+                format.span(),
+                "a field can't be both nested and formatted",
+            ));
+        }
+        Ok(())
+    }
+
+    fn used(&self) -> Vec<(&'static str, Span)> {
+        let mut used = vec![];
+        if let Some(span) = self.skip {
+            used.push(("skip", span));
+        }
+        if let Some(span) = self.nested {
+            used.push(("nested", span));
+        }
+        if let Some(rename) = &self.rename {
+            used.push(("rename", rename.span()));
+        }
+        if let Some(format) = &self.format {
+            used.push(("format", format.span()));
+        }
+        if let Some((bound, _)) = &self.bound {
+            used.push(("bound", bound.span()));
+        }
+        used
+    }
+}
@@ -0,0 +1,207 @@
+use crate::attrs::Attrs;
+use crate::template::{self, Name};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Result};
+
+// The generated impl is always a match on self, with one arm per variant (a
+// struct is a single arm, `Self { .. }`), which binds every field by
+// reference so structs and enums share the code that formats them:
+//
+//   impl Describe for Human {
+//       fn describe(&self) -> String {
+//           match self {
+//               Self { name: __describe_0, age: __describe_1, email: __describe_2 } => ...
+//           }
+//       }
+//   }
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = Attrs::parse(&input.attrs)?;
+    let type_name = input.ident.to_string();
+    let arms = match &input.data {
+        Data::Struct(data) => {
+            attrs.allow(&["format", "bound"], "a struct")?;
+            let body = describe_fields(&type_name, &data.fields, attrs.format.as_ref())?;
+            let pattern = pattern(quote!(Self), &data.fields);
+            vec![quote!(#pattern => #body)]
+        }
+        Data::Enum(data) => {
+            // every variant reads differently so a format here wouldn't help
+            attrs.allow(&["bound"], "an enum, put it on the variants instead")?;
+            let mut arms = vec![];
+            for variant in &data.variants {
+                let variant_attrs = Attrs::parse(&variant.attrs)?;
+                variant_attrs.allow(&["rename", "format"], "a variant")?;
+                let ident = &variant.ident;
+                let pattern = pattern(quote!(Self::#ident), &variant.fields);
+                let body = match (
+                    &variant.fields,
+                    &variant_attrs.rename,
+                    &variant_attrs.format,
+                ) {
+                    (_, Some(rename), None) if variant.fields.is_empty() => {
+                        quote!(::std::string::String::from(#rename))
+                    }
+                    (Fields::Unit, None, None) => {
+                        let words = LitStr::new(&words(&ident.to_string()), ident.span());
+                        quote!(::std::string::String::from(#words))
+                    }
+                    (fields, rename, format) => {
+                        let head = rename.as_ref().map_or(ident.to_string(), LitStr::value);
+                        describe_fields(&head, fields, format.as_ref())?
+                    }
+                };
+                arms.push(quote!(#pattern => #body));
+            }
+            arms
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "Describe can't be derived for a union, we can't tell which field is set",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    if let Some((_, predicates)) = &attrs.bound {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates.iter().cloned());
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    // an enum with no variants can't be made, but matching on a reference to
+    // one isn't exhaustive so we match on the value instead
+    let scrutinee = if arms.is_empty() {
+        quote!(*self)
+    } else {
+        quote!(self)
+    };
+    Ok(quote! {
+        impl #impl_generics ::describe::Describe for #ident #type_generics #where_clause {
+            fn describe(&self) -> ::std::string::String {
+                match #scrutinee {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+// The name we bind field number `index` to in the match. Numbers rather than
+// the field names, so an odd name like _mileage doesn't trip a lint.
+fn binding(index: usize) -> Ident {
+    format_ident!("__describe_{}", index)
+}
+
+fn pattern(path: TokenStream, fields: &Fields) -> TokenStream {
+    let bindings = (0..fields.len()).map(binding);
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings,)* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings,)*)),
+        Fields::Unit => path,
+    }
+}
+
+// Either the given format filled in with the fields, or by default
+// "Head: label value, label value" listing the fields that aren't skipped.
+fn describe_fields(head: &str, fields: &Fields, format: Option<&LitStr>) -> Result<TokenStream> {
+    if let Some(format) = format {
+        for field in fields {
+            // the format decides how fields look so field attributes are moot
+            Attrs::parse(&field.attrs)?.allow(&[], "a field when the format is given")?;
+        }
+        return with_format(fields, format);
+    }
+    let mut parts = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = Attrs::parse(&field.attrs)?;
+        attrs.allow(&["skip", "rename", "format", "nested"], "a field")?;
+        attrs.check_field()?;
+        if attrs.skip.is_some() {
+            continue;
+        }
+        // spanned so a field that isn't Display gets the error, not the derive
+        let span = field.ty.span();
+        let mut binding = binding(index);
+        binding.set_span(span);
+        let value = if attrs.nested.is_some() {
+            quote_spanned!(span=> ::describe::Describe::describe(#binding))
+        } else if let Some(format) = &attrs.format {
+            quote_spanned!(span=> ::std::format!(#format, #binding))
+        } else {
+            quote_spanned!(span=> ::std::string::ToString::to_string(#binding))
+        };
+        // tuple fields have no name so without a rename it's just the value
+        let label = match (&attrs.rename, &field.ident) {
+            (Some(rename), _) => Some(rename.value()),
+            (None, Some(name)) => Some(name.to_string()),
+            (None, None) => None,
+        };
+        parts.push(match label {
+            Some(label) => quote!(::std::format!("{} {}", #label, #value)),
+            None => value,
+        });
+    }
+    if parts.is_empty() {
+        return Ok(quote!(::std::string::String::from(#head)));
+    }
+    Ok(quote! {
+        ::std::format!("{}: {}", #head, [#(#parts),*].join(", "))
+    })
+}
+
+fn with_format(fields: &Fields, format: &LitStr) -> Result<TokenStream> {
+    let template =
+        template::parse(&format.value()).map_err(|message| Error::new(format.span(), message))?;
+    let mut arguments = vec![];
+    for name in &template.names {
+        let index = match name {
+            Name::Field(name) => fields
+                .iter()
+                .position(|field| {
+                    field
+                        .ident
+                        .as_ref()
+                        .is_some_and(|ident| ident.unraw() == name)
+                })
+                .ok_or_else(|| {
+                    Error::new(
+                        format.span(),
+                        format!("there's no field `{name}` to format"),
+                    )
+                })?,
+            Name::Index(index) if matches!(fields, Fields::Unnamed(_)) && *index < fields.len() => {
+                *index
+            }
+            Name::Index(index) => {
+                return Err(Error::new(
+                    format.span(),
+                    format!("there's no field `{index}` to format"),
+                ))
+            }
+        };
+        arguments.push(binding(index));
+    }
+    let rewritten = LitStr::new(&template.format, format.span());
+    Ok(quote!(::std::format!(#rewritten, #(#arguments),*)))
+}
+
+// SuperKing -> "super king"
+fn words(name: &str) -> String {
+    let mut words = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            words.push(' ');
+        }
+        words.extend(c.to_lowercase());
+    }
+    words
+}
@@ -0,0 +1,20 @@
+// #[derive(Describe)], see the describe crate for what it generates.
+//
+// Proc macros get the item as tokens and hand back the impl as tokens. syn
+// parses the tokens into a DeriveInput and quote! builds the output. Mistakes
+// are returned as a syn::Error which becomes a compile_error! pointing at the
+// offending attribute, rather than a panic pointing at the derive.
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attrs;
+mod expand;
+mod template;
+
+#[proc_macro_derive(Describe, attributes(describe))]
+pub fn derive_describe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,87 @@
+// A format string written against field names, "{name}, aged {age}", turned
+// into one format! can take. Passing every field as a named argument doesn't
+// work as format! errors on arguments it doesn't use, so we find the fields
+// that are used and rewrite each placeholder to a numbered argument:
+//
+//   "{name}, aged {age:>3}" -> "{0}, aged {1:>3}" with names [name, age]
+//
+// Tuple fields are referred to by position, "{0} by {1}".
+#[derive(Debug, PartialEq)]
+pub enum Name {
+    Field(String),
+    Index(usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Template {
+    pub format: String,
+    pub names: Vec<Name>,
+}
+
+pub fn parse(source: &str) -> Result<Template, String> {
+    let mut format = String::new();
+    let mut names: Vec<Name> = vec![];
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            }
+            '}' => return Err("unmatched `}`, write `}}` for a literal brace".to_string()),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err("unclosed `{`, write `{{` for a literal brace".to_string())
+                        }
+                    }
+                }
+                let (name, spec) = match placeholder.split_once(':') {
+                    Some((name, spec)) => (name.trim(), Some(spec)),
+                    None => (placeholder.trim(), None),
+                };
+                let name = parse_name(name)?;
+                let index = match names.iter().position(|n| *n == name) {
+                    Some(index) => index,
+                    None => {
+                        names.push(name);
+                        names.len() - 1
+                    }
+                };
+                format.push('{');
+                format.push_str(&index.to_string());
+                if let Some(spec) = spec {
+                    format.push(':');
+                    format.push_str(spec);
+                }
+                format.push('}');
+            }
+            c => format.push(c),
+        }
+    }
+    Ok(Template { format, names })
+}
+
+fn parse_name(name: &str) -> Result<Name, String> {
+    if name.is_empty() {
+        return Err("`{}` doesn't say which field, use a field name like `{name}`".to_string());
+    }
+    if let Ok(index) = name.parse() {
+        return Ok(Name::Index(index));
+    }
+    let mut chars = name.chars();
+    let starts_well = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_');
+    if starts_well && chars.all(|c| c.is_alphanumeric() || c == '_') {
+        Ok(Name::Field(name.to_string()))
+    } else {
+        Err(format!("`{{{name}}}` isn't a field name"))
+    }
+}
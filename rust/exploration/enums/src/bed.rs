@@ -0,0 +1,55 @@
+use describe::Describe;
+
+#[derive(Debug, Describe)]
+pub enum Bed {
+    Single,
+    #[describe(rename = "queen (small double)")]
+    Queen,
+    Double,
+    King,
+    SuperKing,
+}
+
+#[derive(Debug, PartialEq, Eq, Describe)]
+#[describe(format = "{width}cm by {length}cm")]
+pub struct Dimensions {
+    pub width: i32,
+    pub length: i32,
+}
+
+impl Dimensions {
+    pub fn area(&self) -> i32 {
+        self.width * self.length
+    }
+}
+
+impl Bed {
+    pub fn size(&self) -> Dimensions {
+        match self {
+            Bed::Single => Dimensions {
+                width: 90,
+                length: 190,
+            },
+            Bed::Queen => Dimensions {
+                width: 120,
+                length: 190,
+            },
+            Bed::Double => Dimensions {
+                width: 135,
+                length: 190,
+            },
+            Bed::King => Dimensions {
+                width: 150,
+                length: 200,
+            },
+            Bed::SuperKing => Dimensions {
+                width: 180,
+                length: 200,
+            },
+        }
+    }
+
+    pub fn area(&self) -> i32 {
+        self.size().area()
+    }
+}
@@ -0,0 +1,6 @@
+// Run with `cargo run -p enums --bin calc`
+use std::io;
+
+fn main() -> io::Result<()> {
+    enums::expr::repl(io::stdin().lock(), io::stdout())
+}
@@ -0,0 +1,176 @@
+// Directions on a grid where North is up (y + 1) and East is right (x + 1).
+//
+// An enum with no data is a natural fit: a match has to handle every
+// direction so adding one (see CompassPoint) shows up every place to change.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_around(self) -> Direction {
+        self.opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    // The (x, y) step for moving one square this way.
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::East => (1, 0),
+            Direction::South => (0, -1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            Direction::North => 'N',
+            Direction::East => 'E',
+            Direction::South => 'S',
+            Direction::West => 'W',
+        }
+    }
+
+    pub fn from_letter(letter: char) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.letter() == letter.to_ascii_uppercase())
+    }
+}
+
+// The 8 point compass, turning 45 degrees at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassPoint {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl CompassPoint {
+    // Clockwise from North, so turning is moving along this list.
+    pub const ALL: [CompassPoint; 8] = [
+        CompassPoint::North,
+        CompassPoint::NorthEast,
+        CompassPoint::East,
+        CompassPoint::SouthEast,
+        CompassPoint::South,
+        CompassPoint::SouthWest,
+        CompassPoint::West,
+        CompassPoint::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        CompassPoint::ALL
+            .iter()
+            .position(|point| *point == self)
+            .expect("every point is in ALL")
+    }
+
+    fn rotate(self, eighths: usize) -> CompassPoint {
+        CompassPoint::ALL[(self.index() + eighths) % 8]
+    }
+
+    pub fn turn_left(self) -> CompassPoint {
+        self.rotate(7)
+    }
+
+    pub fn turn_right(self) -> CompassPoint {
+        self.rotate(1)
+    }
+
+    pub fn turn_around(self) -> CompassPoint {
+        self.opposite()
+    }
+
+    pub fn opposite(self) -> CompassPoint {
+        self.rotate(4)
+    }
+
+    // Diagonals move one square on both axes.
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            CompassPoint::North => (0, 1),
+            CompassPoint::NorthEast => (1, 1),
+            CompassPoint::East => (1, 0),
+            CompassPoint::SouthEast => (1, -1),
+            CompassPoint::South => (0, -1),
+            CompassPoint::SouthWest => (-1, -1),
+            CompassPoint::West => (-1, 0),
+            CompassPoint::NorthWest => (-1, 1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let (x, y) = self.delta();
+        x != 0 && y != 0
+    }
+}
+
+impl From<Direction> for CompassPoint {
+    fn from(direction: Direction) -> CompassPoint {
+        match direction {
+            Direction::North => CompassPoint::North,
+            Direction::East => CompassPoint::East,
+            Direction::South => CompassPoint::South,
+            Direction::West => CompassPoint::West,
+        }
+    }
+}
+
+// Only the four main points are Directions.
+impl TryFrom<CompassPoint> for Direction {
+    type Error = CompassPoint;
+
+    fn try_from(point: CompassPoint) -> Result<Direction, CompassPoint> {
+        match point {
+            CompassPoint::North => Ok(Direction::North),
+            CompassPoint::East => Ok(Direction::East),
+            CompassPoint::South => Ok(Direction::South),
+            CompassPoint::West => Ok(Direction::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::bed::{Bed, Dimensions};
+use crate::direction::Direction;
+use crate::measurable::Registry;
+use crate::number_words::{English, French, Locale};
+use crate::room::Room;
+use crate::rover::{Grid, Robot, RoverError, Simulation};
+use crate::shape::Shape;
+use crate::shapes;
+use describe::Describe;
+use output::Output;
+
+// Everything main runs, in order.
+pub fn run_all(out: &mut dyn Output) {
+    direction_example(out);
+    rover_example(out);
+    shape_example(out);
+    bed_example(out);
+    measurable_example(out);
+    option_example(out);
+    exhaustive_match_example(out);
+    if_let_example(out);
+}
+
+// returns where we head turning left, right and around
+pub fn direction_example(out: &mut dyn Output) -> (Direction, Direction, Direction) {
+    writeln!(out, "Direction example");
+    let direction = Direction::North;
+    writeln!(out, "We are heading {:?}", direction);
+    let turns = (
+        direction.turn_left(),
+        direction.turn_right(),
+        direction.turn_around(),
+    );
+    writeln!(
+        out,
+        "Turning left we head {:?}, turning right {:?} and turning around {:?}",
+        turns.0, turns.1, turns.2
+    );
+    writeln!(out);
+    turns
+}
+
+// returns the robots after both programs and why the last one crashes
+pub fn rover_example(out: &mut dyn Output) -> (Vec<Robot>, RoverError) {
+    writeln!(out, "Rover example");
+    let grid = Grid::new(6, 6).with_obstacles(&[(3, 4)]);
+    let mut simulation = Simulation::new(grid);
+    simulation.add_robot(1, 2, Direction::North).unwrap();
+    simulation.add_robot(3, 3, Direction::East).unwrap();
+    let robots = simulation
+        .run_all(&["LMLMLMLMM", "MMRMMRMRRM"])
+        .expect("both programs stay on the grid");
+    for robot in &robots {
+        writeln!(out, "{robot}");
+    }
+    // This one drives into the obstacle
+    let crashed = simulation
+        .run(1, "LMMMLMM")
+        .expect_err("the obstacle is in the way");
+    writeln!(out, "{crashed:?}");
+    writeln!(out);
+    (robots, crashed)
+}
+
+// returns the rectangle and circle areas
+pub fn shape_example(out: &mut dyn Output) -> (f64, f64) {
+    writeln!(out, "Shape example");
+    // shapes! (see macros.rs) is shorter than the Shape::Rectangle { .. } literal
+    let shapes = shapes![rect 10 x 10, circle 10];
+    let (rectangle, circle) = (&shapes[0], &shapes[1]);
+    writeln!(
+        out,
+        "Hello, world! {rectangle:#?}, {circle:#?} have areas {} and {}",
+        rectangle.area(),
+        circle.area()
+    );
+    writeln!(out);
+    (rectangle.area(), circle.area())
+}
+
+pub fn bed_example(out: &mut dyn Output) -> Dimensions {
+    writeln!(out, "Bed example");
+    let bed = Bed::SuperKing;
+    writeln!(
+        out,
+        "I would like a {:#?} which has dimensions {:#?} and area {:?}",
+        bed,
+        bed.size(),
+        bed.area()
+    );
+    // or less like a data dump
+    writeln!(out, "A {} bed is {}", bed.describe(), bed.size().describe());
+    writeln!(out);
+    bed.size()
+}
+
+// returns the registry sorted largest first
+pub fn measurable_example(out: &mut dyn Output) -> Registry {
+    writeln!(out, "Measurable example");
+    // shapes, beds, dimensions and rooms all in one Vec<Box<dyn Measurable>>
+    let mut registry = Registry::new();
+    registry
+        .add(Shape::Circle(100))
+        .add(Bed::King)
+        .add(Dimensions {
+            width: 50,
+            length: 60,
+        })
+        .add(Room::new("box room", 200, 250).with_bed(Bed::Single))
+        .add(Bed::Single);
+    registry.extend(shapes![rect 10 x 20, polygon]);
+    registry.sort_by_area();
+    for item in registry.iter() {
+        writeln!(out, "{:>12.1} {}", item.area(), item.describe());
+    }
+    writeln!(out, "Total area {:.1}", registry.total_area());
+    if let Some(largest) = registry.largest() {
+        writeln!(out, "The largest is the {}", largest.describe());
+    }
+    // getting the concrete types back out
+    let beds: Vec<&Bed> = registry.of_type::<Bed>().collect();
+    writeln!(out, "The beds are {beds:?}");
+    for room in registry.of_type::<Room>() {
+        writeln!(out, "The {} has {}cm² free", room.name, room.free_area());
+    }
+    writeln!(out);
+    registry
+}
+
+// clippy can see x is always Some, which is the point of the example
+#[allow(clippy::unnecessary_literal_unwrap)]
+pub fn option_example(out: &mut dyn Output) -> i32 {
+    writeln!(out, "Option example");
+    let x: Option<i32> = Some(5);
+    let y: Option<i32> = None;
+    let z: i32 = 5 + x.unwrap();
+    // Equivalent to:
+    let unwrapped_x: i32 = match x {
+        Some(y) => y,
+        None => panic!("called `Option::unwrap()` on a `None` value"),
+    };
+    let _z2: i32 = 5 + unwrapped_x;
+    writeln!(out, "x is {x:?}, y is {y:?}, z and z2 = {z:?}");
+    writeln!(out);
+    z
+}
+
+// returns the English words for each number
+pub fn exhaustive_match_example(out: &mut dyn Output) -> Vec<String> {
+    writeln!(out, "Exhaustive match example");
+    let x: i32 = 20;
+    match x {
+        1 => writeln!(out, "one"),
+        2 => writeln!(out, "two"),
+        3 => writeln!(out, "three"),
+        _ => writeln!(out, "If you comment this line i fail."),
+    }
+    writeln!(out, "x is {x:?}");
+    // number_words handles every number, not just 1, 2 and 3
+    let mut words = vec![];
+    for x in [x, 121, 1_001, -7] {
+        let cardinal = English::BRITISH.cardinal(x.into());
+        writeln!(
+            out,
+            "{x} is \"{}\" (\"{}\") or in French \"{}\"",
+            cardinal,
+            English::BRITISH.ordinal(x.into()),
+            French.cardinal(x.into())
+        );
+        words.push(cardinal);
+    }
+    writeln!(out);
+    words
+}
+
+// returns the circle's radius
+pub fn if_let_example(out: &mut dyn Output) -> Option<i32> {
+    writeln!(out, "If let example");
+    let x: Option<i32> = Some(5);
+
+    if let Some(y) = x {
+        writeln!(out, "y is {y:?}");
+    }
+    // Same as (clippy would rather we used the if let):
+    #[allow(clippy::single_match)]
+    match x {
+        Some(y) => writeln!(out, "y is {y:?}"),
+        _ => (),
+    }
+
+    // Example with custom enum
+    let circle = Shape::Circle(10);
+    let radius = if let Shape::Circle(radius) = circle {
+        writeln!(out, "Radius is {radius:?}");
+        Some(radius)
+    } else {
+        writeln!(out, "Not a circle");
+        None
+    };
+    writeln!(out);
+    radius
+}
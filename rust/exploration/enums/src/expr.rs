@@ -0,0 +1,366 @@
+// A little arithmetic language with a recursive enum for the syntax tree.
+//
+// Shape (in shape.rs) shows variants carrying data, here a variant can carry
+// more Exprs. A recursive enum needs a Box (or other pointer) as otherwise the
+// type would have infinite size.
+//
+//   source --token::tokenize--> tokens --parser::Parser--> Expr --eval--> Value
+//
+// Evaluating never panics: integer overflow, division by zero and floats
+// becoming infinite/NaN are all EvalErrors.
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+mod parser;
+mod token;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(Value),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary {
+        op: BinaryOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedChar { position: usize, found: char },
+    BadNumber { position: usize, text: String },
+    UnexpectedToken { position: usize, found: String },
+    UnclosedParen { position: usize },
+    UnexpectedEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    UnknownVariable(String),
+    DivisionByZero,
+    Overflow,
+    // A float result that is infinite or NaN.
+    NotFinite,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar { position, found } => {
+                write!(f, "unexpected {found:?} at {position}")
+            }
+            ParseError::BadNumber { position, text } => {
+                write!(f, "{text:?} at {position} is not a number")
+            }
+            ParseError::UnexpectedToken { position, found } => {
+                write!(f, "unexpected {found} at {position}")
+            }
+            ParseError::UnclosedParen { position } => {
+                write!(f, "the ( at {position} is never closed")
+            }
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of input"),
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::UnknownVariable(name) => write!(f, "{name} is not defined"),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::Overflow => write!(f, "integer overflow"),
+            EvalError::NotFinite => write!(f, "result is not a finite number"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+impl std::error::Error for EvalError {}
+
+pub fn parse(source: &str) -> Result<Expr, ParseError> {
+    let tokens = token::tokenize(source)?;
+    parser::Parser::new(tokens, source.chars().count()).parse()
+}
+
+impl Value {
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Value::Int(x) => x as f64,
+            Value::Float(x) => x,
+        }
+    }
+
+    pub fn checked_neg(self) -> Result<Value, EvalError> {
+        match self {
+            Value::Int(x) => x.checked_neg().map(Value::Int).ok_or(EvalError::Overflow),
+            Value::Float(x) => Ok(Value::Float(-x)),
+        }
+    }
+
+    // Two Ints stay an Int (checked), anything else is done as floats.
+    pub fn apply(op: BinaryOp, left: Value, right: Value) -> Result<Value, EvalError> {
+        match (left, right) {
+            (Value::Int(a), Value::Int(b)) => int_op(op, a, b),
+            _ => float_op(op, left.as_f64(), right.as_f64()),
+        }
+    }
+}
+
+fn int_op(op: BinaryOp, a: i64, b: i64) -> Result<Value, EvalError> {
+    let result = match op {
+        BinaryOp::Add => a.checked_add(b),
+        BinaryOp::Sub => a.checked_sub(b),
+        BinaryOp::Mul => a.checked_mul(b),
+        BinaryOp::Div | BinaryOp::Rem if b == 0 => return Err(EvalError::DivisionByZero),
+        BinaryOp::Div => a.checked_div(b),
+        BinaryOp::Rem => a.checked_rem(b),
+        // A negative power isn't a whole number.
+        BinaryOp::Pow if b < 0 => return float_op(op, a as f64, b as f64),
+        BinaryOp::Pow => u32::try_from(b).ok().and_then(|b| a.checked_pow(b)),
+    };
+    result.map(Value::Int).ok_or(EvalError::Overflow)
+}
+
+fn float_op(op: BinaryOp, a: f64, b: f64) -> Result<Value, EvalError> {
+    let result = match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => a * b,
+        BinaryOp::Div | BinaryOp::Rem if b == 0.0 => return Err(EvalError::DivisionByZero),
+        BinaryOp::Div => a / b,
+        BinaryOp::Rem => a % b,
+        BinaryOp::Pow => a.powf(b),
+    };
+    if result.is_finite() {
+        Ok(Value::Float(result))
+    } else {
+        Err(EvalError::NotFinite)
+    }
+}
+
+impl BinaryOp {
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinaryOp::Add | BinaryOp::Sub => 1,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 2,
+            BinaryOp::Pow => 4,
+        }
+    }
+
+    pub fn is_right_associative(self) -> bool {
+        self == BinaryOp::Pow
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            BinaryOp::Add => '+',
+            BinaryOp::Sub => '-',
+            BinaryOp::Mul => '*',
+            BinaryOp::Div => '/',
+            BinaryOp::Rem => '%',
+            BinaryOp::Pow => '^',
+        }
+    }
+}
+
+impl Expr {
+    pub fn eval(&self, variables: &HashMap<String, Value>) -> Result<Value, EvalError> {
+        match self {
+            Expr::Number(value) => Ok(*value),
+            Expr::Variable(name) => variables
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::UnknownVariable(name.clone())),
+            Expr::Neg(inner) => inner.eval(variables)?.checked_neg(),
+            Expr::Binary { op, left, right } => {
+                Value::apply(*op, left.eval(variables)?, right.eval(variables)?)
+            }
+        }
+    }
+
+    // Work out everything that doesn't depend on a variable, and drop the
+    // do-nothing operations (x + 0, x * 1, x ^ 1, --x). Constants that would be
+    // an error are left alone so they still error when evaluated. The one
+    // exception is --x, which is always x except that -x overflows when x is
+    // the smallest int, and simplifying throws that overflow away.
+    pub fn simplify(&self) -> Expr {
+        match self {
+            Expr::Number(_) | Expr::Variable(_) => self.clone(),
+            Expr::Neg(inner) => match inner.simplify() {
+                Expr::Number(value) => match value.checked_neg() {
+                    Ok(negated) => Expr::Number(negated),
+                    Err(_) => Expr::Neg(Box::new(Expr::Number(value))),
+                },
+                Expr::Neg(double) => *double,
+                inner => Expr::Neg(Box::new(inner)),
+            },
+            Expr::Binary { op, left, right } => {
+                let (left, right) = (left.simplify(), right.simplify());
+                if let (Expr::Number(a), Expr::Number(b)) = (&left, &right) {
+                    if let Ok(value) = Value::apply(*op, *a, *b) {
+                        return Expr::Number(value);
+                    }
+                }
+                match (op, left, right) {
+                    (BinaryOp::Add, x, zero) | (BinaryOp::Add, zero, x) if zero.is_int(0) => x,
+                    (BinaryOp::Sub, x, zero) if zero.is_int(0) => x,
+                    (BinaryOp::Sub, zero, x) if zero.is_int(0) => Expr::Neg(Box::new(x)),
+                    (BinaryOp::Mul, x, one) | (BinaryOp::Mul, one, x) if one.is_int(1) => x,
+                    (BinaryOp::Div | BinaryOp::Pow, x, one) if one.is_int(1) => x,
+                    (op, left, right) => Expr::Binary {
+                        op: *op,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    },
+                }
+            }
+        }
+    }
+
+    fn is_int(&self, n: i64) -> bool {
+        *self == Expr::Number(Value::Int(n))
+    }
+
+    // How tightly this binds when printed, to decide on brackets.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary { op, .. } => op.precedence(),
+            Expr::Neg(_) => parser::UNARY_PRECEDENCE,
+            Expr::Number(value) if value.as_f64() < 0.0 => parser::UNARY_PRECEDENCE,
+            Expr::Number(_) | Expr::Variable(_) => u8::MAX,
+        }
+    }
+
+    fn fmt_at_least(&self, f: &mut fmt::Formatter, min_precedence: u8) -> fmt::Result {
+        if self.precedence() < min_precedence {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(x) => write!(f, "{x}"),
+            // Debug keeps the ".0" so a float still reads back as a float.
+            Value::Float(x) => write!(f, "{x:?}"),
+        }
+    }
+}
+
+// Prints with only the brackets that are needed. parse(e.to_string()) gives e
+// back for anything parse made, and evaluates the same as e for anything else.
+// The difference is negative numbers, which parse never makes (-5 is a Neg of
+// 5) but simplify does: Number(-5) prints as -5 and reads back as Neg(5).
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // 9223372036854775808 is too big to parse, so -9223372036854775808
+            // can't be read back either
+            Expr::Number(Value::Int(i64::MIN)) => write!(f, "(-{} - 1)", i64::MAX),
+            Expr::Number(value) => write!(f, "{value}"),
+            Expr::Variable(name) => write!(f, "{name}"),
+            Expr::Neg(inner) => {
+                write!(f, "-")?;
+                inner.fmt_at_least(f, parser::UNARY_PRECEDENCE)
+            }
+            Expr::Binary { op, left, right } => {
+                let precedence = op.precedence();
+                let (left_min, right_min) = if op.is_right_associative() {
+                    (precedence + 1, precedence)
+                } else {
+                    (precedence, precedence + 1)
+                };
+                left.fmt_at_least(f, left_min)?;
+                write!(f, " {} ", op.symbol())?;
+                right.fmt_at_least(f, right_min)
+            }
+        }
+    }
+}
+
+const HELP: &str = "\
+enter an expression to evaluate it, e.g. 2 * (x + 1)
+  <name> = <expression>    set a variable
+  :simplify <expression>   show the constant folded expression
+  :vars                    list the variables
+  :quit                    stop (so does end of input)";
+
+// A calculator prompt, one line at a time. pi and e are predefined.
+pub fn repl<R: BufRead, W: Write>(input: R, mut output: W) -> io::Result<()> {
+    let mut variables: HashMap<String, Value> = HashMap::from([
+        ("pi".to_string(), Value::Float(std::f64::consts::PI)),
+        ("e".to_string(), Value::Float(std::f64::consts::E)),
+    ]);
+    writeln!(output, "Calculator (type :help for commands)")?;
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let reply = match line.split_once(char::is_whitespace).unwrap_or((line, "")) {
+            (":quit", _) => break,
+            (":help", _) => HELP.to_string(),
+            (":vars", _) => {
+                let mut names: Vec<_> = variables.iter().collect();
+                names.sort_by(|a, b| a.0.cmp(b.0));
+                let lines: Vec<String> = names
+                    .iter()
+                    .map(|(name, value)| format!("{name} = {value}"))
+                    .collect();
+                lines.join("\n")
+            }
+            (":simplify", source) => match parse(source) {
+                Ok(expr) => expr.simplify().to_string(),
+                Err(error) => format!("error: {error}"),
+            },
+            _ => match assignment(line) {
+                Some((name, source)) => match parse(source).map(|expr| expr.eval(&variables)) {
+                    Ok(Ok(value)) => {
+                        variables.insert(name.to_string(), value);
+                        format!("{name} = {value}")
+                    }
+                    Ok(Err(error)) => format!("error: {error}"),
+                    Err(error) => format!("error: {error}"),
+                },
+                None => match parse(line).map(|expr| expr.eval(&variables)) {
+                    Ok(Ok(value)) => value.to_string(),
+                    Ok(Err(error)) => format!("error: {error}"),
+                    Err(error) => format!("error: {error}"),
+                },
+            },
+        };
+        writeln!(output, "{reply}")?;
+    }
+    Ok(())
+}
+
+// "x = 1 + 2" -> Some(("x", "1 + 2"))
+fn assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, source) = line.split_once('=')?;
+    let name = name.trim();
+    let is_name = name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_name.then_some((name, source))
+}
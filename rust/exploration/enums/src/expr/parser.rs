@@ -0,0 +1,119 @@
+// Precedence climbing: parse_expr(min) keeps eating operators that bind at
+// least as tightly as min, parsing their right hand side one level tighter
+// (or the same level for right associative ^).
+//
+//   + -      1  left
+//   * / %    2  left
+//   unary -  3
+//   ^        4  right   (so -2^2 is -(2^2) and 2^3^2 is 2^(3^2))
+use super::token::Token;
+use super::{BinaryOp, Expr, ParseError};
+
+pub struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    end: usize,
+}
+
+pub const UNARY_PRECEDENCE: u8 = 3;
+
+fn binary_op(token: &Token) -> Option<BinaryOp> {
+    match token {
+        Token::Plus => Some(BinaryOp::Add),
+        Token::Minus => Some(BinaryOp::Sub),
+        Token::Star => Some(BinaryOp::Mul),
+        Token::Slash => Some(BinaryOp::Div),
+        Token::Percent => Some(BinaryOp::Rem),
+        Token::Caret => Some(BinaryOp::Pow),
+        _ => None,
+    }
+}
+
+impl Parser {
+    // end is the length of the source, used as the position of "end of input".
+    pub fn new(tokens: Vec<(usize, Token)>, end: usize) -> Parser {
+        Parser {
+            tokens,
+            next: 0,
+            end,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.next)
+            .map_or(self.end, |(position, _)| *position)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.tokens.get(self.next) {
+            Some((position, token)) => ParseError::UnexpectedToken {
+                position: *position,
+                found: format!("{token:?}"),
+            },
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    // The whole input has to be one expression.
+    pub fn parse(mut self) -> Result<Expr, ParseError> {
+        let expr = self.parse_expr(0)?;
+        match self.peek() {
+            None => Ok(expr),
+            Some(_) => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_expr(&mut self, min_precedence: u8) -> Result<Expr, ParseError> {
+        let mut left = self.parse_prefix()?;
+        while let Some(op) = self.peek().and_then(binary_op) {
+            if op.precedence() < min_precedence {
+                break;
+            }
+            self.next += 1;
+            let next_min = if op.is_right_associative() {
+                op.precedence()
+            } else {
+                op.precedence() + 1
+            };
+            let right = self.parse_expr(next_min)?;
+            left = Expr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expr, ParseError> {
+        let start = self.position();
+        let token = self.peek().cloned().ok_or(ParseError::UnexpectedEnd)?;
+        self.next += 1;
+        match token {
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::Ident(name) => Ok(Expr::Variable(name)),
+            Token::Minus => Ok(Expr::Neg(Box::new(self.parse_expr(UNARY_PRECEDENCE)?))),
+            Token::Plus => self.parse_expr(UNARY_PRECEDENCE),
+            Token::LeftParen => {
+                let inner = self.parse_expr(0)?;
+                match self.peek() {
+                    Some(Token::RightParen) => {
+                        self.next += 1;
+                        Ok(inner)
+                    }
+                    Some(_) => Err(self.unexpected()),
+                    None => Err(ParseError::UnclosedParen { position: start }),
+                }
+            }
+            _ => {
+                self.next -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Turning "2 * (x + 1.5)" into tokens.
+use super::{ParseError, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(Value),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LeftParen,
+    RightParen,
+}
+
+// Each token with the (char) position it started at, for error messages.
+pub fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        let token = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '^' => Token::Caret,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            _ if c.is_ascii_digit() || c == '.' => {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    // allow a sign straight after an exponent e.g. 1e-3
+                    if matches!(chars[i], 'e' | 'E') && matches!(chars.get(i + 1), Some('+' | '-'))
+                    {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push((start, Token::Number(parse_number(&text, start)?)));
+                continue;
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push((start, Token::Ident(chars[start..i].iter().collect())));
+                continue;
+            }
+            _ => {
+                return Err(ParseError::UnexpectedChar {
+                    position: start,
+                    found: c,
+                })
+            }
+        };
+        tokens.push((start, token));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+// Whole numbers are integers, anything with a point or exponent is a float.
+fn parse_number(text: &str, position: usize) -> Result<Value, ParseError> {
+    let bad_number = || ParseError::BadNumber {
+        position,
+        text: text.to_string(),
+    };
+    if text.contains(['.', 'e', 'E']) {
+        text.parse().map(Value::Float).map_err(|_| bad_number())
+    } else {
+        text.parse().map(Value::Int).map_err(|_| bad_number())
+    }
+}
@@ -0,0 +1,11 @@
+// Enums pulled out of main.rs so they can be used (and tested) elsewhere.
+pub mod bed;
+pub mod direction;
+pub mod examples;
+pub mod expr;
+mod macros;
+pub mod measurable;
+pub mod number_words;
+pub mod room;
+pub mod rover;
+pub mod shape;
@@ -0,0 +1,55 @@
+// shapes! makes a Vec<Shape> from a short description of each one:
+//
+//   let shapes = shapes![rect 10 x 20, circle 10, polygon];
+//
+// rect takes the height then the width. It has to be `10 x 20` with spaces,
+// `10x20` is read by rust as the single number 10 with the suffix x20 so we
+// spot that and say so. Like car_park! the shapes are munched one at a time
+// so an error quotes the shape it couldn't read.
+#[macro_export]
+macro_rules! shapes {
+    (@shapes [$($shape:expr,)*]) => {
+        ::std::vec![$($shape),*]
+    };
+    (@shapes [$($shape:expr,)*] rect $height:literal x $width:literal $(, $($rest:tt)*)?) => {
+        $crate::shapes!(
+            @shapes [
+                $($shape,)*
+                $crate::shape::Shape::Rectangle {
+                    height: $height,
+                    width: $width,
+                },
+            ]
+            $($($rest)*)?
+        )
+    };
+    (@shapes [$($shape:expr,)*] circle $radius:literal $(, $($rest:tt)*)?) => {
+        $crate::shapes!(
+            @shapes [$($shape,)* $crate::shape::Shape::Circle($radius),]
+            $($($rest)*)?
+        )
+    };
+    (@shapes [$($shape:expr,)*] polygon $(, $($rest:tt)*)?) => {
+        $crate::shapes!(
+            @shapes [$($shape,)* $crate::shape::Shape::UnknownPolygon,]
+            $($($rest)*)?
+        )
+    };
+    (@shapes [$($shape:expr,)*] rect $size:literal $($rest:tt)*) => {
+        ::std::compile_error!(::std::concat!(
+            "shapes! can't read `rect ",
+            ::std::stringify!($size),
+            "`, put spaces around the x as in `rect 10 x 10`"
+        ))
+    };
+    (@shapes [$($shape:expr,)*] $($bad:tt)+) => {
+        ::std::compile_error!(::std::concat!(
+            "shapes! expected `rect HEIGHT x WIDTH`, `circle RADIUS` or `polygon` but found `",
+            ::std::stringify!($($bad)+),
+            "`"
+        ))
+    };
+    ($($shapes:tt)*) => {
+        $crate::shapes!(@shapes [] $($shapes)*)
+    };
+}
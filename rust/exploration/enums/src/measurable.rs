@@ -0,0 +1,164 @@
+// Shape::area, Bed::area and Dimensions::area were three unrelated methods
+// (one even returns f64 and the others i32), so nothing could take "anything
+// with an area". Measurable is that common trait and Registry keeps a mixed
+// Vec<Box<dyn Measurable>> of them.
+//
+// The areas are in whatever units the thing uses, cm² for beds and rooms, so
+// only compare like with like if the units matter to you.
+use crate::bed::{Bed, Dimensions};
+use crate::room::Room;
+use crate::shape::Shape;
+use describe::Describe;
+use std::any::Any;
+use std::cmp::Ordering;
+
+// To be used as a dyn Measurable the trait must be object safe, no generic
+// methods and nothing returning Self. Any as a supertrait lets us get back
+// to the concrete type (see downcast_ref below) and Describe gives us
+// something to print.
+pub trait Measurable: Describe + Any {
+    fn area(&self) -> f64;
+}
+
+impl Measurable for Shape {
+    fn area(&self) -> f64 {
+        // the inherent method wins over the trait's one here
+        Shape::area(self)
+    }
+}
+
+impl Measurable for Dimensions {
+    fn area(&self) -> f64 {
+        f64::from(Dimensions::area(self))
+    }
+}
+
+impl Measurable for Bed {
+    fn area(&self) -> f64 {
+        f64::from(Bed::area(self))
+    }
+}
+
+impl Measurable for Room {
+    fn area(&self) -> f64 {
+        f64::from(self.floor.area())
+    }
+}
+
+// Methods on the trait object itself. A &dyn Measurable can be upcast to
+// &dyn Any, which knows the concrete type's TypeId.
+impl dyn Measurable {
+    pub fn is<T: Measurable>(&self) -> bool {
+        (self as &dyn Any).is::<T>()
+    }
+
+    pub fn downcast_ref<T: Measurable>(&self) -> Option<&T> {
+        (self as &dyn Any).downcast_ref()
+    }
+
+    pub fn downcast_mut<T: Measurable>(&mut self) -> Option<&mut T> {
+        (self as &mut dyn Any).downcast_mut()
+    }
+}
+
+// Largest area first, NaN can't happen as no area is made by dividing.
+fn by_area(a: &dyn Measurable, b: &dyn Measurable) -> Ordering {
+    b.area().total_cmp(&a.area())
+}
+
+#[derive(Default)]
+pub struct Registry {
+    items: Vec<Box<dyn Measurable>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    // Generic so callers don't have to Box things themselves
+    pub fn add<T: Measurable>(&mut self, item: T) -> &mut Registry {
+        self.items.push(Box::new(item));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Measurable> {
+        self.items.iter().map(|item| item.as_ref())
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.iter().map(|item| item.area()).sum()
+    }
+
+    // The first of the largest if there's a tie
+    pub fn largest(&self) -> Option<&dyn Measurable> {
+        self.iter().min_by(|a, b| by_area(*a, *b))
+    }
+
+    // Largest first, stable so equal areas keep the order they were added in
+    pub fn sort_by_area(&mut self) {
+        self.items.sort_by(|a, b| by_area(a.as_ref(), b.as_ref()));
+    }
+
+    // Just the items that are a T, e.g. registry.of_type::<Bed>()
+    pub fn of_type<T: Measurable>(&self) -> impl Iterator<Item = &T> {
+        self.iter().filter_map(|item| item.downcast_ref::<T>())
+    }
+
+    pub fn of_type_mut<T: Measurable>(&mut self) -> impl Iterator<Item = &mut T> {
+        self.items
+            .iter_mut()
+            .filter_map(|item| item.as_mut().downcast_mut::<T>())
+    }
+
+    // Takes the Ts out, leaving everything else
+    pub fn remove_type<T: Measurable>(&mut self) -> Vec<T> {
+        let (taken, kept) = std::mem::take(&mut self.items)
+            .into_iter()
+            .partition(|item| item.is::<T>());
+        self.items = kept;
+        taken
+            .into_iter()
+            .map(|item: Box<dyn Measurable>| {
+                let any: Box<dyn Any> = item;
+                *any.downcast::<T>().expect("partitioned on is::<T>()")
+            })
+            .collect()
+    }
+}
+
+impl<T: Measurable> Extend<T> for Registry {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Measurable> FromIterator<T> for Registry {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Registry {
+        let mut registry = Registry::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+// The same sum both ways for the benchmark. Static dispatch makes a copy of
+// total_area_static for each T with area() known (and often inlined) at
+// compile time. Dynamic dispatch has one total_area_dynamic that looks area()
+// up in each item's vtable as it goes.
+pub fn total_area_static<T: Measurable>(items: &[T]) -> f64 {
+    items.iter().map(|item| item.area()).sum()
+}
+
+pub fn total_area_dynamic(items: &[Box<dyn Measurable>]) -> f64 {
+    items.iter().map(|item| item.area()).sum()
+}
@@ -0,0 +1,97 @@
+// Writing numbers out in words, "121" -> "one hundred and twenty-one".
+//
+// exhaustive_match_example in main.rs matches 1, 2 and 3 then gives up with
+// a wildcard. Here the match arms cover the small numbers and everything
+// bigger is built from them in groups (hundreds, thousands, millions, ...).
+//
+// Each language is a Locale. English can also go back from words to numbers.
+mod english;
+mod french;
+
+pub use english::{AndStyle, English, WordsError};
+pub use french::French;
+
+// A whole number from any of i64 / u64 / u128, stored as sign and size so
+// i64::MIN and u128::MAX both fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub negative: bool,
+    pub magnitude: u128,
+}
+
+impl Number {
+    pub fn new(negative: bool, magnitude: u128) -> Number {
+        // there is no minus zero
+        Number {
+            negative: negative && magnitude != 0,
+            magnitude,
+        }
+    }
+}
+
+impl From<i64> for Number {
+    fn from(n: i64) -> Number {
+        Number::new(n < 0, n.unsigned_abs() as u128)
+    }
+}
+
+impl From<i32> for Number {
+    fn from(n: i32) -> Number {
+        Number::from(n as i64)
+    }
+}
+
+impl From<u64> for Number {
+    fn from(n: u64) -> Number {
+        Number::new(false, n as u128)
+    }
+}
+
+impl From<u128> for Number {
+    fn from(n: u128) -> Number {
+        Number::new(false, n)
+    }
+}
+
+impl TryFrom<Number> for i64 {
+    type Error = Number;
+
+    fn try_from(number: Number) -> Result<i64, Number> {
+        let magnitude = i128::try_from(number.magnitude).map_err(|_| number)?;
+        let signed = if number.negative {
+            -magnitude
+        } else {
+            magnitude
+        };
+        i64::try_from(signed).map_err(|_| number)
+    }
+}
+
+impl TryFrom<Number> for u128 {
+    type Error = Number;
+
+    fn try_from(number: Number) -> Result<u128, Number> {
+        if number.negative {
+            Err(number)
+        } else {
+            Ok(number.magnitude)
+        }
+    }
+}
+
+pub trait Locale {
+    // "one hundred and twenty-one"
+    fn cardinal(&self, number: Number) -> String;
+    // "one hundred and twenty-first"
+    fn ordinal(&self, number: Number) -> String;
+}
+
+// Split into groups of three digits, least significant first: 1234567 -> [567, 234, 1].
+pub(crate) fn groups_of_thousands(mut n: u128) -> Vec<u16> {
+    let mut groups = vec![];
+    while n > 0 {
+        groups.push((n % 1000) as u16);
+        n /= 1000;
+    }
+    groups
+}
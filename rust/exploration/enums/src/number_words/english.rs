@@ -0,0 +1,278 @@
+use std::fmt;
+
+use super::{groups_of_thousands, Locale, Number};
+
+// British English says "one hundred and one", American English "one hundred one".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AndStyle {
+    British,
+    American,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct English {
+    pub and: AndStyle,
+}
+
+impl English {
+    pub const BRITISH: English = English {
+        and: AndStyle::British,
+    };
+    pub const AMERICAN: English = English {
+        and: AndStyle::American,
+    };
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// Short scale, each one is another 1000 times bigger. u128::MAX is about 340 undecillion.
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+// 1 to 99
+fn tens_words(n: u16) -> String {
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        _ if n.is_multiple_of(10) => TENS[(n / 10) as usize].to_string(),
+        _ => format!("{}-{}", TENS[(n / 10) as usize], ONES[(n % 10) as usize]),
+    }
+}
+
+impl English {
+    // 1 to 999
+    fn group_words(&self, n: u16) -> String {
+        let (hundreds, rest) = (n / 100, n % 100);
+        match (hundreds, rest) {
+            (0, _) => tens_words(rest),
+            (_, 0) => format!("{} hundred", ONES[hundreds as usize]),
+            _ => match self.and {
+                AndStyle::British => format!(
+                    "{} hundred and {}",
+                    ONES[hundreds as usize],
+                    tens_words(rest)
+                ),
+                AndStyle::American => {
+                    format!("{} hundred {}", ONES[hundreds as usize], tens_words(rest))
+                }
+            },
+        }
+    }
+
+    fn magnitude_words(&self, n: u128) -> String {
+        if n == 0 {
+            return ONES[0].to_string();
+        }
+        let groups = groups_of_thousands(n);
+        let mut parts: Vec<String> = vec![];
+        for (scale, group) in groups.iter().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+            let mut words = self.group_words(*group);
+            if scale > 0 {
+                words = format!("{words} {}", SCALES[scale]);
+            } else if self.and == AndStyle::British && *group < 100 && !parts.is_empty() {
+                // "one thousand and one"
+                words = format!("and {words}");
+            }
+            parts.push(words);
+        }
+        parts.join(" ")
+    }
+}
+
+// The last word changes: one -> first, twenty -> twentieth, six -> sixth.
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ => match word.strip_suffix('y') {
+            Some(stem) => format!("{stem}ieth"),
+            None => format!("{word}th"),
+        },
+    }
+}
+
+impl Locale for English {
+    fn cardinal(&self, number: Number) -> String {
+        let words = self.magnitude_words(number.magnitude);
+        if number.negative {
+            format!("minus {words}")
+        } else {
+            words
+        }
+    }
+
+    fn ordinal(&self, number: Number) -> String {
+        let cardinal = self.cardinal(number);
+        // the last word might be after a space or a hyphen (twenty-one)
+        let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+        format!("{}{}", &cardinal[..split], ordinal_word(&cardinal[split..]))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordsError {
+    Empty,
+    UnknownWord(String),
+    // The words are all real but in an order that isn't a number, e.g. "two three".
+    Misplaced(String),
+    TooBig,
+}
+
+impl fmt::Display for WordsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WordsError::Empty => write!(f, "no number given"),
+            WordsError::UnknownWord(word) => write!(f, "{word:?} is not a number word"),
+            WordsError::Misplaced(word) => write!(f, "{word:?} is in the wrong place"),
+            WordsError::TooBig => write!(f, "the number is too big"),
+        }
+    }
+}
+
+impl std::error::Error for WordsError {}
+
+// Where we are in a group of three digits, to reject "two three" or "twenty thirty".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Place {
+    Start,
+    // one to nine, could be followed by hundred
+    Unit,
+    Hundred,
+    // twenty, thirty, ... could be followed by one to nine
+    Tens,
+    Done,
+}
+
+fn word_value(word: &str) -> Option<u16> {
+    if let Some(n) = ONES.iter().position(|w| *w == word) {
+        return Some(n as u16);
+    }
+    TENS.iter()
+        .position(|w| !w.is_empty() && *w == word)
+        .map(|n| n as u16 * 10)
+}
+
+// "twenty-first" -> "one", the other way round to ordinal_word.
+fn cardinal_word(word: &str) -> String {
+    for n in 0..100 {
+        let cardinal = tens_words(n);
+        if ordinal_word(&cardinal) == word {
+            return cardinal;
+        }
+    }
+    for scale in SCALES.iter().skip(1).chain(&["hundred"]) {
+        if word == ordinal_word(scale) {
+            return scale.to_string();
+        }
+    }
+    word.to_string()
+}
+
+impl English {
+    // Reads cardinals and ordinals in either "and" style, so
+    // "minus one hundred and twenty-first" is Ok(-121).
+    pub fn parse(&self, words: &str) -> Result<Number, WordsError> {
+        let lowercase = words.to_lowercase();
+        let mut tokens: Vec<&str> = lowercase
+            .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+            .filter(|token| !token.is_empty() && *token != "and")
+            .collect();
+        let negative = matches!(tokens.first(), Some(&"minus") | Some(&"negative"));
+        if negative {
+            tokens.remove(0);
+        }
+        let last = tokens.pop().ok_or(WordsError::Empty)?;
+        let last = cardinal_word(last);
+        tokens.push(&last);
+
+        if tokens == ["zero"] {
+            return Ok(Number::new(negative, 0));
+        }
+        let mut total: u128 = 0;
+        // the group being built, e.g. "three hundred and six" before "thousand"
+        let mut group: u16 = 0;
+        let mut place = Place::Start;
+        let mut last_scale = SCALES.len();
+        for token in tokens {
+            let misplaced = || WordsError::Misplaced(token.to_string());
+            if let Some(value) = word_value(token) {
+                place = match (place, value) {
+                    (_, 0) => return Err(misplaced()),
+                    (Place::Start, 1..=9) => Place::Unit,
+                    (Place::Start | Place::Hundred, 10..=19) => Place::Done,
+                    (Place::Start | Place::Hundred, 20..) => Place::Tens,
+                    (Place::Hundred | Place::Tens, 1..=9) => Place::Done,
+                    _ => return Err(misplaced()),
+                };
+                group += value;
+            } else if token == "hundred" {
+                if place != Place::Unit {
+                    return Err(misplaced());
+                }
+                group *= 100;
+                place = Place::Hundred;
+            } else if let Some(scale) = SCALES.iter().position(|s| *s == token) {
+                if place == Place::Start || scale == 0 || scale >= last_scale {
+                    return Err(misplaced());
+                }
+                let multiplier = 1000u128.pow(scale as u32);
+                total = (group as u128)
+                    .checked_mul(multiplier)
+                    .and_then(|value| total.checked_add(value))
+                    .ok_or(WordsError::TooBig)?;
+                group = 0;
+                place = Place::Start;
+                last_scale = scale;
+            } else {
+                return Err(WordsError::UnknownWord(token.to_string()));
+            }
+        }
+        let magnitude = total.checked_add(group as u128).ok_or(WordsError::TooBig)?;
+        Ok(Number::new(negative, magnitude))
+    }
+}
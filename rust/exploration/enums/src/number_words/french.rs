@@ -0,0 +1,142 @@
+// French, which has a few more special cases than English:
+// - 70 is "soixante-dix" (sixty-ten), 80 "quatre-vingts" (four twenties), 90 "quatre-vingt-dix"
+// - 21, 31, ... 71 use "et": "vingt et un", "soixante et onze" (but not 81 or 91)
+// - "cents" and "quatre-vingts" lose their s when anything follows, except a
+//   noun like "millions" ("deux cents millions" but "deux cent mille")
+// - "mille" never changes and there is no "un mille"
+// - the long scale: million, milliard (10^9), billion (10^12), billiard, ...
+use super::{groups_of_thousands, Locale, Number};
+
+pub struct French;
+
+const ONES: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const TENS: [&str; 7] = [
+    "",
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+// Scale names for each power of 1000 from a million up (mille is special).
+const SCALES: [&str; 11] = [
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+];
+
+// 1 to 99, with `last` saying whether nothing follows (for quatre-vingts).
+fn tens_words(n: u16, last: bool) -> String {
+    match n {
+        0..=16 => ONES[n as usize].to_string(),
+        17..=19 => format!("dix-{}", ONES[(n - 10) as usize]),
+        // soixante-dix to soixante-dix-neuf are sixty plus ten to nineteen
+        70..=79 if n == 71 => "soixante et onze".to_string(),
+        70..=79 => format!("soixante-{}", tens_words(n - 60, last)),
+        80 if last => "quatre-vingts".to_string(),
+        80..=99 if n == 80 => "quatre-vingt".to_string(),
+        80..=99 => format!("quatre-vingt-{}", tens_words(n - 80, last)),
+        _ => {
+            let (tens, units) = (TENS[(n / 10) as usize], n % 10);
+            match units {
+                0 => tens.to_string(),
+                1 => format!("{tens} et un"),
+                _ => format!("{tens}-{}", ONES[units as usize]),
+            }
+        }
+    }
+}
+
+// 1 to 999
+fn group_words(n: u16, last: bool) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let hundred = match (hundreds, rest) {
+        (0, _) => return tens_words(rest, last),
+        (1, _) => "cent".to_string(),
+        (_, 0) if last => format!("{} cents", ONES[hundreds as usize]),
+        _ => format!("{} cent", ONES[hundreds as usize]),
+    };
+    if rest == 0 {
+        hundred
+    } else {
+        format!("{hundred} {}", tens_words(rest, last))
+    }
+}
+
+fn magnitude_words(n: u128) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let groups = groups_of_thousands(n);
+    let mut parts: Vec<String> = vec![];
+    for (scale, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+        let words = match scale {
+            0 => group_words(*group, true),
+            1 if *group == 1 => "mille".to_string(),
+            // mille is an adjective so the s goes: "deux cent mille"
+            1 => format!("{} mille", group_words(*group, false)),
+            _ => {
+                let name = SCALES[scale - 2];
+                let plural = if *group > 1 { "s" } else { "" };
+                // million is a noun so the s stays: "deux cents millions"
+                format!("{} {name}{plural}", group_words(*group, true))
+            }
+        };
+        parts.push(words);
+    }
+    parts.join(" ")
+}
+
+// premier for 1 on its own, otherwise drop a final e (or s) and add ième.
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "cinq" => "cinquième".to_string(),
+        "neuf" => "neuvième".to_string(),
+        _ => {
+            let stem = word.strip_suffix(['e', 's']).unwrap_or(word);
+            format!("{stem}ième")
+        }
+    }
+}
+
+impl Locale for French {
+    fn cardinal(&self, number: Number) -> String {
+        let words = magnitude_words(number.magnitude);
+        if number.negative {
+            format!("moins {words}")
+        } else {
+            words
+        }
+    }
+
+    fn ordinal(&self, number: Number) -> String {
+        if number.magnitude == 1 {
+            return if number.negative {
+                "moins premier"
+            } else {
+                "premier"
+            }
+            .to_string();
+        }
+        let cardinal = self.cardinal(number);
+        let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+        format!("{}{}", &cardinal[..split], ordinal_word(&cardinal[split..]))
+    }
+}
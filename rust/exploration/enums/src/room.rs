@@ -0,0 +1,31 @@
+// A room is a floor with some beds in it, so we can ask how much floor is left.
+use crate::bed::{Bed, Dimensions};
+use describe::Describe;
+
+#[derive(Debug, Describe)]
+#[describe(format = "{name}")]
+pub struct Room {
+    pub name: String,
+    pub floor: Dimensions,
+    pub beds: Vec<Bed>,
+}
+
+impl Room {
+    pub fn new(name: &str, width: i32, length: i32) -> Room {
+        Room {
+            name: name.to_string(),
+            floor: Dimensions { width, length },
+            beds: vec![],
+        }
+    }
+
+    pub fn with_bed(mut self, bed: Bed) -> Room {
+        self.beds.push(bed);
+        self
+    }
+
+    // Can be negative if we've squeezed in too many beds
+    pub fn free_area(&self) -> i32 {
+        self.floor.area() - self.beds.iter().map(Bed::area).sum::<i32>()
+    }
+}
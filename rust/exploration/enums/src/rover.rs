@@ -0,0 +1,231 @@
+// Robots driving round a bounded grid with obstacles (the Mars rover kata).
+//
+// Each robot runs a string of commands: L and R turn on the spot, M moves one
+// square forward. Robots take turns running their whole program and a move
+// that would leave the grid, hit an obstacle or hit another robot stops the
+// run with an error saying what happened.
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::direction::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Position {
+        Position { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Position {
+        let (dx, dy) = direction.delta();
+        Position::new(self.x + dx, self.y + dy)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Left,
+    Right,
+    Move,
+}
+
+impl Command {
+    pub fn from_char(letter: char) -> Option<Command> {
+        match letter.to_ascii_uppercase() {
+            'L' => Some(Command::Left),
+            'R' => Some(Command::Right),
+            'M' => Some(Command::Move),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Robot {
+    pub position: Position,
+    pub facing: Direction,
+}
+
+// Printed the kata way, e.g. "1 3 N".
+impl fmt::Display for Robot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.position.x,
+            self.position.y,
+            self.facing.letter()
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoverError {
+    InvalidCommand {
+        robot: usize,
+        // which character of the program it is, counting spaces
+        index: usize,
+        letter: char,
+    },
+    OutOfBounds {
+        robot: usize,
+        position: Position,
+    },
+    Obstacle {
+        robot: usize,
+        position: Position,
+    },
+    Collision {
+        robot: usize,
+        other: usize,
+        position: Position,
+    },
+    NoSuchRobot(usize),
+}
+
+impl fmt::Display for RoverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoverError::InvalidCommand {
+                robot,
+                index,
+                letter,
+            } => {
+                write!(f, "robot {robot}: {letter:?} at {index} is not L, R or M")
+            }
+            RoverError::OutOfBounds { robot, position } => {
+                write!(f, "robot {robot} would leave the grid at {position:?}")
+            }
+            RoverError::Obstacle { robot, position } => {
+                write!(f, "robot {robot} would hit an obstacle at {position:?}")
+            }
+            RoverError::Collision {
+                robot,
+                other,
+                position,
+            } => write!(f, "robot {robot} would hit robot {other} at {position:?}"),
+            RoverError::NoSuchRobot(robot) => write!(f, "there is no robot {robot}"),
+        }
+    }
+}
+
+impl std::error::Error for RoverError {}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub width: i32,
+    pub height: i32,
+    pub obstacles: HashSet<Position>,
+}
+
+impl Grid {
+    // Squares go from (0, 0) to (width - 1, height - 1).
+    pub fn new(width: i32, height: i32) -> Grid {
+        Grid {
+            width,
+            height,
+            obstacles: HashSet::new(),
+        }
+    }
+
+    pub fn with_obstacles(mut self, obstacles: &[(i32, i32)]) -> Grid {
+        self.obstacles
+            .extend(obstacles.iter().map(|(x, y)| Position::new(*x, *y)));
+        self
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.width).contains(&position.x) && (0..self.height).contains(&position.y)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub grid: Grid,
+    pub robots: Vec<Robot>,
+}
+
+impl Simulation {
+    pub fn new(grid: Grid) -> Simulation {
+        Simulation {
+            grid,
+            robots: vec![],
+        }
+    }
+
+    // Anything a robot can't move into, it can't be put on either.
+    fn check_free(&self, robot: usize, position: Position) -> Result<(), RoverError> {
+        if !self.grid.contains(position) {
+            return Err(RoverError::OutOfBounds { robot, position });
+        }
+        if self.grid.obstacles.contains(&position) {
+            return Err(RoverError::Obstacle { robot, position });
+        }
+        match self
+            .robots
+            .iter()
+            .position(|other| other.position == position)
+        {
+            Some(other) if other != robot => Err(RoverError::Collision {
+                robot,
+                other,
+                position,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    // Returns the new robot's number.
+    pub fn add_robot(&mut self, x: i32, y: i32, facing: Direction) -> Result<usize, RoverError> {
+        let robot = self.robots.len();
+        let position = Position::new(x, y);
+        self.check_free(robot, position)?;
+        self.robots.push(Robot { position, facing });
+        Ok(robot)
+    }
+
+    // The whole program is checked before moving so a typo doesn't leave the
+    // robot half way. A blocked move leaves the robot where it was before it.
+    pub fn run(&mut self, robot: usize, commands: &str) -> Result<Robot, RoverError> {
+        if robot >= self.robots.len() {
+            return Err(RoverError::NoSuchRobot(robot));
+        }
+        let commands = commands
+            .chars()
+            .enumerate()
+            .filter(|(_, letter)| !letter.is_whitespace())
+            .map(|(index, letter)| {
+                Command::from_char(letter).ok_or(RoverError::InvalidCommand {
+                    robot,
+                    index,
+                    letter,
+                })
+            })
+            .collect::<Result<Vec<Command>, RoverError>>()?;
+        for command in commands {
+            let current = self.robots[robot];
+            match command {
+                Command::Left => self.robots[robot].facing = current.facing.turn_left(),
+                Command::Right => self.robots[robot].facing = current.facing.turn_right(),
+                Command::Move => {
+                    let next = current.position.step(current.facing);
+                    self.check_free(robot, next)?;
+                    self.robots[robot].position = next;
+                }
+            }
+        }
+        Ok(self.robots[robot])
+    }
+
+    // One program per robot, run one robot after another.
+    pub fn run_all(&mut self, programs: &[&str]) -> Result<Vec<Robot>, RoverError> {
+        for (robot, commands) in programs.iter().enumerate() {
+            self.run(robot, commands)?;
+        }
+        Ok(self.robots.clone())
+    }
+}
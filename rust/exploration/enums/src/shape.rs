@@ -0,0 +1,29 @@
+use describe::Describe;
+use std::f64::consts;
+
+#[derive(Debug, Describe)]
+pub enum Shape {
+    // variants can include data similar to unit structs, tuple structs and structs
+    #[describe(format = "{height} x {width} rectangle")]
+    Rectangle {
+        height: i32,
+        width: i32,
+    },
+    #[describe(format = "circle of radius {0}")]
+    Circle(i32),
+    UnknownPolygon,
+}
+
+impl Shape {
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Rectangle { height, width } => f64::from(height * width),
+            Shape::Circle(radius) => Shape::circle_area(radius),
+            Shape::UnknownPolygon => 0.0,
+        }
+    }
+
+    pub fn circle_area(radius: &i32) -> f64 {
+        consts::PI * f64::from(radius * radius)
+    }
+}
@@ -0,0 +1,5 @@
+use enums::shapes;
+
+fn main() {
+    let _shapes = shapes![circle 5, rect 10x10];
+}
@@ -0,0 +1,5 @@
+use enums::shapes;
+
+fn main() {
+    let _shapes = shapes![circle 5, triangle 3 4 5];
+}
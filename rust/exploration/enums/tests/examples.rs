@@ -0,0 +1,63 @@
+use describe::Describe;
+use enums::bed::{Bed, Dimensions};
+use enums::direction::Direction;
+use enums::examples::*;
+use enums::rover::{Position, RoverError};
+use enums::shape::Shape;
+use output::Buffer;
+
+#[test]
+fn direction_and_rover() {
+    assert_eq!(
+        direction_example(&mut Buffer::new()),
+        (Direction::West, Direction::East, Direction::South)
+    );
+    let (robots, crashed) = rover_example(&mut Buffer::new());
+    let finished: Vec<String> = robots.iter().map(|robot| robot.to_string()).collect();
+    assert_eq!(finished, ["1 3 N", "5 1 E"]);
+    assert_eq!(
+        crashed,
+        RoverError::Obstacle {
+            robot: 1,
+            position: Position::new(3, 4)
+        }
+    );
+}
+
+#[test]
+fn shapes_and_beds() {
+    let (rectangle, circle) = shape_example(&mut Buffer::new());
+    assert_eq!(rectangle, 100.0);
+    assert!((circle - 314.159).abs() < 0.001);
+    assert_eq!(Shape::UnknownPolygon.area(), 0.0);
+
+    assert_eq!(
+        bed_example(&mut Buffer::new()),
+        Dimensions {
+            width: 180,
+            length: 200
+        }
+    );
+    assert_eq!(Bed::Single.area(), 90 * 190);
+    let registry = measurable_example(&mut Buffer::new());
+    assert_eq!(registry.len(), 7);
+    assert_eq!(registry.iter().next().unwrap().describe(), "box room");
+    assert_eq!(Bed::SuperKing.describe(), "super king");
+    assert_eq!(Bed::Queen.describe(), "queen (small double)");
+    assert_eq!(Bed::Queen.size().describe(), "120cm by 190cm");
+}
+
+#[test]
+fn options_and_matches() {
+    assert_eq!(option_example(&mut Buffer::new()), 10);
+    assert_eq!(if_let_example(&mut Buffer::new()), Some(10));
+    assert_eq!(
+        exhaustive_match_example(&mut Buffer::new()),
+        [
+            "twenty",
+            "one hundred and twenty-one",
+            "one thousand and one",
+            "minus seven"
+        ]
+    );
+}
@@ -0,0 +1,187 @@
+use enums::expr::{parse, repl, BinaryOp, EvalError, Expr, ParseError, Value};
+use std::collections::HashMap;
+
+fn eval(source: &str) -> Result<Value, EvalError> {
+    let variables = HashMap::from([
+        ("x".to_string(), Value::Int(3)),
+        ("y".to_string(), Value::Float(0.5)),
+    ]);
+    parse(source).unwrap().eval(&variables)
+}
+
+#[test]
+fn precedence_and_associativity() {
+    assert_eq!(eval("1 + 2 * 3"), Ok(Value::Int(7)));
+    assert_eq!(eval("(1 + 2) * 3"), Ok(Value::Int(9)));
+    assert_eq!(eval("10 - 4 - 3"), Ok(Value::Int(3)));
+    assert_eq!(eval("2 ^ 3 ^ 2"), Ok(Value::Int(512)));
+    assert_eq!(eval("-2 ^ 2"), Ok(Value::Int(-4)));
+    assert_eq!(eval("(-2) ^ 2"), Ok(Value::Int(4)));
+    assert_eq!(eval("7 % 4 * 2"), Ok(Value::Int(6)));
+    assert_eq!(eval("--x + +1"), Ok(Value::Int(4)));
+}
+
+#[test]
+fn builds_a_boxed_tree() {
+    assert_eq!(
+        parse("x * 2").unwrap(),
+        Expr::Binary {
+            op: BinaryOp::Mul,
+            left: Box::new(Expr::Variable("x".to_string())),
+            right: Box::new(Expr::Number(Value::Int(2))),
+        }
+    );
+}
+
+#[test]
+fn ints_and_floats_mix() {
+    assert_eq!(eval("7 / 2"), Ok(Value::Int(3)));
+    assert_eq!(eval("7 / 2.0"), Ok(Value::Float(3.5)));
+    assert_eq!(eval("x * y"), Ok(Value::Float(1.5)));
+    assert_eq!(eval("2 ^ -1"), Ok(Value::Float(0.5)));
+    assert_eq!(eval("1e3 + 1"), Ok(Value::Float(1001.0)));
+    assert_eq!(eval("1.5e-1"), Ok(Value::Float(0.15)));
+}
+
+#[test]
+fn errors_instead_of_panics() {
+    assert_eq!(eval("1 / 0"), Err(EvalError::DivisionByZero));
+    assert_eq!(eval("1 % 0"), Err(EvalError::DivisionByZero));
+    assert_eq!(eval("1.0 / 0"), Err(EvalError::DivisionByZero));
+    assert_eq!(eval("9223372036854775807 + 1"), Err(EvalError::Overflow));
+    assert_eq!(
+        eval("(-9223372036854775807 - 1) / -1"),
+        Err(EvalError::Overflow)
+    );
+    assert_eq!(
+        eval("-(-9223372036854775807 - 1)"),
+        Err(EvalError::Overflow)
+    );
+    assert_eq!(eval("2 ^ 64"), Err(EvalError::Overflow));
+    assert_eq!(eval("10.0 ^ 400"), Err(EvalError::NotFinite));
+    assert_eq!(
+        eval("z + 1"),
+        Err(EvalError::UnknownVariable("z".to_string()))
+    );
+}
+
+#[test]
+fn parse_errors_say_where() {
+    assert_eq!(
+        parse("1 + $"),
+        Err(ParseError::UnexpectedChar {
+            position: 4,
+            found: '$'
+        })
+    );
+    assert_eq!(parse("1 +"), Err(ParseError::UnexpectedEnd));
+    assert_eq!(
+        parse("(1 + 2"),
+        Err(ParseError::UnclosedParen { position: 0 })
+    );
+    assert!(matches!(
+        parse("1 2"),
+        Err(ParseError::UnexpectedToken { position: 2, .. })
+    ));
+    assert!(matches!(
+        parse("1 + )"),
+        Err(ParseError::UnexpectedToken { position: 4, .. })
+    ));
+    assert!(matches!(
+        parse("1.2.3"),
+        Err(ParseError::BadNumber { position: 0, .. })
+    ));
+    assert!(matches!(
+        parse("99999999999999999999"),
+        Err(ParseError::BadNumber { .. })
+    ));
+}
+
+#[test]
+fn pretty_printing_round_trips() {
+    for (source, printed) in [
+        ("1+2*3", "1 + 2 * 3"),
+        ("(1+2)*3", "(1 + 2) * 3"),
+        ("a - (b - c)", "a - (b - c)"),
+        ("(a - b) - c", "a - b - c"),
+        ("(2^3)^2", "(2 ^ 3) ^ 2"),
+        ("2^(3^2)", "2 ^ 3 ^ 2"),
+        ("(-2)^2", "(-2) ^ 2"),
+        ("-(x*y)", "-(x * y)"),
+        ("2.0 * x", "2.0 * x"),
+    ] {
+        let expr = parse(source).unwrap();
+        assert_eq!(expr.to_string(), printed);
+        assert_eq!(parse(printed).unwrap(), expr);
+    }
+}
+
+#[test]
+fn simplified_expressions_print_and_read_back() {
+    for source in [
+        "0 - 5",
+        "x * (0 - 5)",
+        "(0 - 2) ^ 2",
+        "2 ^ (0 - 1)",
+        "-9223372036854775807 - 1",
+        "(-9223372036854775807 - 1) * x",
+        "0.0 - 2.5 + x",
+    ] {
+        let simplified = parse(source).unwrap().simplify();
+        let printed = simplified.to_string();
+        let read_back = parse(&printed).unwrap_or_else(|error| panic!("{printed}: {error}"));
+        assert_eq!(
+            eval(source),
+            eval(&printed),
+            "{source} printed as {printed}"
+        );
+        // a negative number reads back as a Neg, the same once simplified
+        assert_eq!(read_back.simplify(), simplified, "{printed}");
+    }
+}
+
+#[test]
+fn simplify_hides_the_overflow_of_negating_twice() {
+    let mut variables = HashMap::new();
+    variables.insert("x".to_string(), Value::Int(i64::MIN));
+    let expr = parse("--x").unwrap();
+    assert_eq!(expr.eval(&variables), Err(EvalError::Overflow));
+    assert_eq!(expr.simplify().eval(&variables), Ok(Value::Int(i64::MIN)));
+}
+
+#[test]
+fn simplify_folds_constants() {
+    let simplified = |source: &str| parse(source).unwrap().simplify().to_string();
+    assert_eq!(simplified("1 + 2 * 3"), "7");
+    assert_eq!(simplified("x * (2 + 3)"), "x * 5");
+    assert_eq!(simplified("(x + 0) * 1"), "x");
+    assert_eq!(simplified("0 - x"), "-x");
+    assert_eq!(simplified("--x ^ 1"), "x");
+    assert_eq!(simplified("x + 0.0"), "x + 0.0");
+    // errors are kept for eval to report
+    assert_eq!(simplified("x + 1 / 0"), "x + 1 / 0");
+    // folding doesn't change the answer
+    for source in ["(x + 2) * (3 - 1) ^ 2", "y * 4 / 2 - -x", "2 ^ -1 + x % 2"] {
+        assert_eq!(eval(source), eval(&simplified(source)), "{source}");
+    }
+}
+
+#[test]
+fn repl_keeps_variables() {
+    let input = "x = 2 + 3\nx * 2\n:simplify 1 + 2 * y\nz\n1 +\n7 / 2.0\n:quit\n1 + 1\n";
+    let mut output = Vec::new();
+    repl(input.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().skip(1).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "x = 5",
+            "10",
+            "1 + 2 * y",
+            "error: z is not defined",
+            "error: unexpected end of input",
+            "3.5",
+        ]
+    );
+}
@@ -0,0 +1,26 @@
+use enums::shape::Shape;
+use enums::shapes;
+
+#[test]
+fn shapes_in_order() {
+    let shapes = shapes![rect 2 x 3, circle 1, polygon, rect 4 x 4,];
+    assert_eq!(shapes.len(), 4);
+    assert!(matches!(
+        shapes[0],
+        Shape::Rectangle {
+            height: 2,
+            width: 3
+        }
+    ));
+    assert!(matches!(shapes[1], Shape::Circle(1)));
+    assert!(matches!(shapes[2], Shape::UnknownPolygon));
+    let areas: Vec<f64> = shapes.iter().map(Shape::area).collect();
+    assert_eq!(areas[0], 6.0);
+    assert_eq!(areas[3], 16.0);
+}
+
+#[test]
+fn no_shapes() {
+    let shapes: Vec<Shape> = shapes![];
+    assert!(shapes.is_empty());
+}
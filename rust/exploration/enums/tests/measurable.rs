@@ -0,0 +1,111 @@
+use enums::bed::{Bed, Dimensions};
+use enums::measurable::{total_area_dynamic, total_area_static, Measurable, Registry};
+use enums::room::Room;
+use enums::shape::Shape;
+
+fn mixed() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .add(Bed::Single)
+        .add(Shape::Rectangle {
+            height: 100,
+            width: 200,
+        })
+        .add(Room::new("hall", 100, 300))
+        .add(Dimensions {
+            width: 10,
+            length: 10,
+        })
+        .add(Bed::Double);
+    registry
+}
+
+#[test]
+fn areas_through_the_trait() {
+    let bed: &dyn Measurable = &Bed::Single;
+    assert_eq!(bed.area(), 90.0 * 190.0);
+    let circle: &dyn Measurable = &Shape::Circle(1);
+    assert_eq!(circle.area(), std::f64::consts::PI);
+    assert_eq!(Room::new("cupboard", 50, 50).area(), 2_500.0);
+}
+
+#[test]
+fn total_and_largest() {
+    let registry = mixed();
+    assert_eq!(registry.len(), 5);
+    assert_eq!(
+        registry.total_area(),
+        17_100.0 + 20_000.0 + 30_000.0 + 100.0 + 25_650.0
+    );
+    assert_eq!(registry.largest().unwrap().describe(), "hall");
+    assert!(Registry::new().largest().is_none());
+    assert_eq!(Registry::new().total_area(), 0.0);
+}
+
+#[test]
+fn sort_is_largest_first_and_stable() {
+    let mut registry = mixed();
+    // the same area as the rectangle, added after it
+    registry.add(Dimensions {
+        width: 100,
+        length: 200,
+    });
+    registry.sort_by_area();
+    let described: Vec<String> = registry.iter().map(|item| item.describe()).collect();
+    assert_eq!(
+        described,
+        [
+            "hall",
+            "double",
+            "100 x 200 rectangle",
+            "100cm by 200cm",
+            "single",
+            "10cm by 10cm"
+        ]
+    );
+}
+
+#[test]
+fn downcasting_back_to_concrete_types() {
+    let mut registry = mixed();
+    let first = registry.iter().next().unwrap();
+    assert!(first.is::<Bed>());
+    assert!(!first.is::<Shape>());
+    assert!(matches!(first.downcast_ref::<Bed>(), Some(Bed::Single)));
+    assert!(first.downcast_ref::<Room>().is_none());
+
+    let beds: Vec<&Bed> = registry.of_type::<Bed>().collect();
+    assert!(matches!(beds[..], [Bed::Single, Bed::Double]));
+
+    for room in registry.of_type_mut::<Room>() {
+        room.beds.push(Bed::King);
+    }
+    let hall = registry.of_type::<Room>().next().unwrap();
+    assert_eq!(hall.free_area(), 30_000 - 30_000);
+
+    let taken = registry.remove_type::<Bed>();
+    assert_eq!(taken.len(), 2);
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry.of_type::<Bed>().count(), 0);
+}
+
+#[test]
+fn static_and_dynamic_dispatch_agree() {
+    let shapes = vec![
+        Shape::Circle(3),
+        Shape::Rectangle {
+            height: 2,
+            width: 5,
+        },
+    ];
+    let boxed: Vec<Box<dyn Measurable>> = vec![
+        Box::new(Shape::Circle(3)),
+        Box::new(Shape::Rectangle {
+            height: 2,
+            width: 5,
+        }),
+    ];
+    assert_eq!(total_area_static(&shapes), total_area_dynamic(&boxed));
+    let registry: Registry = shapes.into_iter().collect();
+    assert_eq!(registry.total_area(), total_area_dynamic(&boxed));
+}
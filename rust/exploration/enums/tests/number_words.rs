@@ -0,0 +1,229 @@
+use enums::number_words::{English, French, Locale, Number, WordsError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn british<N: Into<Number>>(n: N) -> String {
+    English::BRITISH.cardinal(n.into())
+}
+
+fn american<N: Into<Number>>(n: N) -> String {
+    English::AMERICAN.cardinal(n.into())
+}
+
+#[test]
+fn small_numbers() {
+    assert_eq!(british(0), "zero");
+    assert_eq!(british(1), "one");
+    assert_eq!(british(13), "thirteen");
+    assert_eq!(british(20), "twenty");
+    assert_eq!(british(42), "forty-two");
+    assert_eq!(british(99), "ninety-nine");
+    assert_eq!(british(-1), "minus one");
+}
+
+#[test]
+fn british_and_american_and() {
+    for (n, uk, us) in [
+        (100, "one hundred", "one hundred"),
+        (101, "one hundred and one", "one hundred one"),
+        (121, "one hundred and twenty-one", "one hundred twenty-one"),
+        (1_001, "one thousand and one", "one thousand one"),
+        (
+            1_100,
+            "one thousand one hundred",
+            "one thousand one hundred",
+        ),
+        (
+            2_100_001,
+            "two million one hundred thousand and one",
+            "two million one hundred thousand one",
+        ),
+        (
+            121_000,
+            "one hundred and twenty-one thousand",
+            "one hundred twenty-one thousand",
+        ),
+    ] {
+        assert_eq!(british(n), uk);
+        assert_eq!(american(n), us);
+    }
+}
+
+#[test]
+fn powers_of_ten() {
+    let expected = [
+        "one",
+        "ten",
+        "one hundred",
+        "one thousand",
+        "ten thousand",
+        "one hundred thousand",
+        "one million",
+    ];
+    for (power, words) in expected.iter().enumerate() {
+        assert_eq!(british(10u128.pow(power as u32)), *words);
+    }
+    assert_eq!(british(10u128.pow(9)), "one billion");
+    assert_eq!(british(10u128.pow(36)), "one undecillion");
+    assert_eq!(british(10u128.pow(38)), "one hundred undecillion");
+}
+
+#[test]
+fn extreme_values() {
+    assert_eq!(
+        british(i64::MAX),
+        "nine quintillion two hundred and twenty-three quadrillion three hundred and \
+         seventy-two trillion thirty-six billion eight hundred and fifty-four million \
+         seven hundred and seventy-five thousand eight hundred and seven"
+    );
+    assert!(british(i64::MIN).starts_with("minus nine quintillion"));
+    assert!(british(i64::MIN).ends_with("eight hundred and eight"));
+    assert!(british(u128::MAX).starts_with("three hundred and forty undecillion"));
+    assert!(british(u128::MAX).ends_with("four hundred and fifty-five"));
+}
+
+#[test]
+fn ordinals() {
+    for (n, words) in [
+        (0, "zeroth"),
+        (1, "first"),
+        (2, "second"),
+        (3, "third"),
+        (4, "fourth"),
+        (5, "fifth"),
+        (8, "eighth"),
+        (9, "ninth"),
+        (11, "eleventh"),
+        (12, "twelfth"),
+        (20, "twentieth"),
+        (21, "twenty-first"),
+        (100, "one hundredth"),
+        (121, "one hundred and twenty-first"),
+        (1_000_000, "one millionth"),
+        (-2, "minus second"),
+    ] {
+        assert_eq!(English::BRITISH.ordinal(n.into()), words);
+    }
+}
+
+#[test]
+fn words_back_to_numbers() {
+    let parse = |words: &str| English::BRITISH.parse(words);
+    assert_eq!(parse("zero"), Ok(0.into()));
+    assert_eq!(parse("One Hundred and Twenty-One"), Ok(121.into()));
+    assert_eq!(parse("one hundred twenty one"), Ok(121.into()));
+    assert_eq!(parse("one hundred and twenty-first"), Ok(121.into()));
+    assert_eq!(parse("minus forty-two"), Ok((-42).into()));
+    assert_eq!(parse("twelfth"), Ok(12.into()));
+    assert_eq!(parse("one thousand, two hundred"), Ok(1_200.into()));
+    assert_eq!(parse("five millionth"), Ok(5_000_000.into()));
+    assert_eq!(parse(""), Err(WordsError::Empty));
+    assert_eq!(parse("minus"), Err(WordsError::Empty));
+    assert_eq!(
+        parse("one zillion"),
+        Err(WordsError::UnknownWord("zillion".to_string()))
+    );
+    for bad in [
+        "two three",
+        "twenty thirty",
+        "hundred",
+        "twenty hundred",
+        "one thousand one million",
+        "one million million",
+        "thousand",
+        "one zero",
+        "five hundred hundred",
+        "nineteen one",
+    ] {
+        assert!(matches!(parse(bad), Err(WordsError::Misplaced(_))), "{bad}");
+    }
+    assert_eq!(
+        parse("one thousand undecillion"),
+        Err(WordsError::Misplaced("undecillion".to_string()))
+    );
+    assert_eq!(
+        parse("three hundred and fifty undecillion"),
+        Err(WordsError::TooBig)
+    );
+}
+
+#[test]
+fn round_trips() {
+    let mut rng = StdRng::seed_from_u64(34);
+    let mut numbers: Vec<Number> = vec![
+        0.into(),
+        i64::MAX.into(),
+        i64::MIN.into(),
+        u128::MAX.into(),
+        (u128::MAX - 1).into(),
+    ];
+    for power in 0..=38 {
+        numbers.push(10u128.pow(power).into());
+        numbers.push((10u128.pow(power) - 1).into());
+    }
+    numbers.extend((0..500).map(|_| Number::from(rng.gen::<i64>())));
+    numbers.extend((0..500).map(|_| Number::from(rng.gen_range(-5_000..5_000))));
+    numbers.extend((0..200).map(|_| Number::from(rng.gen::<u128>())));
+    for number in numbers {
+        for english in [English::BRITISH, English::AMERICAN] {
+            assert_eq!(english.parse(&english.cardinal(number)), Ok(number));
+            assert_eq!(english.parse(&english.ordinal(number)), Ok(number));
+        }
+    }
+}
+
+#[test]
+fn number_conversions() {
+    assert_eq!(i64::try_from(Number::from(i64::MIN)), Ok(i64::MIN));
+    assert!(i64::try_from(Number::from(u128::MAX)).is_err());
+    assert_eq!(u128::try_from(Number::from(u128::MAX)), Ok(u128::MAX));
+    assert!(u128::try_from(Number::from(-1)).is_err());
+    assert_eq!(Number::new(true, 0), Number::from(0));
+}
+
+#[test]
+fn french() {
+    let cardinal = |n: i64| French.cardinal(n.into());
+    for (n, words) in [
+        (0, "zéro"),
+        (1, "un"),
+        (16, "seize"),
+        (17, "dix-sept"),
+        (21, "vingt et un"),
+        (22, "vingt-deux"),
+        (70, "soixante-dix"),
+        (71, "soixante et onze"),
+        (77, "soixante-dix-sept"),
+        (80, "quatre-vingts"),
+        (81, "quatre-vingt-un"),
+        (91, "quatre-vingt-onze"),
+        (99, "quatre-vingt-dix-neuf"),
+        (100, "cent"),
+        (101, "cent un"),
+        (200, "deux cents"),
+        (201, "deux cent un"),
+        (1_000, "mille"),
+        (2_000, "deux mille"),
+        (80_000, "quatre-vingt mille"),
+        (200_000, "deux cent mille"),
+        (1_000_000, "un million"),
+        (200_000_000, "deux cents millions"),
+        (1_000_000_000, "un milliard"),
+        (3_000_000_000_000, "trois billions"),
+        (-5, "moins cinq"),
+    ] {
+        assert_eq!(cardinal(n), words, "{n}");
+    }
+    let ordinal = |n: i64| French.ordinal(n.into());
+    assert_eq!(ordinal(1), "premier");
+    assert_eq!(ordinal(2), "deuxième");
+    assert_eq!(ordinal(4), "quatrième");
+    assert_eq!(ordinal(5), "cinquième");
+    assert_eq!(ordinal(9), "neuvième");
+    assert_eq!(ordinal(21), "vingt et unième");
+    assert_eq!(ordinal(80), "quatre-vingtième");
+    assert_eq!(ordinal(1_000), "millième");
+    assert!(French
+        .cardinal(u128::MAX.into())
+        .starts_with("trois cent quarante sextillions"));
+}
@@ -0,0 +1,134 @@
+use enums::direction::{CompassPoint, Direction};
+use enums::rover::{Grid, Position, Robot, RoverError, Simulation};
+
+#[test]
+fn turning_four_times_gets_back() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_around(), direction.opposite());
+        assert_eq!(direction.opposite().opposite(), direction);
+        let (x, y) = direction.delta();
+        assert_eq!(direction.opposite().delta(), (-x, -y));
+        let mut turned = direction;
+        for _ in 0..4 {
+            turned = turned.turn_right();
+        }
+        assert_eq!(turned, direction);
+        assert_eq!(Direction::from_letter(direction.letter()), Some(direction));
+    }
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::from_letter('x'), None);
+}
+
+#[test]
+fn compass_points_turn_by_eighths() {
+    assert_eq!(CompassPoint::North.turn_right(), CompassPoint::NorthEast);
+    assert_eq!(CompassPoint::North.turn_left(), CompassPoint::NorthWest);
+    assert_eq!(CompassPoint::SouthWest.opposite(), CompassPoint::NorthEast);
+    for point in CompassPoint::ALL {
+        let (x, y) = point.delta();
+        assert_eq!(point.opposite().delta(), (-x, -y));
+        assert_eq!(point.turn_left().turn_right(), point);
+        assert_eq!(Direction::try_from(point).is_err(), point.is_diagonal());
+    }
+    for direction in Direction::ALL {
+        let point = CompassPoint::from(direction);
+        assert_eq!(point.delta(), direction.delta());
+        assert_eq!(
+            point.turn_right().turn_right(),
+            direction.turn_right().into()
+        );
+    }
+}
+
+#[test]
+fn kata_example() {
+    let mut simulation = Simulation::new(Grid::new(6, 6));
+    simulation.add_robot(1, 2, Direction::North).unwrap();
+    simulation.add_robot(3, 3, Direction::East).unwrap();
+    let robots = simulation.run_all(&["LMLMLMLMM", "MMRMMRMRRM"]).unwrap();
+    let printed: Vec<String> = robots.iter().map(|robot| robot.to_string()).collect();
+    assert_eq!(printed, vec!["1 3 N", "5 1 E"]);
+}
+
+#[test]
+fn commands_are_validated_before_moving() {
+    let mut simulation = Simulation::new(Grid::new(5, 5));
+    simulation.add_robot(0, 0, Direction::North).unwrap();
+    assert_eq!(
+        simulation.run(0, "MM X"),
+        Err(RoverError::InvalidCommand {
+            robot: 0,
+            index: 3,
+            letter: 'X'
+        })
+    );
+    assert_eq!(
+        simulation.run(0, " M\tL  ?"),
+        Err(RoverError::InvalidCommand {
+            robot: 0,
+            index: 6,
+            letter: '?'
+        })
+    );
+    assert_eq!(simulation.robots[0].position, Position::new(0, 0));
+    assert_eq!(simulation.run(3, "M"), Err(RoverError::NoSuchRobot(3)));
+}
+
+#[test]
+fn blocked_moves_are_errors() {
+    let grid = Grid::new(3, 3).with_obstacles(&[(1, 1)]);
+    let mut simulation = Simulation::new(grid);
+    simulation.add_robot(0, 0, Direction::East).unwrap();
+    simulation.add_robot(2, 2, Direction::South).unwrap();
+
+    // off the bottom, the robot stays on its last good square
+    assert_eq!(
+        simulation.run(0, "M RM"),
+        Err(RoverError::OutOfBounds {
+            robot: 0,
+            position: Position::new(1, -1)
+        })
+    );
+    assert_eq!(
+        simulation.robots[0],
+        Robot {
+            position: Position::new(1, 0),
+            facing: Direction::South
+        }
+    );
+    assert_eq!(
+        simulation.run(0, "LLM"),
+        Err(RoverError::Obstacle {
+            robot: 0,
+            position: Position::new(1, 1)
+        })
+    );
+    assert_eq!(
+        simulation.run(1, "MMRM"),
+        Err(RoverError::Collision {
+            robot: 1,
+            other: 0,
+            position: Position::new(1, 0)
+        })
+    );
+    assert_eq!(simulation.robots[1].position, Position::new(2, 0));
+}
+
+#[test]
+fn robots_cannot_be_placed_on_taken_squares() {
+    let mut simulation = Simulation::new(Grid::new(2, 2).with_obstacles(&[(1, 1)]));
+    assert_eq!(simulation.add_robot(0, 0, Direction::North), Ok(0));
+    assert!(matches!(
+        simulation.add_robot(0, 0, Direction::North),
+        Err(RoverError::Collision { other: 0, .. })
+    ));
+    assert!(matches!(
+        simulation.add_robot(1, 1, Direction::North),
+        Err(RoverError::Obstacle { .. })
+    ));
+    assert!(matches!(
+        simulation.add_robot(2, 0, Direction::North),
+        Err(RoverError::OutOfBounds { .. })
+    ));
+}
@@ -0,0 +1,14 @@
+// Everything the enums binary prints, checked against tests/snapshots.
+// After a deliberate change run `UPDATE_SNAPSHOTS=1 cargo test` to update it.
+use enums::examples::run_all;
+use output::{assert_snapshot, Buffer};
+
+#[test]
+fn full_output() {
+    let mut out = Buffer::new();
+    run_all(&mut out);
+    assert_snapshot(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/enums.txt"),
+        out.as_str(),
+    );
+}
@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+use crate::simulation::{simulate, Config, Report};
+use crate::{car_park, CarPark};
+use describe::Describe;
+use output::Output;
+
+// Everything main runs, in order. The file is created (or appended to) at
+// filename.
+pub fn run_all(out: &mut dyn Output, filename: &str) -> io::Result<()> {
+    // example_panic(out); // This will panic if uncommented
+    example_file_writing(out, filename)?;
+    car_example(out);
+    simulation_example(out);
+    Ok(())
+}
+
+pub fn example_panic(out: &mut dyn Output) {
+    write!(out, "Example Panic");
+    panic!("This is the panic message")
+}
+
+pub fn example_file_writing(out: &mut dyn Output, filename: &str) -> io::Result<()> {
+    let mut file: File = open_or_create(out, filename);
+    file.write_all("Well here we go !".to_string().as_bytes())
+}
+
+pub fn open_or_create(out: &mut dyn Output, filename: &str) -> File {
+    OpenOptions::new()
+        .read(false)
+        .append(true) // append implies write
+        .create(false)
+        .open(filename)
+        .unwrap_or_else(|error| {
+            writeln!(out, "{error}\nCreating file {filename}");
+            File::create(filename).expect("Failed to create")
+        })
+}
+
+// returns the car park once the old cars have gone
+pub fn car_example(out: &mut dyn Output) -> CarPark {
+    // a new audi and ford and an old audi and ford (see macros.rs)
+    let mut lot = car_park![
+        "RG54 1PQ" => (0, Black),
+        "RG54 3PQ" => (0, Blue),
+        "RG54 2PQ" => (0, Green),
+        "RG54 4PQ" => (10, Silver),
+    ];
+    let audi = &lot.cars[1];
+    writeln!(out, "audi = {audi:?}");
+    writeln!(out, "or in words {}", audi.describe());
+    lot.print_car_count(out);
+    lot.filter_old(5);
+    lot.print_car_count(out);
+    lot
+}
+
+// A seeded day at a small car park with the gates on their own threads (see
+// simulation.rs), returns the report
+pub fn simulation_example(out: &mut dyn Output) -> Report {
+    let config = Config {
+        cars: 12,
+        capacity: 3,
+        ..Config::default()
+    };
+    let report = simulate(&config);
+    writeln!(
+        out,
+        "Simulating {} cars and {} spaces",
+        config.cars, config.capacity
+    );
+    for event in &report.events {
+        writeln!(out, "{event}");
+    }
+    let stats = &report.stats;
+    writeln!(
+        out,
+        "{} parked and {} gave up, at most {} parked at once",
+        stats.parked, stats.turned_away, stats.peak
+    );
+    writeln!(
+        out,
+        "On average {:.1} spaces were taken and a car waited {:.1} ticks",
+        stats.average_occupancy, stats.average_wait
+    );
+    report
+}
@@ -0,0 +1,100 @@
+pub mod examples;
+mod macros;
+pub mod simulation;
+
+use describe::Describe;
+use output::Output;
+use std::mem::take;
+
+#[derive(Debug, Clone, Describe)]
+pub enum CarColour {
+    Red,
+    Green,
+    Blue,
+    Black,
+    Silver,
+}
+
+#[derive(Debug, Clone, Describe)]
+pub struct Car {
+    #[describe(rename = "plate")]
+    pub number_plate: String,
+    #[describe(rename = "aged")]
+    pub age: i32,
+    #[describe(nested)]
+    pub colour: CarColour,
+}
+
+// so a Car can be audit::Audited, a respray or a new plate recorded
+audit::fields!(Car {
+    number_plate,
+    age,
+    colour,
+});
+
+#[derive(Debug)]
+pub struct CarPark {
+    pub cars: Vec<Car>,
+}
+
+impl CarPark {
+    pub fn filter_old(&mut self, filter_age: i32) {
+        // I am in some method on a CarPark
+        // and I want to remove the old cars from the cars field
+
+        // How should I do this!?
+
+        // 0.
+        // self.cars.retain(|car| car.age < filter_age)
+        // (see filter_old_retain)
+
+        // The following approach is not possible because
+        // we would have a "missing" self.cars on CarLot if the rhs fails
+        // --
+        // self.cars = self.cars
+        //     .into_iter()
+        //     .filter(|car| car.age < filter_age)
+        //     .collect();
+
+        // 1.
+        self.cars = take(&mut self.cars)
+            .into_iter()
+            .filter(|car| car.age < filter_age)
+            .collect();
+
+        // 2.
+        // self.cars = self
+        //     .cars
+        //     .drain(..)
+        //     .filter(|car| car.age < filter_age)
+        //     .collect();
+        // (see filter_old_drain)
+
+        // 3.
+        // Change the input to mut self instead of &mut self
+        // but then I need to return the self (to carry on using)
+        // self.cars = self
+        //     .cars
+        //     .into_iter()
+        //     .filter(|car| car.age < filter_age)
+        //     .collect();
+        // self
+    }
+
+    // The alternatives above as their own methods so we can benchmark them.
+    pub fn filter_old_retain(&mut self, filter_age: i32) {
+        self.cars.retain(|car| car.age < filter_age)
+    }
+
+    pub fn filter_old_drain(&mut self, filter_age: i32) {
+        self.cars = self
+            .cars
+            .drain(..)
+            .filter(|car| car.age < filter_age)
+            .collect();
+    }
+
+    pub fn print_car_count(&self, out: &mut dyn Output) {
+        writeln!(out, "Car count: {}", self.cars.len());
+    }
+}
@@ -0,0 +1,44 @@
+// car_park! builds a CarPark from one line per car rather than a Car { .. }
+// literal each:
+//
+//   let lot = car_park![
+//       "RG54 1PQ" => (0, Black),
+//       "RG54 4PQ" => (10, Silver),
+//   ];
+//
+// The cars are munched one at a time (the @cars rules, with the ones done so
+// far kept in the []), so when one is malformed the error quotes it rather
+// than the whole macro just failing to match. A colour that doesn't exist is
+// left to the compiler, "no variant named `Purple` found for CarColour" is
+// already a good error.
+#[macro_export]
+macro_rules! car_park {
+    (@cars [$($car:expr,)*]) => {
+        $crate::CarPark {
+            cars: ::std::vec![$($car),*],
+        }
+    };
+    (@cars [$($car:expr,)*] $plate:expr => ($age:expr, $colour:ident) $(, $($rest:tt)*)?) => {
+        $crate::car_park!(
+            @cars [
+                $($car,)*
+                $crate::Car {
+                    number_plate: ::std::string::String::from($plate),
+                    age: $age,
+                    colour: $crate::CarColour::$colour,
+                },
+            ]
+            $($($rest)*)?
+        )
+    };
+    (@cars [$($car:expr,)*] $($bad:tt)+) => {
+        ::std::compile_error!(::std::concat!(
+            "car_park! expected `\"PLATE\" => (age, Colour)` but found `",
+            ::std::stringify!($($bad)+),
+            "`"
+        ))
+    };
+    ($($cars:tt)*) => {
+        $crate::car_park!(@cars [] $($cars)*)
+    };
+}
@@ -0,0 +1,318 @@
+// A busy day at the car park, with the entry and exit gates on threads of
+// their own sharing one CarPark.
+//
+// Cars turn up on a schedule (see schedule.rs) and are sent down a channel to
+// the entry gate. The exit gate gets the cars due to leave down another. The
+// lot is behind a Mutex, and a Condvar wakes whoever is waiting when anything
+// changes: the entry gate waits on it for a space when the lot is full, and
+// gives up on the car when its patience runs out.
+//
+// Threads run whenever the OS likes, so to get the same run every time the
+// time is a logical clock, a tick count, rather than the real time. The main
+// thread is the clock. Each tick it sends the exit gate whoever is leaving,
+// then the entry gate whoever arrived, and waits for both gates to settle
+// (done, or the entry gate asleep waiting for a space) before the next tick.
+// Within a tick things happen in the same order every run: cars leave, then
+// cars already queueing get a space (or give up), then new cars arrive.
+//
+// It's a single lane in, so a car queueing behind one that's waiting for a
+// space can't give up until it reaches the barrier.
+//
+// A stay or patience of u64::MAX is for good. The run ends when there's
+// nothing left to happen, so with those it can end with cars still parked or
+// waiting at the barrier.
+use crate::CarPark;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::thread;
+
+mod schedule;
+
+pub use schedule::{random_schedule, Arrival, Config};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Arrived,
+    Parked { waited: u64 },
+    TurnedAway { waited: u64 },
+    Left,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub tick: u64,
+    pub plate: String,
+    pub kind: EventKind,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>3} {} ", self.tick, self.plate)?;
+        match self.kind {
+            EventKind::Arrived => write!(f, "arrived"),
+            EventKind::Parked { waited: 0 } => write!(f, "parked"),
+            EventKind::Parked { waited } => write!(f, "parked after waiting {waited}"),
+            EventKind::TurnedAway { waited } => write!(f, "gave up after waiting {waited}"),
+            EventKind::Left => write!(f, "left"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub arrived: usize,
+    pub parked: usize,
+    pub turned_away: usize,
+    pub departed: usize,
+    // cars parked at the end of each tick
+    pub occupancy: Vec<usize>,
+    pub peak: usize,
+    pub average_occupancy: f64,
+    // in ticks, of the cars that got a space
+    pub average_wait: f64,
+    pub longest_wait: u64,
+}
+
+impl Stats {
+    fn new(events: &[Event], occupancy: Vec<usize>) -> Stats {
+        let count =
+            |kind: fn(&EventKind) -> bool| events.iter().filter(|event| kind(&event.kind)).count();
+        let waits: Vec<u64> = events
+            .iter()
+            .filter_map(|event| match event.kind {
+                EventKind::Parked { waited } => Some(waited),
+                _ => None,
+            })
+            .collect();
+        let average = |values: &[f64]| {
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<f64>() / values.len() as f64
+            }
+        };
+        let occupied: Vec<f64> = occupancy.iter().map(|cars| *cars as f64).collect();
+        let waited: Vec<f64> = waits.iter().map(|ticks| *ticks as f64).collect();
+        Stats {
+            arrived: count(|kind| *kind == EventKind::Arrived),
+            parked: waits.len(),
+            turned_away: count(|kind| matches!(kind, EventKind::TurnedAway { .. })),
+            departed: count(|kind| *kind == EventKind::Left),
+            peak: occupancy.iter().copied().max().unwrap_or(0),
+            average_occupancy: average(&occupied),
+            average_wait: average(&waited),
+            longest_wait: waits.iter().copied().max().unwrap_or(0),
+            occupancy,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub capacity: usize,
+    pub events: Vec<Event>,
+    pub stats: Stats,
+}
+
+// Everything the threads share, only touched with the mutex held
+struct Lot {
+    park: CarPark,
+    capacity: usize,
+    // (tick, plate) of everyone parked, for when they leave
+    leaving: Vec<(u64, String)>,
+    clock: u64,
+    events: Vec<Event>,
+    // arrivals sent to the entry gate it hasn't dealt with yet
+    arriving: usize,
+    // batches sent to the exit gate it hasn't dealt with yet
+    departing: usize,
+    // the tick the entry gate went to sleep waiting for a space, so the clock
+    // knows it's stuck rather than still busy
+    asleep_at: Option<u64>,
+    // when the car it's asleep with gives up
+    gives_up_at: u64,
+    // the run is over, so a car still waiting won't get a space
+    closed: bool,
+}
+
+impl Lot {
+    fn is_full(&self) -> bool {
+        self.park.cars.len() >= self.capacity
+    }
+
+    fn log(&mut self, plate: &str, kind: EventKind) {
+        self.events.push(Event {
+            tick: self.clock,
+            plate: plate.to_string(),
+            kind,
+        });
+    }
+
+    // Nothing more will happen this tick
+    fn is_settled(&self) -> bool {
+        self.departing == 0 && (self.arriving == 0 || self.asleep_at == Some(self.clock))
+    }
+
+    // Nothing more will happen ever, apart from new arrivals
+    fn is_finished(&self) -> bool {
+        let waiting_for_ever = self.arriving == 0 || self.gives_up_at == u64::MAX;
+        waiting_for_ever && self.leaving.iter().all(|(at, _)| *at == u64::MAX)
+    }
+}
+
+struct Shared {
+    lot: Mutex<Lot>,
+    changed: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Lot> {
+        self.lot.lock().expect("a gate thread panicked")
+    }
+
+    fn wait<'a>(&self, lot: MutexGuard<'a, Lot>) -> MutexGuard<'a, Lot> {
+        self.changed.wait(lot).expect("a gate thread panicked")
+    }
+
+    fn wait_until_settled(&self) -> MutexGuard<'_, Lot> {
+        let mut lot = self.lock();
+        while !lot.is_settled() {
+            lot = self.wait(lot);
+        }
+        lot
+    }
+}
+
+fn entry_gate(shared: &Shared, arrivals: Receiver<Arrival>) {
+    for arrival in arrivals {
+        let plate = arrival.car.number_plate.clone();
+        // u64::MAX patience is waiting forever, not a deadline that wrapped round
+        let gives_up_at = arrival.at.saturating_add(arrival.patience);
+        let mut lot = shared.lock();
+        loop {
+            if lot.closed {
+                break;
+            }
+            // cars leaving this tick go first
+            if lot.departing == 0 {
+                let waited = lot.clock - arrival.at;
+                if !lot.is_full() {
+                    lot.log(&plate, EventKind::Parked { waited });
+                    // a car can't leave the tick it parks, that tick's
+                    // departures have been and gone
+                    let leaves_at = lot.clock.saturating_add(arrival.stay.max(1));
+                    lot.leaving.push((leaves_at, plate));
+                    lot.park.cars.push(arrival.car);
+                    break;
+                }
+                if lot.clock >= gives_up_at {
+                    lot.log(&plate, EventKind::TurnedAway { waited });
+                    break;
+                }
+                lot.asleep_at = Some(lot.clock);
+                lot.gives_up_at = gives_up_at;
+                shared.changed.notify_all();
+            }
+            lot = shared.wait(lot);
+            lot.asleep_at = None;
+        }
+        lot.arriving -= 1;
+        shared.changed.notify_all();
+    }
+}
+
+fn exit_gate(shared: &Shared, departures: Receiver<Vec<String>>) {
+    for plates in departures {
+        // one batch under one lock, so the entry gate sees all the spaces at once
+        let mut lot = shared.lock();
+        for plate in plates {
+            if let Some(index) = lot
+                .park
+                .cars
+                .iter()
+                .position(|car| car.number_plate == plate)
+            {
+                lot.park.cars.remove(index);
+            }
+            lot.log(&plate, EventKind::Left);
+        }
+        lot.departing -= 1;
+        shared.changed.notify_all();
+    }
+}
+
+// Runs the schedule (sorted by arrival) until everyone has been and gone
+pub fn run(capacity: usize, mut schedule: Vec<Arrival>) -> Report {
+    schedule.sort_by_key(|arrival| arrival.at);
+    let shared = Shared {
+        lot: Mutex::new(Lot {
+            park: CarPark { cars: vec![] },
+            capacity,
+            leaving: vec![],
+            clock: 0,
+            events: vec![],
+            arriving: 0,
+            departing: 0,
+            asleep_at: None,
+            gives_up_at: 0,
+            closed: false,
+        }),
+        changed: Condvar::new(),
+    };
+    let mut occupancy = vec![];
+    // scoped threads can borrow shared, they're all joined at the end of the
+    // scope so it can't be dropped while they use it
+    thread::scope(|scope| {
+        let (arrive, arrivals) = channel();
+        let (depart, departures) = channel();
+        scope.spawn(|| entry_gate(&shared, arrivals));
+        scope.spawn(|| exit_gate(&shared, departures));
+
+        let mut schedule = schedule.into_iter().peekable();
+        for tick in 0.. {
+            let mut lot = shared.lock();
+            if schedule.peek().is_none() && lot.is_finished() {
+                // wakes a car waiting for good so the entry gate can finish
+                lot.closed = true;
+                shared.changed.notify_all();
+                break;
+            }
+            lot.clock = tick;
+            let (leaving, staying) = lot.leaving.drain(..).partition(|(at, _)| *at <= tick);
+            lot.leaving = staying;
+            let plates: Vec<String> = leaving.into_iter().map(|(_, plate)| plate).collect();
+            if !plates.is_empty() {
+                lot.departing += 1;
+                depart.send(plates).expect("the exit gate is open");
+            }
+            // the entry gate rechecks with the new time
+            shared.changed.notify_all();
+            drop(lot);
+            let mut lot = shared.wait_until_settled();
+
+            while let Some(arrival) = schedule.next_if(|arrival| arrival.at == tick) {
+                lot.log(&arrival.car.number_plate, EventKind::Arrived);
+                lot.arriving += 1;
+                arrive.send(arrival).expect("the entry gate is open");
+            }
+            drop(lot);
+            let lot = shared.wait_until_settled();
+            occupancy.push(lot.park.cars.len());
+        }
+        // closing the channels ends the gates' loops
+        drop(arrive);
+        drop(depart);
+    });
+
+    let lot = shared.lot.into_inner().expect("a gate thread panicked");
+    Report {
+        capacity,
+        stats: Stats::new(&lot.events, occupancy),
+        events: lot.events,
+    }
+}
+
+pub fn simulate(config: &Config) -> Report {
+    run(config.capacity, random_schedule(config))
+}
@@ -0,0 +1,91 @@
+// Who turns up when, made up front from a seed so a run can be repeated.
+use crate::{Car, CarColour};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Arrival {
+    pub car: Car,
+    // the tick they reach the entry gate
+    pub at: u64,
+    // ticks they stay once parked, 0 is the same as 1
+    pub stay: u64,
+    // ticks they'll wait for a space, 0 means they leave straight away
+    pub patience: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub seed: u64,
+    pub capacity: usize,
+    pub cars: usize,
+    // ticks between one arrival and the next, 0 is at the same time
+    pub gap: RangeInclusive<u64>,
+    pub stay: RangeInclusive<u64>,
+    pub patience: RangeInclusive<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            seed: 16,
+            capacity: 5,
+            cars: 30,
+            gap: 0..=2,
+            stay: 2..=10,
+            patience: 0..=3,
+        }
+    }
+}
+
+fn colour(rng: &mut StdRng) -> CarColour {
+    match rng.gen_range(0..5) {
+        0 => CarColour::Red,
+        1 => CarColour::Green,
+        2 => CarColour::Blue,
+        3 => CarColour::Black,
+        _ => CarColour::Silver,
+    }
+}
+
+// A plate like RG54 1PQ, the number being the year they were registered
+fn number_plate(rng: &mut StdRng, age: i32) -> String {
+    let mut letter = || char::from(rng.gen_range(b'A'..=b'Z'));
+    let area: String = [letter(), letter()].iter().collect();
+    let last: String = [letter(), letter()].iter().collect();
+    let digit = rng.gen_range(1..=9);
+    format!("{area}{:02} {digit}{last}", (24 - age).rem_euclid(100))
+}
+
+// Arrivals in the order they happen
+pub fn random_schedule(config: &Config) -> Vec<Arrival> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut at = 0;
+    // the gates tell cars apart by their plates
+    let mut plates = HashSet::new();
+    (0..config.cars)
+        .map(|_| {
+            let age = rng.gen_range(0..15);
+            let plate = loop {
+                let plate = number_plate(&mut rng, age);
+                if plates.insert(plate.clone()) {
+                    break plate;
+                }
+            };
+            let arrival = Arrival {
+                car: Car {
+                    number_plate: plate,
+                    age,
+                    colour: colour(&mut rng),
+                },
+                at,
+                stay: rng.gen_range(config.stay.clone()),
+                patience: rng.gen_range(config.patience.clone()),
+            };
+            at += rng.gen_range(config.gap.clone());
+            arrival
+        })
+        .collect()
+}
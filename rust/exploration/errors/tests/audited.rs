@@ -0,0 +1,37 @@
+use audit::{diff, Audited};
+use errors::{Car, CarColour};
+
+fn car() -> Car {
+    Car {
+        number_plate: String::from("RG54 1PQ"),
+        age: 3,
+        colour: CarColour::Red,
+    }
+}
+
+#[test]
+fn a_respray_and_a_new_plate_are_recorded() {
+    let mut car = Audited::new(car());
+    car.edit().colour = CarColour::Black;
+    car.update(|car| {
+        car.number_plate = String::from("SDJ 16");
+        car.age += 1;
+    });
+    let changes: Vec<String> = car.changes().map(ToString::to_string).collect();
+    assert_eq!(
+        changes,
+        [
+            "#1 colour: Red -> Black",
+            "#2 number_plate: \"RG54 1PQ\" -> \"SDJ 16\"",
+            "#3 age: 3 -> 4",
+        ]
+    );
+    car.undo();
+    assert_eq!(car.number_plate, "RG54 1PQ");
+    assert!(matches!(car.as_of(0).colour, CarColour::Red));
+    let fields: Vec<&str> = diff(car.original(), &car)
+        .iter()
+        .map(|difference| difference.field)
+        .collect();
+    assert_eq!(fields, ["colour"]);
+}
@@ -0,0 +1,5 @@
+use errors::car_park;
+
+fn main() {
+    let _lot = car_park!["RG54 1PQ" (0, Black)];
+}
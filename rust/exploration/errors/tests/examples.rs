@@ -0,0 +1,43 @@
+use errors::examples::*;
+use output::Buffer;
+use std::fs;
+
+#[test]
+fn file_writing_creates_then_appends() {
+    let path = std::env::temp_dir().join(format!("errors-example-{}.txt", std::process::id()));
+    let filename = path.to_str().unwrap();
+    let _ = fs::remove_file(&path);
+    example_file_writing(&mut Buffer::new(), filename).unwrap();
+    example_file_writing(&mut Buffer::new(), filename).unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "Well here we go !Well here we go !"
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "This is the panic message")]
+fn panics() {
+    example_panic(&mut Buffer::new());
+}
+
+#[test]
+fn old_cars_are_filtered() {
+    let mut out = Buffer::new();
+    let lot = car_example(&mut out);
+    assert_eq!(
+        out.lines()[1..],
+        [
+            "or in words Car: plate RG54 3PQ, aged 0, colour blue",
+            "Car count: 4",
+            "Car count: 3"
+        ]
+    );
+    let plates: Vec<&str> = lot
+        .cars
+        .iter()
+        .map(|car| car.number_plate.as_str())
+        .collect();
+    assert_eq!(plates, ["RG54 1PQ", "RG54 3PQ", "RG54 2PQ"]);
+}
@@ -0,0 +1,26 @@
+use errors::{car_park, CarColour};
+
+#[test]
+fn car_park_builds_the_cars_in_order() {
+    let lot = car_park![
+        "RG54 1PQ" => (0, Black),
+        "RG54 2PQ" => (3 + 4, Silver)
+    ];
+    assert_eq!(lot.cars.len(), 2);
+    assert_eq!(lot.cars[0].number_plate, "RG54 1PQ");
+    assert!(matches!(lot.cars[0].colour, CarColour::Black));
+    assert_eq!(lot.cars[1].age, 7);
+    assert!(matches!(lot.cars[1].colour, CarColour::Silver));
+}
+
+#[test]
+fn car_park_takes_a_trailing_comma_and_any_plate_expression() {
+    let plate = format!("RG54 {}PQ", 9);
+    let lot = car_park![plate => (1, Red),];
+    assert_eq!(lot.cars[0].number_plate, "RG54 9PQ");
+}
+
+#[test]
+fn empty_car_park() {
+    assert!(car_park![].cars.is_empty());
+}
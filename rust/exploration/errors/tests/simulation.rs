@@ -0,0 +1,197 @@
+use errors::simulation::{
+    random_schedule, run, simulate, Arrival, Config, Event, EventKind, Report,
+};
+use errors::{Car, CarColour};
+
+fn arrival(plate: &str, at: u64, stay: u64, patience: u64) -> Arrival {
+    Arrival {
+        car: Car {
+            number_plate: plate.to_string(),
+            age: 0,
+            colour: CarColour::Red,
+        },
+        at,
+        stay,
+        patience,
+    }
+}
+
+fn log(report: &Report) -> Vec<String> {
+    report.events.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn the_same_seed_is_the_same_run() {
+    let config = Config::default();
+    let first = simulate(&config);
+    // the threads are scheduled differently every time, the logical clock
+    // means it doesn't matter
+    for _ in 0..20 {
+        assert_eq!(simulate(&config).events, first.events);
+    }
+    let other = simulate(&Config { seed: 17, ..config });
+    assert_ne!(other.events, first.events);
+}
+
+#[test]
+fn a_full_lot_makes_cars_wait_or_give_up() {
+    let schedule = vec![
+        arrival("A", 0, 3, 0),
+        arrival("B", 1, 2, 5),
+        // stuck behind B, it can't give up until B is through the barrier
+        arrival("C", 1, 2, 0),
+        arrival("D", 6, 1, 0),
+    ];
+    let report = run(1, schedule);
+    assert_eq!(
+        log(&report),
+        [
+            "  0 A arrived",
+            "  0 A parked",
+            "  1 B arrived",
+            "  1 C arrived",
+            "  3 A left",
+            "  3 B parked after waiting 2",
+            "  3 C gave up after waiting 2",
+            "  5 B left",
+            "  6 D arrived",
+            "  6 D parked",
+            "  7 D left",
+        ]
+    );
+    assert_eq!(report.stats.occupancy, [1, 1, 1, 1, 1, 0, 1, 0]);
+}
+
+#[test]
+fn a_space_freed_this_tick_is_used_this_tick() {
+    // A leaves at 2, the tick B's patience runs out, and leaving comes first
+    let report = run(1, vec![arrival("A", 0, 2, 0), arrival("B", 1, 1, 1)]);
+    assert!(report.events.contains(&Event {
+        tick: 2,
+        plate: "B".to_string(),
+        kind: EventKind::Parked { waited: 1 },
+    }));
+}
+
+#[test]
+fn staying_no_time_at_all_is_a_tick() {
+    let report = run(1, vec![arrival("A", 0, 0, 0), arrival("B", 0, 0, 1)]);
+    assert_eq!(
+        log(&report),
+        [
+            "  0 A arrived",
+            "  0 B arrived",
+            "  0 A parked",
+            "  1 A left",
+            "  1 B parked after waiting 1",
+            "  2 B left",
+        ]
+    );
+    let config = Config {
+        stay: 0..=1,
+        ..Config::default()
+    };
+    let stats = simulate(&config).stats;
+    assert_eq!(stats.departed, stats.parked);
+}
+
+#[test]
+fn waiting_and_staying_for_good() {
+    let schedule = vec![
+        arrival("A", 0, u64::MAX, 0),
+        arrival("B", 1, 2, u64::MAX),
+        arrival("C", 2, u64::MAX, u64::MAX),
+        arrival("D", 2, 1, u64::MAX),
+    ];
+    let report = run(2, schedule);
+    assert_eq!(
+        log(&report),
+        [
+            "  0 A arrived",
+            "  0 A parked",
+            "  1 B arrived",
+            "  1 B parked",
+            "  2 C arrived",
+            "  2 D arrived",
+            "  3 B left",
+            "  3 C parked after waiting 1",
+            // D waits for a space for good
+        ]
+    );
+    assert_eq!(report.stats.occupancy, [1, 2, 2, 2]);
+}
+
+#[test]
+fn no_spaces_turns_everyone_away() {
+    let report = run(0, vec![arrival("A", 0, 1, 2), arrival("B", 0, 1, 0)]);
+    assert_eq!(
+        log(&report)[2..],
+        [
+            "  2 A gave up after waiting 2",
+            "  2 B gave up after waiting 2"
+        ]
+    );
+    assert_eq!(report.stats.parked, 0);
+    assert_eq!(report.stats.turned_away, 2);
+}
+
+#[test]
+fn an_empty_day() {
+    let report = run(3, vec![]);
+    assert!(report.events.is_empty());
+    assert_eq!(report.stats.occupancy, Vec::<usize>::new());
+    assert_eq!(report.stats.average_occupancy, 0.0);
+}
+
+#[test]
+fn statistics_add_up() {
+    for seed in 0..10 {
+        let config = Config {
+            seed,
+            capacity: 4,
+            cars: 40,
+            ..Config::default()
+        };
+        let report = simulate(&config);
+        let stats = &report.stats;
+        assert_eq!(stats.arrived, config.cars);
+        assert_eq!(stats.parked + stats.turned_away, stats.arrived);
+        assert_eq!(stats.departed, stats.parked);
+        assert!(stats.occupancy.iter().all(|cars| *cars <= config.capacity));
+        assert_eq!(stats.peak, *stats.occupancy.iter().max().unwrap());
+        assert_eq!(stats.occupancy.last(), Some(&0));
+        let total: usize = stats.occupancy.iter().sum();
+        let average = total as f64 / stats.occupancy.len() as f64;
+        assert!((stats.average_occupancy - average).abs() < 1e-9);
+        // the events are in time order
+        assert!(report
+            .events
+            .windows(2)
+            .all(|pair| pair[0].tick <= pair[1].tick));
+    }
+}
+
+#[test]
+fn schedules_are_seeded() {
+    let config = Config::default();
+    let schedule = random_schedule(&config);
+    assert_eq!(schedule.len(), config.cars);
+    let plates: Vec<&str> = schedule
+        .iter()
+        .map(|arrival| arrival.car.number_plate.as_str())
+        .collect();
+    let again = random_schedule(&config);
+    assert!(again
+        .iter()
+        .zip(&plates)
+        .all(|(arrival, plate)| arrival.car.number_plate == *plate));
+    let mut unique = plates.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), plates.len());
+    assert!(schedule.windows(2).all(|pair| pair[0].at <= pair[1].at));
+    assert!(schedule
+        .iter()
+        .all(|arrival| config.stay.contains(&arrival.stay)
+            && config.patience.contains(&arrival.patience)));
+}
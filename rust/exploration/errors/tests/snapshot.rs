@@ -0,0 +1,22 @@
+// Everything the errors binary prints, checked against tests/snapshots.
+// After a deliberate change run `UPDATE_SNAPSHOTS=1 cargo test` to update it.
+use errors::examples::run_all;
+use output::{assert_snapshot, Buffer};
+use std::fs;
+
+#[test]
+fn full_output() {
+    let path = std::env::temp_dir().join(format!("errors-snapshot-{}.txt", std::process::id()));
+    let filename = path.to_str().unwrap();
+    // so the file is always created, which prints the same message every time
+    let _ = fs::remove_file(&path);
+    let mut out = Buffer::new();
+    run_all(&mut out, filename).unwrap();
+    fs::remove_file(&path).unwrap();
+    // the temporary folder depends on the machine
+    let output = out.as_str().replace(filename, "simon.txt");
+    assert_snapshot(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/errors.txt"),
+        &output,
+    );
+}
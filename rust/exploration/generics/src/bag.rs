@@ -0,0 +1,39 @@
+use crate::shuffle;
+use describe::Describe;
+use rand::Rng;
+
+// The derive can't guess the items need Debug, so we tell it with a bound
+#[derive(Debug, Describe)]
+#[describe(bound = "T: std::fmt::Debug")]
+pub struct Bag<T> {
+    pub brand: String,
+    #[describe(format = "{:?}")]
+    pub items: Vec<T>,
+}
+
+impl<T> Bag<T> {
+    pub fn get_first_item(&self) -> Option<&T> {
+        self.items.first()
+    }
+}
+
+impl Bag<JugglingBall> {
+    pub fn juggle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        shuffle(&mut self.items, rng)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JugglingBallColour {
+    Red,
+    Blue,
+    Green,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JugglingBall {
+    pub colour: JugglingBallColour,
+}
+
+// cheeky aliasing of a type here for brevity
+pub type JBC = JugglingBallColour;
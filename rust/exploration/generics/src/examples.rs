@@ -0,0 +1,116 @@
+// use len_trait::len::Len;
+use crate::bag;
+use crate::bag::{Bag, JugglingBall};
+use crate::len::get_len;
+use crate::{shuffle, shuffle_f64_slice, shuffle_i32_slice};
+use output::Output;
+use rand::RngCore;
+
+// Everything main runs, in order. The shuffles use rng.
+pub fn run_all(out: &mut dyn Output, rng: &mut dyn RngCore) {
+    per_type_functions(out, rng);
+    generic_functions(out, rng);
+    generic_structs(out, rng);
+    custom_trait(out);
+}
+
+// returns the shuffled vec, array and f64 vec
+pub fn per_type_functions(
+    out: &mut dyn Output,
+    rng: &mut dyn RngCore,
+) -> (Vec<i32>, [i32; 5], Vec<f64>) {
+    writeln!(out, "Per type functions");
+    let mut example_vec: Vec<i32> = vec![1, 2, 3, 4, 5];
+    let mut example_array: [i32; 5] = [1, 2, 3, 4, 5];
+    writeln!(out, "Before shuffle example_array: {:?}", example_array);
+    let example_slice: &mut [i32] = &mut example_array[..];
+    let mut example_f64_vec: Vec<f64> = example_vec.iter().map(|x| *x as f64).collect::<Vec<_>>();
+    writeln!(out, "Before shuffle example_slice: {:?}", example_slice);
+    writeln!(out, "Before shuffle example_vec: {:?}", example_vec);
+    writeln!(out, "Before shuffle example_f64_vec: {:?}", example_f64_vec);
+    // We have to use a different shuffle for the different types
+    shuffle_i32_slice(&mut example_vec, rng);
+    shuffle_i32_slice(example_slice, rng);
+    shuffle_f64_slice(&mut example_f64_vec, rng);
+    writeln!(out, "After shuffle example_slice: {:?}", example_slice);
+    writeln!(
+        out,
+        "After shuffle example_array (same as the slice !): {example_array:?}",
+    );
+    writeln!(out, "After shuffle example_vec: {:?}", example_vec);
+    writeln!(out, "After shuffle example_f64_vec: {:?}", example_f64_vec);
+    writeln!(out);
+    (example_vec, example_array, example_f64_vec)
+}
+
+// returns the shuffled vec, array and f64 vec
+pub fn generic_functions(
+    out: &mut dyn Output,
+    rng: &mut dyn RngCore,
+) -> (Vec<i32>, [i32; 5], Vec<f64>) {
+    writeln!(out, "Generic functions");
+    let mut example_vec: Vec<i32> = vec![1, 2, 3, 4, 5];
+    let mut example_array: [i32; 5] = [1, 2, 3, 4, 5];
+    writeln!(out, "Before shuffle example_array: {:?}", example_array);
+    let example_slice: &mut [i32] = &mut example_array[..];
+    let mut example_f64_vec: Vec<f64> = example_vec.iter().map(|x| *x as f64).collect::<Vec<_>>();
+    writeln!(out, "Before shuffle example_slice: {:?}", example_slice);
+    writeln!(out, "Before shuffle example_vec: {:?}", example_vec);
+    writeln!(out, "Before shuffle example_f64_vec: {:?}", example_f64_vec);
+    // We have to use a different shuffle for the different types
+    shuffle(&mut example_vec, rng);
+    shuffle(example_slice, rng);
+    shuffle(&mut example_f64_vec, rng);
+    writeln!(out, "After shuffle example_slice: {:?}", example_slice);
+    writeln!(
+        out,
+        "After shuffle example_array (same as the slice !): {example_array:?}",
+    );
+    writeln!(out, "After shuffle example_vec: {:?}", example_vec);
+    writeln!(out, "After shuffle example_f64_vec: {:?}", example_f64_vec);
+    writeln!(out);
+    (example_vec, example_array, example_f64_vec)
+}
+
+pub fn generic_structs(
+    out: &mut dyn Output,
+    rng: &mut dyn RngCore,
+) -> (Bag<&'static str>, Bag<JugglingBall>) {
+    writeln!(out, "Generic structs");
+    writeln!(
+        out,
+        "Here we make two bags, one with strings and one with juggling balls"
+    );
+    // bag! (see macros.rs) saves writing out the Bag { brand, items } literals
+    let nike_bag: Bag<&str> = bag!("Nike"; "hat", "shirt", "pants");
+    let mut juggling_bag: Bag<JugglingBall> = bag!("Clown max"; balls Red, Blue, Green);
+    writeln!(
+        out,
+        "My {}  bag has items {:?}",
+        nike_bag.brand, nike_bag.items
+    );
+    writeln!(
+        out,
+        "My {}  bag has items {:?}",
+        juggling_bag.brand, juggling_bag.items
+    );
+    writeln!(
+        out,
+        "The first item in nike_bag is {:?}",
+        nike_bag.get_first_item()
+    );
+    juggling_bag.juggle(rng);
+    (nike_bag, juggling_bag)
+}
+
+// returns both lengths
+pub fn custom_trait(out: &mut dyn Output) -> (i32, i32) {
+    writeln!(out, "Custom traits");
+    let example_vec: Vec<i32> = vec![1, 2, 3, 4, 5];
+    let example_array: [i32; 5] = [1, 2, 3, 4, 5];
+    let lens = (get_len(example_vec), get_len(&example_array));
+    writeln!(out, "example_vec has len {}", lens.0);
+    writeln!(out, "example_array has len {}", lens.1);
+    writeln!(out);
+    lens
+}
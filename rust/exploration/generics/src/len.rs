@@ -0,0 +1,30 @@
+pub trait Len {
+    fn len(&self) -> usize;
+
+    // a default method, implementors get it for free
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Len for Vec<i32> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Len for &[T; 5] {
+    // I don't understand this fully
+    // I think the ** goes from &&[T; 5] to [T; 5]
+    // where len is defined
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+pub fn get_len<T>(t: T) -> i32
+where
+    T: Len,
+{
+    t.len() as i32
+}
@@ -0,0 +1,36 @@
+// The shuffles live in the library so they can be benchmarked against each other.
+use rand::Rng;
+
+pub mod bag;
+pub mod examples;
+pub mod len;
+mod macros;
+
+// The rng is passed in so callers choose, rand::thread_rng() for real
+// randomness or a seeded StdRng for the same shuffle every time.
+pub fn shuffle_i32_slice<'a, R: Rng + ?Sized>(array: &'a mut [i32], rng: &mut R) -> &'a mut [i32] {
+    // A slice is a reference to any contiguous subset of elements
+    // we can use the slice as a reference to the array/vector data so typing it like this
+    // is more flexible than typing it as a vector
+    let len: usize = array.len();
+    for i in 0..len {
+        let j = rng.gen_range(0..len);
+        array.swap(i, j);
+    }
+    array
+}
+
+pub fn shuffle_f64_slice<'a, R: Rng + ?Sized>(array: &'a mut [f64], rng: &mut R) -> &'a mut [f64] {
+    for i in 0..array.len() {
+        let j = rng.gen_range(0..array.len());
+        array.swap(i, j);
+    }
+    array
+}
+
+pub fn shuffle<T, R: Rng + ?Sized>(array: &mut [T], rng: &mut R) {
+    for i in 0..array.len() {
+        let j = rng.gen_range(0..array.len());
+        array.swap(i, j);
+    }
+}
@@ -0,0 +1,44 @@
+// bag! fills a Bag with a brand and its items:
+//
+//   let nike_bag = bag!("Nike"; "hat", "shirt", "pants");
+//   let juggling_bag = bag!("Clown max"; balls Red, Blue, Green);
+//   let empty: Bag<&str> = bag!("Nike");
+//
+// `balls` is a shortcut for a bag of juggling balls, the rest of the items can
+// be any expressions. The brand is split from the items with `;` as a `,`
+// would make the brand look like the first item.
+#[macro_export]
+macro_rules! bag {
+    ($brand:expr $(;)?) => {
+        $crate::bag::Bag {
+            brand: ::std::string::String::from($brand),
+            items: ::std::vec::Vec::new(),
+        }
+    };
+    ($brand:expr; balls $($colour:ident),+ $(,)?) => {
+        $crate::bag::Bag {
+            brand: ::std::string::String::from($brand),
+            items: ::std::vec![$($crate::bag::JugglingBall {
+                colour: $crate::bag::JugglingBallColour::$colour,
+            }),+],
+        }
+    };
+    ($brand:expr; $($item:expr),+ $(,)?) => {
+        $crate::bag::Bag {
+            brand: ::std::string::String::from($brand),
+            items: ::std::vec![$($item),+],
+        }
+    };
+    ($brand:expr, $($items:tt)*) => {
+        ::std::compile_error!(::std::concat!(
+            "bag! separates the brand from the items with `;`, as in bag!(",
+            ::std::stringify!($brand),
+            "; ",
+            ::std::stringify!($($items)*),
+            ")"
+        ))
+    };
+    () => {
+        ::std::compile_error!("bag! needs at least a brand, as in bag!(\"Nike\"; \"hat\", \"shirt\")")
+    };
+}
@@ -0,0 +1,8 @@
+// Each file in compile_fail/ misuses a macro and the matching .stderr is the
+// error we expect. After changing an error message regenerate them with
+// TRYBUILD=overwrite cargo test
+#[test]
+fn malformed_input_is_a_compile_error() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,5 @@
+use generics::bag;
+
+fn main() {
+    let _bag: generics::bag::Bag<&str> = bag!();
+}
@@ -0,0 +1,46 @@
+use describe::Describe;
+use generics::bag::{JugglingBall, JBC};
+use generics::examples::*;
+use generics::len::Len;
+use output::Buffer;
+use rand::thread_rng;
+
+fn sorted(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort();
+    values
+}
+
+// The shuffles are random so only check nothing was lost or added.
+#[test]
+fn shuffles_keep_the_values() {
+    for (vec, array, f64_vec) in [
+        per_type_functions(&mut Buffer::new(), &mut thread_rng()),
+        generic_functions(&mut Buffer::new(), &mut thread_rng()),
+    ] {
+        assert_eq!(sorted(vec), [1, 2, 3, 4, 5]);
+        assert_eq!(sorted(array.to_vec()), [1, 2, 3, 4, 5]);
+        let f64_vec: Vec<i32> = f64_vec.iter().map(|x| *x as i32).collect();
+        assert_eq!(sorted(f64_vec), [1, 2, 3, 4, 5]);
+    }
+}
+
+#[test]
+fn bags() {
+    let (nike_bag, juggling_bag) = generic_structs(&mut Buffer::new(), &mut thread_rng());
+    assert_eq!(nike_bag.get_first_item(), Some(&"hat"));
+    assert_eq!(
+        nike_bag.describe(),
+        format!("Bag: brand {}, items {:?}", nike_bag.brand, nike_bag.items)
+    );
+    assert_eq!(juggling_bag.brand, "Clown max");
+    assert_eq!(juggling_bag.items.len(), 3);
+    for colour in [JBC::Red, JBC::Blue, JBC::Green] {
+        assert!(juggling_bag.items.contains(&JugglingBall { colour }));
+    }
+}
+
+#[test]
+fn lens() {
+    assert_eq!(custom_trait(&mut Buffer::new()), (5, 5));
+    assert!(Len::is_empty(&Vec::<i32>::new()));
+}
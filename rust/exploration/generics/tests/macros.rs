@@ -0,0 +1,31 @@
+use generics::bag;
+use generics::bag::{Bag, JugglingBall, JBC};
+
+#[test]
+fn bag_of_items() {
+    let bag = bag!("Nike"; "hat", "shirt");
+    assert_eq!(bag.brand, "Nike");
+    assert_eq!(bag.items, ["hat", "shirt"]);
+    let numbers = bag!(String::from("Numbers"); 1 + 1, 2 * 3,);
+    assert_eq!(numbers.items, [2, 6]);
+}
+
+#[test]
+fn bag_of_juggling_balls() {
+    let bag = bag!("Clown max"; balls Red, Green);
+    assert_eq!(
+        bag.items,
+        [
+            JugglingBall { colour: JBC::Red },
+            JugglingBall { colour: JBC::Green }
+        ]
+    );
+}
+
+#[test]
+fn empty_bag() {
+    let bag: Bag<&str> = bag!("Nike");
+    assert!(bag.items.is_empty());
+    let bag: Bag<&str> = bag!("Nike";);
+    assert_eq!(bag.get_first_item(), None);
+}
@@ -0,0 +1,17 @@
+// Everything the generics binary prints, checked against tests/snapshots.
+// After a deliberate change run `UPDATE_SNAPSHOTS=1 cargo test` to update it.
+use generics::examples::run_all;
+use output::{assert_snapshot, Buffer};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn full_output() {
+    let mut out = Buffer::new();
+    // seeded so the shuffles come out the same every time
+    run_all(&mut out, &mut StdRng::seed_from_u64(41));
+    assert_snapshot(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/generics.txt"),
+        out.as_str(),
+    );
+}
@@ -0,0 +1,177 @@
+use rand::Rng;
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::RangeInclusive;
+
+use crate::scores::HighScores;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub range: RangeInclusive<u32>,
+    // None means keep guessing forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            range: 1..=100,
+            max_attempts: Some(7),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GuessError {
+    NotANumber(String),
+    OutOfRange(u32),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::NotANumber(text) => write!(f, "{text:?} is not a number"),
+            GuessError::OutOfRange(guess) => write!(f, "{guess} is out of range"),
+        }
+    }
+}
+
+pub fn parse_guess(line: &str, range: &RangeInclusive<u32>) -> Result<u32, GuessError> {
+    let text = line.trim();
+    let guess: u32 = text
+        .parse()
+        .map_err(|_| GuessError::NotANumber(text.to_string()))?;
+    if range.contains(&guess) {
+        Ok(guess)
+    } else {
+        Err(GuessError::OutOfRange(guess))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    TooSmall,
+    TooBig,
+    Correct { attempts: u32 },
+    OutOfAttempts { secret: u32 },
+}
+
+#[derive(Debug)]
+pub struct Game {
+    config: Config,
+    secret: u32,
+    attempts: u32,
+    // Some once won or lost, and then every guess gets this answer again
+    finished: Option<Outcome>,
+}
+
+impl Game {
+    pub fn new<R: Rng>(config: Config, rng: &mut R) -> Game {
+        let secret = rng.gen_range(config.range.clone());
+        Game {
+            config,
+            secret,
+            attempts: 0,
+            finished: None,
+        }
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.config
+            .max_attempts
+            .map(|max| max.saturating_sub(self.attempts))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.is_some()
+    }
+
+    // A finished game doesn't count any more guesses, a lost game stays lost
+    pub fn guess(&mut self, guess: u32) -> Outcome {
+        if let Some(outcome) = &self.finished {
+            return outcome.clone();
+        }
+        self.attempts += 1;
+        let outcome = match guess.cmp(&self.secret) {
+            Ordering::Equal => Outcome::Correct {
+                attempts: self.attempts,
+            },
+            _ if self.attempts_left() == Some(0) => Outcome::OutOfAttempts {
+                secret: self.secret,
+            },
+            Ordering::Less => Outcome::TooSmall,
+            Ordering::Greater => Outcome::TooBig,
+        };
+        if matches!(
+            outcome,
+            Outcome::Correct { .. } | Outcome::OutOfAttempts { .. }
+        ) {
+            self.finished = Some(outcome.clone());
+        }
+        outcome
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameResult {
+    Won { attempts: u32 },
+    Lost { secret: u32 },
+    // The input ran out before the game finished.
+    Quit,
+}
+
+// Play a whole game, reading a guess per line. Bad lines are reported and
+// don't use up an attempt. A win is added to the high scores.
+pub fn play<R: Rng, I: BufRead, W: Write>(
+    config: Config,
+    rng: &mut R,
+    player: &str,
+    input: I,
+    mut output: W,
+    scores: &mut HighScores,
+) -> io::Result<GameResult> {
+    let (min, max) = (*config.range.start(), *config.range.end());
+    let mut game = Game::new(config, rng);
+    match game.attempts_left() {
+        Some(attempts) => writeln!(
+            output,
+            "Guess the number between {min} and {max} in {attempts} attempts."
+        )?,
+        None => writeln!(output, "Guess the number between {min} and {max}.")?,
+    }
+    let mut lines = input.lines();
+    loop {
+        writeln!(output, "Please input your guess.")?;
+        let Some(line) = lines.next() else {
+            writeln!(output, "Bye!")?;
+            return Ok(GameResult::Quit);
+        };
+        let guess = match parse_guess(&line?, &(min..=max)) {
+            Ok(guess) => guess,
+            Err(error) => {
+                writeln!(output, "{error}, try a number between {min} and {max}.")?;
+                continue;
+            }
+        };
+        match game.guess(guess) {
+            Outcome::TooSmall => writeln!(output, "Too small!")?,
+            Outcome::TooBig => writeln!(output, "Too big!")?,
+            Outcome::Correct { attempts } => {
+                writeln!(output, "You win in {attempts} attempts!")?;
+                if let Some(rank) = scores.record(player, attempts, min..=max) {
+                    writeln!(output, "New high score at number {rank}!")?;
+                }
+                return Ok(GameResult::Won { attempts });
+            }
+            Outcome::OutOfAttempts { secret } => {
+                writeln!(output, "Out of attempts, the number was {secret}.")?;
+                return Ok(GameResult::Lost { secret });
+            }
+        }
+    }
+}
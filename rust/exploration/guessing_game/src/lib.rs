@@ -0,0 +1,7 @@
+// The guessing game from chapter 2 of the rust book, but finished off.
+//
+// The game itself (game.rs) never touches stdin/stdout or the thread rng
+// directly, it is handed a seeded rng, a BufRead to read guesses from and a
+// Write to talk to. So tests can script a whole game.
+pub mod game;
+pub mod scores;
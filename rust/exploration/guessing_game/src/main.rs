@@ -0,0 +1,111 @@
+// Run with `cargo run -p guessing_game` and optionally
+//   --min 1 --max 100 --attempts 7 (0 for unlimited) --seed 42 --scores high_scores.txt
+use guessing_game::game::{play, Config};
+use guessing_game::scores::HighScores;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "usage: guessing_game [--min N] [--max N] [--attempts N] [--seed N] [--scores PATH]";
+
+struct Options {
+    config: Config,
+    seed: Option<u64>,
+    scores_path: PathBuf,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        config: Config::default(),
+        seed: None,
+        scores_path: PathBuf::from("high_scores.txt"),
+    };
+    let config = &mut options.config;
+    for pair in args.chunks(2) {
+        let [flag, value] = pair else {
+            return Err(format!("{} is missing a value", pair[0]));
+        };
+        let number = || {
+            value
+                .parse::<u32>()
+                .map_err(|_| format!("{flag} expected a number, not {value}"))
+        };
+        match flag.as_str() {
+            "--min" => config.range = number()?..=*config.range.end(),
+            "--max" => config.range = *config.range.start()..=number()?,
+            "--attempts" => config.max_attempts = Some(number()?).filter(|n| *n > 0),
+            "--seed" => {
+                let seed = value.parse();
+                options.seed =
+                    Some(seed.map_err(|_| format!("--seed expected a number, not {value}"))?)
+            }
+            "--scores" => options.scores_path = PathBuf::from(value),
+            _ => return Err(format!("unknown flag {flag}")),
+        }
+    }
+    if config.range.is_empty() {
+        return Err("--min must be at most --max".to_string());
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: Options) -> io::Result<()> {
+    let Options {
+        config,
+        seed,
+        scores_path,
+    } = options;
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut scores = HighScores::load(&scores_path)?;
+
+    println!("What is your name?");
+    let stdin = io::stdin();
+    let mut name = String::new();
+    stdin.lock().read_line(&mut name)?;
+    let name = match name.trim() {
+        "" => "anonymous",
+        name => name,
+    };
+
+    let range = config.range.clone();
+    play(
+        config,
+        &mut rng,
+        name,
+        stdin.lock(),
+        io::stdout(),
+        &mut scores,
+    )?;
+    scores.save(&scores_path)?;
+
+    println!("High scores for {}-{}:", range.start(), range.end());
+    for (i, score) in scores.table(&range).iter().enumerate() {
+        println!("{:>2}. {} in {}", i + 1, score.name, score.attempts);
+    }
+    Ok(())
+}
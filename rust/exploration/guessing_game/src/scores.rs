@@ -0,0 +1,127 @@
+// A high score table kept in a plain text file, one "attempts min max name" per line.
+// Each range is ranked on its own, 3 guesses for 1 to 10 isn't better than 4
+// for 1 to a million. The scores are kept sorted by range then attempts.
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+pub const TABLE_SIZE: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub attempts: u32,
+    pub range: RangeInclusive<u32>,
+    pub name: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct HighScores {
+    pub scores: Vec<Score>,
+}
+
+impl HighScores {
+    // A missing file is just an empty table, lines we can't read are skipped.
+    pub fn load(path: &Path) -> io::Result<HighScores> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(HighScores::default()),
+            Err(error) => return Err(error),
+        };
+        Ok(HighScores::parse(&text))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn parse(text: &str) -> HighScores {
+        let scores = text
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(4, ' ');
+                let attempts = parts.next()?.parse().ok()?;
+                let min = parts.next()?.parse().ok()?;
+                let max = parts.next()?.parse().ok()?;
+                let name = parts.next()?.to_string();
+                Some(Score {
+                    attempts,
+                    range: min..=max,
+                    name,
+                })
+            })
+            .collect();
+        let mut scores = HighScores { scores };
+        scores.scores.sort_by_key(sort_key);
+        scores
+    }
+
+    // The table for one range, best first
+    pub fn table(&self, range: &RangeInclusive<u32>) -> Vec<&Score> {
+        self.scores
+            .iter()
+            .filter(|score| score.range == *range)
+            .collect()
+    }
+
+    pub fn to_text(&self) -> String {
+        self.scores
+            .iter()
+            .map(|score| {
+                format!(
+                    "{} {} {} {}\n",
+                    score.attempts,
+                    score.range.start(),
+                    score.range.end(),
+                    score.name
+                )
+            })
+            .collect()
+    }
+
+    // Fewest attempts first, a tie goes to whoever got there first.
+    // Returns the 1-based position in the range's table if the score made it.
+    pub fn record(
+        &mut self,
+        name: &str,
+        attempts: u32,
+        range: RangeInclusive<u32>,
+    ) -> Option<usize> {
+        let rank = self
+            .table(&range)
+            .iter()
+            .filter(|score| score.attempts <= attempts)
+            .count();
+        if rank >= TABLE_SIZE {
+            return None;
+        }
+        let score = Score {
+            attempts,
+            range,
+            name: name.to_string(),
+        };
+        let position = self
+            .scores
+            .iter()
+            .position(|other| sort_key(other) > sort_key(&score))
+            .unwrap_or(self.scores.len());
+        let range = score.range.clone();
+        self.scores.insert(position, score);
+        // the score pushed off the bottom of the table
+        if let Some(dropped) = self
+            .scores
+            .iter()
+            .enumerate()
+            .filter(|(_, score)| score.range == range)
+            .nth(TABLE_SIZE)
+            .map(|(index, _)| index)
+        {
+            self.scores.remove(dropped);
+        }
+        Some(rank + 1)
+    }
+}
+
+fn sort_key(score: &Score) -> (u32, u32, u32) {
+    (*score.range.start(), *score.range.end(), score.attempts)
+}
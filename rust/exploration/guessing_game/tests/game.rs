@@ -0,0 +1,200 @@
+use guessing_game::game::{parse_guess, play, Config, Game, GameResult, GuessError, Outcome};
+use guessing_game::scores::{HighScores, TABLE_SIZE};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// The secret a game with this seed and config will pick, found by guessing
+// every number in a game that can't run out of attempts.
+fn secret(config: &Config, seed: u64) -> u32 {
+    let unlimited = Config {
+        max_attempts: None,
+        ..config.clone()
+    };
+    let mut game = Game::new(unlimited, &mut StdRng::seed_from_u64(seed));
+    (*config.range.start()..=*config.range.end())
+        .find(|guess| matches!(game.guess(*guess), Outcome::Correct { .. }))
+        .unwrap()
+}
+
+fn run(config: Config, seed: u64, input: &str, scores: &mut HighScores) -> (GameResult, String) {
+    let mut output = Vec::new();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let result = play(
+        config,
+        &mut rng,
+        "tester",
+        input.as_bytes(),
+        &mut output,
+        scores,
+    )
+    .unwrap();
+    (result, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn same_seed_same_secret() {
+    let config = Config::default();
+    let secret = secret(&config, 31);
+    for _ in 0..3 {
+        let mut game = Game::new(config.clone(), &mut StdRng::seed_from_u64(31));
+        assert_eq!(game.guess(secret), Outcome::Correct { attempts: 1 });
+    }
+}
+
+#[test]
+fn parse_guess_validates() {
+    let range = 1..=100;
+    assert_eq!(parse_guess(" 42\n", &range), Ok(42));
+    assert_eq!(
+        parse_guess("forty", &range),
+        Err(GuessError::NotANumber("forty".to_string()))
+    );
+    assert_eq!(
+        parse_guess("-3", &range),
+        Err(GuessError::NotANumber("-3".to_string()))
+    );
+    assert_eq!(parse_guess("101", &range), Err(GuessError::OutOfRange(101)));
+}
+
+#[test]
+fn hints_point_at_the_secret() {
+    let config = Config {
+        range: 1..=10,
+        max_attempts: None,
+    };
+    let secret = secret(&config, 7);
+    let mut game = Game::new(config, &mut StdRng::seed_from_u64(7));
+    for guess in 1..secret {
+        assert_eq!(game.guess(guess), Outcome::TooSmall);
+    }
+    if secret < 10 {
+        assert_eq!(game.guess(10), Outcome::TooBig);
+    }
+    assert!(matches!(game.guess(secret), Outcome::Correct { .. }));
+}
+
+#[test]
+fn scripted_win_with_retries() {
+    let config = Config {
+        range: 1..=10,
+        max_attempts: Some(3),
+    };
+    let secret = secret(&config, 1);
+    let wrong = if secret == 1 { 2 } else { 1 };
+    let input = format!("banana\n0\n{wrong}\n{secret}\n");
+    let mut scores = HighScores::default();
+    let (result, output) = run(config, 1, &input, &mut scores);
+
+    // the two bad lines don't count as attempts
+    assert_eq!(result, GameResult::Won { attempts: 2 });
+    assert!(output.contains("\"banana\" is not a number, try a number between 1 and 10."));
+    assert!(output.contains("0 is out of range"));
+    assert!(output.contains("You win in 2 attempts!"));
+    assert!(output.contains("New high score at number 1!"));
+    assert_eq!(scores.scores[0].name, "tester");
+    assert_eq!(scores.scores[0].attempts, 2);
+}
+
+#[test]
+fn scripted_loss_reveals_secret() {
+    let config = Config {
+        range: 1..=100,
+        max_attempts: Some(2),
+    };
+    let secret = secret(&config, 9);
+    let wrong = if secret == 1 { 2 } else { 1 };
+    let input = format!("{wrong}\n{wrong}\n{secret}\n");
+    let mut scores = HighScores::default();
+    let (result, output) = run(config, 9, &input, &mut scores);
+    assert_eq!(result, GameResult::Lost { secret });
+    assert!(output.contains(&format!("Out of attempts, the number was {secret}.")));
+    assert!(scores.scores.is_empty());
+}
+
+#[test]
+fn running_out_of_input_quits() {
+    let mut scores = HighScores::default();
+    let (result, output) = run(Config::default(), 3, "", &mut scores);
+    assert_eq!(result, GameResult::Quit);
+    assert!(output.ends_with("Bye!\n"));
+}
+
+#[test]
+fn high_scores_keep_the_best() {
+    let mut scores = HighScores::default();
+    for attempts in (1..=TABLE_SIZE as u32 + 2).rev() {
+        scores.record("someone", attempts, 1..=100);
+    }
+    assert_eq!(scores.scores.len(), TABLE_SIZE);
+    assert_eq!(scores.scores[0].attempts, 1);
+    assert_eq!(scores.record("late", 50, 1..=100), None);
+    // ties go after the existing score
+    assert_eq!(scores.record("tie", 1, 1..=100), Some(2));
+}
+
+#[test]
+fn high_scores_round_trip_through_a_file() {
+    let path =
+        std::env::temp_dir().join(format!("guessing_game_scores_{}.txt", std::process::id()));
+    let mut scores = HighScores::load(&path).unwrap();
+    assert!(scores.scores.is_empty());
+    scores.record("Simon Darcy-Jones", 4, 1..=100);
+    scores.record("Issie", 3, 1..=50);
+    scores.save(&path).unwrap();
+    let loaded = HighScores::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, scores);
+    assert_eq!(loaded.scores[1].name, "Simon Darcy-Jones");
+}
+
+#[test]
+fn a_lost_game_stays_lost() {
+    let config = Config {
+        range: 1..=10,
+        max_attempts: Some(2),
+    };
+    let secret = secret(&config, 5);
+    let wrong = if secret == 1 { 2 } else { 1 };
+    let mut game = Game::new(config, &mut StdRng::seed_from_u64(5));
+    game.guess(wrong);
+    assert_eq!(game.guess(wrong), Outcome::OutOfAttempts { secret });
+    assert!(game.is_finished());
+    assert_eq!(game.guess(secret), Outcome::OutOfAttempts { secret });
+    assert_eq!(game.attempts(), 2);
+    assert_eq!(game.attempts_left(), Some(0));
+}
+
+#[test]
+fn a_won_game_stops_counting() {
+    let config = Config {
+        range: 1..=10,
+        max_attempts: None,
+    };
+    let secret = secret(&config, 6);
+    let mut game = Game::new(config, &mut StdRng::seed_from_u64(6));
+    assert_eq!(game.guess(secret), Outcome::Correct { attempts: 1 });
+    assert_eq!(game.guess(secret), Outcome::Correct { attempts: 1 });
+    assert_eq!(game.attempts(), 1);
+}
+
+#[test]
+fn each_range_has_its_own_table() {
+    let mut scores = HighScores::default();
+    assert_eq!(scores.record("big", 4, 1..=1_000_000), Some(1));
+    // fewer guesses on an easier range doesn't push the big one down
+    assert_eq!(scores.record("small", 3, 1..=10), Some(1));
+    assert_eq!(scores.record("big again", 20, 1..=1_000_000), Some(2));
+    let big: Vec<&str> = scores
+        .table(&(1..=1_000_000))
+        .iter()
+        .map(|score| score.name.as_str())
+        .collect();
+    assert_eq!(big, ["big", "big again"]);
+    for attempts in 1..=TABLE_SIZE as u32 {
+        scores.record("filler", attempts, 1..=10);
+    }
+    // the small table filled up without touching the big one
+    assert_eq!(scores.table(&(1..=10)).len(), TABLE_SIZE);
+    assert_eq!(scores.table(&(1..=1_000_000)).len(), 2);
+    assert_eq!(scores.record("slow", 50, 1..=10), None);
+}
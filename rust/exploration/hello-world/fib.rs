@@ -0,0 +1,294 @@
+// Fibonacci numbers a few different ways, all using fib(0) = 0, fib(1) = 1.
+//
+// The naive recursion recomputes the same values over and over (exponential),
+// here we have:
+// - iterative: keep the last two values, O(n)
+// - memoised: the recursion but caching answers, O(n)
+// - fast_doubling: O(log n) using the matrix identities
+//   fib(2k)   = fib(k) * (2 * fib(k + 1) - fib(k))
+//   fib(2k+1) = fib(k)^2 + fib(k + 1)^2
+// - checked_*: return None rather than overflowing for each integer width
+// - big / Fibonacci: arbitrary precision (our own tiny BigUint) so never overflow
+//
+// iterative, Memo::get and fast_doubling return a u128 so they panic for n
+// past MAX_U128_N, rather than overflowing (a panic in debug builds but a
+// wrong answer in release ones). Use checked_u128 or big for those.
+use std::fmt;
+
+// fib(186) is the largest that fits in a u128.
+pub const MAX_U128_N: u32 = 186;
+
+fn check_fits(n: u32) {
+    assert!(
+        n <= MAX_U128_N,
+        "fib({n}) doesn't fit in a u128, use checked_u128 or big"
+    );
+}
+
+pub fn iterative(n: u32) -> u128 {
+    check_fits(n);
+    if n == 0 {
+        return 0;
+    }
+    // Only go as far as fib(n) (not fib(n + 1)) so fib(186) doesn't overflow.
+    let (mut previous, mut current): (u128, u128) = (0, 1);
+    for _ in 1..n {
+        (previous, current) = (current, previous + current);
+    }
+    current
+}
+
+pub struct Memo {
+    cache: Vec<u128>,
+}
+
+impl Memo {
+    pub fn new() -> Memo {
+        Memo { cache: vec![0, 1] }
+    }
+
+    // The recursion is n deep, another reason to stop at MAX_U128_N.
+    pub fn get(&mut self, n: u32) -> u128 {
+        check_fits(n);
+        let index = n as usize;
+        if index < self.cache.len() {
+            return self.cache[index];
+        }
+        let value = self.get(n - 1) + self.get(n - 2);
+        self.cache.push(value); // the recursion filled everything below n
+        value
+    }
+}
+
+pub fn fast_doubling(n: u32) -> u128 {
+    // (fib(k), fib(k + 1))
+    fn pair(k: u32) -> (u128, u128) {
+        if k == 0 {
+            return (0, 1);
+        }
+        let (a, b) = pair(k / 2);
+        let even = a * (2 * b - a);
+        let odd = a * a + b * b;
+        if k.is_multiple_of(2) {
+            (even, odd)
+        } else {
+            (odd, even + odd)
+        }
+    }
+    check_fits(n);
+    // Same as pair(n).0 but without working out fib(n + 1) which may overflow.
+    let (a, b) = pair(n / 2);
+    if n.is_multiple_of(2) {
+        a * (2 * b - a)
+    } else {
+        a * a + b * b
+    }
+}
+
+// checked_u8, checked_i8, ... the iterative version with checked_add.
+macro_rules! checked_fib {
+    ($($name:ident => $t:ty),* $(,)?) => {
+        $(
+            #[allow(dead_code)] // main only shows a couple of them
+            pub fn $name(n: u32) -> Option<$t> {
+                if n == 0 {
+                    return Some(0);
+                }
+                let (mut previous, mut current): ($t, $t) = (0, 1);
+                for _ in 1..n {
+                    (previous, current) = (current, previous.checked_add(current)?);
+                }
+                Some(current)
+            }
+        )*
+    };
+}
+
+checked_fib!(
+    checked_u8 => u8,
+    checked_u16 => u16,
+    checked_u32 => u32,
+    checked_u64 => u64,
+    checked_u128 => u128,
+    checked_i8 => i8,
+    checked_i16 => i16,
+    checked_i32 => i32,
+    checked_i64 => i64,
+    checked_i128 => i128,
+);
+
+// Just enough of a big unsigned integer for Fibonacci: adding and printing.
+// Stored as base 10^9 "digits", least significant first, so printing is easy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+const BASE: u32 = 1_000_000_000;
+
+impl BigUint {
+    pub fn from_u128(mut value: u128) -> BigUint {
+        let mut limbs = vec![];
+        while value > 0 {
+            limbs.push((value % BASE as u128) as u32);
+            value /= BASE as u128;
+        }
+        BigUint { limbs }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(i).unwrap_or(&0) + other.limbs.get(i).unwrap_or(&0) + carry;
+            limbs.push(sum % BASE);
+            carry = sum / BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.limbs.split_last() {
+            None => write!(f, "0"),
+            Some((last, rest)) => {
+                write!(f, "{last}")?;
+                for limb in rest.iter().rev() {
+                    write!(f, "{limb:09}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn big(n: u32) -> BigUint {
+    Fibonacci::new()
+        .nth(n as usize)
+        .expect("Fibonacci never ends")
+}
+
+// fib(0), fib(1), fib(2), ... forever.
+pub struct Fibonacci {
+    current: BigUint,
+    next: BigUint,
+}
+
+impl Fibonacci {
+    pub fn new() -> Fibonacci {
+        Fibonacci {
+            current: BigUint::from_u128(0),
+            next: BigUint::from_u128(1),
+        }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let after = self.current.add(&self.next);
+        let next = std::mem::replace(&mut self.next, after);
+        Some(std::mem::replace(&mut self.current, next))
+    }
+}
+
+// Build and run these with
+// $ rustc --test main.rs -o fib_tests && ./fib_tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: [u128; 11] = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+
+    #[test]
+    fn first_few() {
+        let mut memo = Memo::new();
+        for (n, expected) in FIRST.iter().enumerate() {
+            let n = n as u32;
+            assert_eq!(iterative(n), *expected);
+            assert_eq!(memo.get(n), *expected);
+            assert_eq!(fast_doubling(n), *expected);
+            assert_eq!(checked_u8(n), Some(*expected as u8));
+            assert_eq!(big(n).to_string(), expected.to_string());
+        }
+    }
+
+    #[test]
+    fn all_agree_up_to_u128_max() {
+        let mut memo = Memo::new();
+        let sequence: Vec<BigUint> = Fibonacci::new().take(MAX_U128_N as usize + 1).collect();
+        for n in 0..=MAX_U128_N {
+            let expected = iterative(n);
+            assert_eq!(memo.get(n), expected, "memo {n}");
+            assert_eq!(fast_doubling(n), expected, "fast_doubling {n}");
+            assert_eq!(checked_u128(n), Some(expected), "checked_u128 {n}");
+            assert_eq!(
+                sequence[n as usize],
+                BigUint::from_u128(expected),
+                "big {n}"
+            );
+        }
+        assert_eq!(checked_u128(MAX_U128_N + 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "fib(187) doesn't fit in a u128")]
+    fn iterative_past_u128() {
+        iterative(MAX_U128_N + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "fib(187) doesn't fit in a u128")]
+    fn memo_past_u128() {
+        Memo::new().get(MAX_U128_N + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "fib(187) doesn't fit in a u128")]
+    fn fast_doubling_past_u128() {
+        fast_doubling(MAX_U128_N + 1);
+    }
+
+    #[test]
+    fn checked_widths_stop_at_overflow() {
+        // (last n that fits, function) for each width
+        fn check<T: PartialEq + std::fmt::Debug + TryFrom<u128>>(
+            last: u32,
+            f: fn(u32) -> Option<T>,
+        ) {
+            for n in 0..=last {
+                assert_eq!(f(n), T::try_from(iterative(n)).ok(), "n = {n}");
+            }
+            assert_eq!(f(last + 1), None, "n = {}", last + 1);
+            assert_eq!(f(last + 50), None, "n = {}", last + 50);
+        }
+        check(13, checked_u8);
+        check(24, checked_u16);
+        check(47, checked_u32);
+        check(93, checked_u64);
+        check(11, checked_i8);
+        check(23, checked_i16);
+        check(46, checked_i32);
+        check(92, checked_i64);
+        check(184, checked_i128);
+    }
+
+    #[test]
+    fn big_goes_past_u128() {
+        let thousand = big(1000).to_string();
+        assert_eq!(thousand.len(), 209);
+        assert!(
+            thousand.starts_with("434665576869374564356885276750406258025646605173717804024817")
+        );
+        assert!(thousand.ends_with("849228875"));
+        assert_eq!(
+            big(187),
+            BigUint::from_u128(iterative(185)).add(&BigUint::from_u128(iterative(186)))
+        );
+    }
+}
@@ -3,17 +3,26 @@
 
 // Then we run it
 // $ ./main
+mod fib;
 
 fn main() {
     let example_string: &str = "Issie";
-    let fib_value: i32 = fib(10);
+    let fib_value: u128 = fib::iterative(10);
     println!("Hello, {example_string} {fib_value}");
-}
 
-fn fib(n: i32) -> i32 {
-    if n == 0 || n == 1 {
-        1
-    } else {
-        fib(n - 1) + fib(n - 2)
-    }
+    // The other ways of working out fib (see fib.rs)
+    let mut memo = fib::Memo::new();
+    println!("memoised fib(50) = {}", memo.get(50));
+    println!(
+        "fast doubling fib(186) = {}",
+        fib::fast_doubling(fib::MAX_U128_N)
+    );
+    println!("checked_i32 fib(46) = {:?}", fib::checked_i32(46));
+    println!("checked_i32 fib(47) = {:?}", fib::checked_i32(47));
+    println!("big fib(200) = {}", fib::big(200));
+    let first_ten: Vec<String> = fib::Fibonacci::new()
+        .take(10)
+        .map(|x| x.to_string())
+        .collect();
+    println!("first ten = {}", first_ten.join(", "));
 }
@@ -0,0 +1,4 @@
+// Solutions shared between the leet binary and the tests.
+pub mod problem;
+pub mod problems;
+pub mod two_sum;
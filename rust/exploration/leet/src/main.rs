@@ -3,12 +3,78 @@
 // Explanation: Because nums[0] + nums[1] == 9, we return [0, 1].
 // Input: nums = [3,2,4], target = 6
 // Output: [1,2]
+use leet::problem::{default_cases_dir, HarnessError, ProblemReport, Runnable};
+use leet::{problems, two_sum};
+use std::process::ExitCode;
 
-fn main() {
-    solution_1()
+// Run with `cargo run -p leet -- <command>` where command is one of
+//   list          show every problem
+//   run [NAME]    run the test cases for one problem (or all of them)
+//   demo          the original two sum playground below, also what runs with
+//                 no command
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+    let result = match args.as_slice() {
+        ["list"] => {
+            for problem in problems::all() {
+                println!("{:<12} {:?}", problem.name(), problem.difficulty());
+            }
+            Ok(true)
+        }
+        ["run"] => run(problems::all()),
+        ["run", name] => problems::find(name).and_then(|problem| run(vec![problem])),
+        [] | ["demo"] => {
+            solution_1();
+            Ok(true)
+        }
+        _ => {
+            eprintln!("usage: leet [list | run [NAME] | demo]");
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Returns whether every case passed.
+fn run(problems: Vec<Box<dyn Runnable>>) -> Result<bool, HarnessError> {
+    let cases_dir = default_cases_dir();
+    let mut all_passed = true;
+    for problem in problems {
+        let report = problem.run(&cases_dir)?;
+        print_report(&report);
+        all_passed &= report.passed();
+    }
+    Ok(all_passed)
+}
+
+fn print_report(report: &ProblemReport) {
+    let passed = report.cases.iter().filter(|case| case.passed).count();
+    println!(
+        "{} ({:?}) {passed}/{} passed in {:?}",
+        report.name,
+        report.difficulty,
+        report.cases.len(),
+        report.elapsed()
+    );
+    for case in &report.cases {
+        let status = if case.passed { "PASS" } else { "FAIL" };
+        println!("  {status} {} ({:?})", case.name, case.elapsed);
+        if !case.passed {
+            println!("    input:    {}", case.input);
+            println!("    expected: {}", case.expected);
+            println!("    actual:   {}", case.actual);
+        }
+    }
 }
 
-#[allow(dead_code)]
 fn solution_1() {
     println!("Hello, world!");
     let example_1: Vec<i32> = vec![2, 7, 11, 15];
@@ -19,6 +85,12 @@ fn solution_1() {
     let result_2 = Solution1::two_sum(example_2, target_2);
     println!("result_1 = {:?}", result_1);
     println!("result_2 = {:?}", result_2);
+    // The O(n) version from the two_sum module borrows instead of taking the Vec.
+    println!(
+        "hash_map result_1 = {:?}",
+        two_sum::hash_map(&[2, 7, 11, 15], 9)
+    );
+    println!("hash_map result_2 = {:?}", two_sum::hash_map(&[3, 2, 4], 6));
 
     // Just playing with iter iter_mut and into_iter
     let mut simon: Vec<String> = vec![1.to_string(), 2.to_string(), 3.to_string()];
@@ -0,0 +1,176 @@
+// A small local harness so every problem is written the same way.
+//
+// Each problem implements `Problem` with typed input/output. Test cases come
+// from the problem itself (`test_cases`) and from `cases/<name>.toml` or
+// `cases/<name>.json` in this crate, so new cases can be added without code.
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fmt::{self, Debug, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestCase<I, O> {
+    pub name: String,
+    pub input: I,
+    pub expected: O,
+}
+
+pub trait Problem {
+    type Input: DeserializeOwned + Clone + Debug;
+    type Output: DeserializeOwned + PartialEq + Clone + Debug;
+
+    fn name(&self) -> &'static str;
+    fn difficulty(&self) -> Difficulty;
+    fn solve(&self, input: Self::Input) -> Self::Output;
+
+    // Cases written in code, on top of any in the cases folder.
+    fn test_cases(&self) -> Vec<TestCase<Self::Input, Self::Output>> {
+        vec![]
+    }
+
+    // Puts an answer in a standard form before comparing, for problems where
+    // LeetCode accepts it in any order.
+    fn normalise(&self, output: Self::Output) -> Self::Output {
+        output
+    }
+}
+
+// The file layout is a list of `[[case]]` tables (or a "case" array in json).
+#[derive(Deserialize)]
+struct CaseFile<I, O> {
+    case: Vec<TestCase<I, O>>,
+}
+
+#[derive(Debug)]
+pub enum HarnessError {
+    Io(PathBuf, std::io::Error),
+    Toml(PathBuf, toml::de::Error),
+    Json(PathBuf, serde_json::Error),
+    UnknownProblem(String),
+}
+
+impl Display for HarnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HarnessError::Io(path, error) => write!(f, "reading {}: {error}", path.display()),
+            HarnessError::Toml(path, error) => write!(f, "parsing {}: {error}", path.display()),
+            HarnessError::Json(path, error) => write!(f, "parsing {}: {error}", path.display()),
+            HarnessError::UnknownProblem(name) => write!(f, "no problem called {name}"),
+        }
+    }
+}
+
+impl std::error::Error for HarnessError {}
+
+// Where the case files live when running from this crate.
+pub fn default_cases_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("cases")
+}
+
+pub fn load_cases<I, O>(cases_dir: &Path, name: &str) -> Result<Vec<TestCase<I, O>>, HarnessError>
+where
+    I: DeserializeOwned,
+    O: DeserializeOwned,
+{
+    let mut cases = Vec::new();
+    let toml_path = cases_dir.join(format!("{name}.toml"));
+    if toml_path.exists() {
+        let text =
+            fs::read_to_string(&toml_path).map_err(|e| HarnessError::Io(toml_path.clone(), e))?;
+        let file: CaseFile<I, O> =
+            toml::from_str(&text).map_err(|e| HarnessError::Toml(toml_path, e))?;
+        cases.extend(file.case);
+    }
+    let json_path = cases_dir.join(format!("{name}.json"));
+    if json_path.exists() {
+        let text =
+            fs::read_to_string(&json_path).map_err(|e| HarnessError::Io(json_path.clone(), e))?;
+        let file: CaseFile<I, O> =
+            serde_json::from_str(&text).map_err(|e| HarnessError::Json(json_path, e))?;
+        cases.extend(file.case);
+    }
+    Ok(cases)
+}
+
+#[derive(Debug)]
+pub struct CaseReport {
+    pub name: String,
+    pub passed: bool,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct ProblemReport {
+    pub name: &'static str,
+    pub difficulty: Difficulty,
+    pub cases: Vec<CaseReport>,
+}
+
+impl ProblemReport {
+    pub fn passed(&self) -> bool {
+        self.cases.iter().all(|case| case.passed)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.cases.iter().map(|case| case.elapsed).sum()
+    }
+}
+
+// `Problem` has associated types so can't be a trait object itself,
+// this wrapper trait lets us keep all the problems in one Vec.
+pub trait Runnable {
+    fn name(&self) -> &'static str;
+    fn difficulty(&self) -> Difficulty;
+    fn run(&self, cases_dir: &Path) -> Result<ProblemReport, HarnessError>;
+}
+
+impl<P: Problem> Runnable for P {
+    fn name(&self) -> &'static str {
+        Problem::name(self)
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Problem::difficulty(self)
+    }
+
+    fn run(&self, cases_dir: &Path) -> Result<ProblemReport, HarnessError> {
+        let mut cases = self.test_cases();
+        cases.extend(load_cases(cases_dir, Problem::name(self))?);
+        let cases = cases
+            .into_iter()
+            .map(|case| {
+                let input = format!("{:?}", case.input);
+                let start = Instant::now();
+                let actual = self.solve(case.input);
+                let elapsed = start.elapsed();
+                let passed =
+                    self.normalise(actual.clone()) == self.normalise(case.expected.clone());
+                CaseReport {
+                    name: case.name,
+                    passed,
+                    input,
+                    expected: format!("{:?}", case.expected),
+                    actual: format!("{actual:?}"),
+                    elapsed,
+                }
+            })
+            .collect();
+        Ok(ProblemReport {
+            name: Problem::name(self),
+            difficulty: Problem::difficulty(self),
+            cases,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+// Every problem the harness knows about. Add a module here and to `all`.
+use crate::problem::{HarnessError, Runnable};
+
+pub mod three_sum;
+pub mod two_sum;
+
+pub fn all() -> Vec<Box<dyn Runnable>> {
+    vec![Box::new(two_sum::TwoSum), Box::new(three_sum::ThreeSum)]
+}
+
+pub fn find(name: &str) -> Result<Box<dyn Runnable>, HarnessError> {
+    all()
+        .into_iter()
+        .find(|problem| problem.name() == name)
+        .ok_or_else(|| HarnessError::UnknownProblem(name.to_string()))
+}
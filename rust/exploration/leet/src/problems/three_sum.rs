@@ -0,0 +1,37 @@
+// https://leetcode.com/problems/3sum/
+use crate::problem::{Difficulty, Problem};
+use crate::two_sum;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Input {
+    pub nums: Vec<i32>,
+}
+
+pub struct ThreeSum;
+
+impl Problem for ThreeSum {
+    type Input = Input;
+    type Output = Vec<Vec<i32>>;
+
+    fn name(&self) -> &'static str {
+        "three_sum"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn solve(&self, input: Input) -> Vec<Vec<i32>> {
+        two_sum::three_sum(&input.nums, 0)
+    }
+
+    // The triplets, and the numbers in each, can come in any order
+    fn normalise(&self, mut output: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+        for triplet in &mut output {
+            triplet.sort();
+        }
+        output.sort();
+        output
+    }
+}
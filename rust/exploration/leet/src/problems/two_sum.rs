@@ -0,0 +1,44 @@
+// https://leetcode.com/problems/two-sum/
+use crate::problem::{Difficulty, Problem, TestCase};
+use crate::two_sum;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Input {
+    pub nums: Vec<i32>,
+    pub target: i32,
+}
+
+pub struct TwoSum;
+
+impl Problem for TwoSum {
+    type Input = Input;
+    // LeetCode style, the two indices or an empty list.
+    type Output = Vec<usize>;
+
+    fn name(&self) -> &'static str {
+        "two_sum"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn solve(&self, input: Input) -> Vec<usize> {
+        match two_sum::hash_map(&input.nums, input.target) {
+            Some((i, j)) => vec![i, j],
+            None => vec![],
+        }
+    }
+
+    fn test_cases(&self) -> Vec<TestCase<Input, Vec<usize>>> {
+        vec![TestCase {
+            name: "no answer".to_string(),
+            input: Input {
+                nums: vec![1, 2, 3],
+                target: 100,
+            },
+            expected: vec![],
+        }]
+    }
+}
@@ -0,0 +1,132 @@
+// The two sum family of problems.
+//
+// Given a list of numbers and a target, find the indices of two numbers
+// adding up to the target. The brute force version checks every pair which
+// is O(n²), here we also have
+// - a hash map version which is O(n) (remember what we have seen so far)
+// - a sorted two pointer version which is O(n log n) (walk in from both ends)
+// and the k sum generalisation (e.g. three sum) built on the two pointers.
+//
+// All the sums are done in i128 so adding two large i64s can't overflow.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Any primitive integer that fits inside an i128 (so not i128/u128 themselves).
+pub trait Integer: Copy + Ord + Hash + Into<i128> + TryFrom<i128> {}
+
+impl<T> Integer for T where T: Copy + Ord + Hash + Into<i128> + TryFrom<i128> {}
+
+fn sum<T: Integer>(x: T, y: T) -> i128 {
+    x.into() + y.into()
+}
+
+pub fn brute_force<T: Integer>(numbers: &[T], target: T) -> Option<(usize, usize)> {
+    for (i, x) in numbers.iter().enumerate() {
+        for (j, y) in numbers.iter().enumerate().skip(i + 1) {
+            if sum(*x, *y) == target.into() {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+pub fn hash_map<T: Integer>(numbers: &[T], target: T) -> Option<(usize, usize)> {
+    // value -> index of the first time we saw it
+    let mut seen: HashMap<T, usize> = HashMap::with_capacity(numbers.len());
+    for (j, y) in numbers.iter().enumerate() {
+        // If the complement doesn't fit in T it can't be in the list.
+        if let Ok(complement) = T::try_from(target.into() - (*y).into()) {
+            if let Some(i) = seen.get(&complement) {
+                return Some((*i, j));
+            }
+        }
+        seen.entry(*y).or_insert(j);
+    }
+    None
+}
+
+pub fn two_pointer<T: Integer>(numbers: &[T], target: T) -> Option<(usize, usize)> {
+    // Sort the indices rather than the numbers so we can report the original positions.
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    order.sort_by_key(|i| numbers[*i]);
+    let (mut left, mut right) = (0, order.len().checked_sub(1)?);
+    while left < right {
+        let (i, j) = (order[left], order[right]);
+        match sum(numbers[i], numbers[j]).cmp(&target.into()) {
+            Ordering::Less => left += 1,
+            Ordering::Greater => right -= 1,
+            Ordering::Equal => return Some((i.min(j), i.max(j))),
+        }
+    }
+    None
+}
+
+pub fn three_sum<T: Integer>(numbers: &[T], target: T) -> Vec<Vec<T>> {
+    k_sum(numbers, 3, target)
+}
+
+// Every distinct (sorted) combination of k values adding up to the target.
+pub fn k_sum<T: Integer>(numbers: &[T], k: usize, target: T) -> Vec<Vec<T>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort();
+    let mut results = Vec::new();
+    if k > 0 {
+        k_sum_sorted(&sorted, k, target.into(), &mut Vec::new(), &mut results);
+    }
+    results
+}
+
+fn k_sum_sorted<T: Integer>(
+    sorted: &[T],
+    k: usize,
+    target: i128,
+    prefix: &mut Vec<T>,
+    results: &mut Vec<Vec<T>>,
+) {
+    if sorted.len() < k {
+        return;
+    }
+    if k == 1 {
+        // Only need to know if the target is there (binary search as we are sorted).
+        if let Ok(value) = T::try_from(target) {
+            if sorted.binary_search(&value).is_ok() {
+                results.push([prefix.as_slice(), &[value]].concat());
+            }
+        }
+        return;
+    }
+    if k == 2 {
+        let (mut left, mut right) = (0, sorted.len() - 1);
+        while left < right {
+            match sum(sorted[left], sorted[right]).cmp(&target) {
+                Ordering::Less => left += 1,
+                Ordering::Greater => right -= 1,
+                Ordering::Equal => {
+                    results.push([prefix.as_slice(), &[sorted[left], sorted[right]]].concat());
+                    // skip duplicates so each combination is only reported once
+                    let value = sorted[left];
+                    while left < right && sorted[left] == value {
+                        left += 1;
+                    }
+                }
+            }
+        }
+        return;
+    }
+    for (i, x) in sorted.iter().enumerate() {
+        if i > 0 && sorted[i - 1] == *x {
+            continue;
+        }
+        prefix.push(*x);
+        k_sum_sorted(
+            &sorted[i + 1..],
+            k - 1,
+            target - (*x).into(),
+            prefix,
+            results,
+        );
+        prefix.pop();
+    }
+}
@@ -0,0 +1,51 @@
+use leet::problem::Problem;
+use leet::problem::{default_cases_dir, load_cases, HarnessError};
+use leet::problems;
+use leet::problems::three_sum::ThreeSum;
+use leet::problems::two_sum::Input;
+
+#[test]
+fn every_problem_passes_its_cases() {
+    for problem in problems::all() {
+        let report = problem
+            .run(&default_cases_dir())
+            .expect("cases should load");
+        assert!(!report.cases.is_empty(), "{} has no cases", report.name);
+        for case in &report.cases {
+            assert!(
+                case.passed,
+                "{} / {}: expected {} got {}",
+                report.name, case.name, case.expected, case.actual
+            );
+        }
+    }
+}
+
+#[test]
+fn cases_load_from_toml() {
+    let cases = load_cases::<Input, Vec<usize>>(&default_cases_dir(), "two_sum").unwrap();
+    assert_eq!(cases[0].name, "example 1");
+    assert_eq!(cases[0].input.nums, vec![2, 7, 11, 15]);
+    assert_eq!(cases[0].expected, vec![0, 1]);
+}
+
+#[test]
+fn missing_case_files_are_empty() {
+    let cases = load_cases::<Input, Vec<usize>>(&default_cases_dir(), "not_a_problem").unwrap();
+    assert!(cases.is_empty());
+}
+
+#[test]
+fn unknown_problem_is_an_error() {
+    assert!(matches!(
+        problems::find("four_sum"),
+        Err(HarnessError::UnknownProblem(_))
+    ));
+}
+
+#[test]
+fn three_sum_answers_compare_in_any_order() {
+    let shuffled = ThreeSum.normalise(vec![vec![1, 0, -1], vec![2, -1, -1]]);
+    let sorted = ThreeSum.normalise(vec![vec![-1, -1, 2], vec![-1, 0, 1]]);
+    assert_eq!(shuffled, sorted);
+}
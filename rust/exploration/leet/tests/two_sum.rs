@@ -0,0 +1,121 @@
+use leet::two_sum::{brute_force, hash_map, k_sum, three_sum, two_pointer, Integer};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+type TwoSum = fn(&[i32], i32) -> Option<(usize, usize)>;
+// (numbers, target, expected)
+type Case = (Vec<i32>, i32, Option<(usize, usize)>);
+
+const SOLVERS: [(&str, TwoSum); 3] = [
+    ("brute_force", brute_force),
+    ("hash_map", hash_map),
+    ("two_pointer", two_pointer),
+];
+
+fn is_valid<T: Integer>(numbers: &[T], target: T, answer: (usize, usize)) -> bool {
+    let (i, j) = answer;
+    i < j && j < numbers.len() && numbers[i].into() + numbers[j].into() == target.into()
+}
+
+#[test]
+fn two_sum_table() {
+    let table: Vec<Case> = vec![
+        (vec![2, 7, 11, 15], 9, Some((0, 1))),
+        (vec![3, 2, 4], 6, Some((1, 2))),
+        (vec![3, 3], 6, Some((0, 1))),
+        (vec![1, 2, 3], 100, None),
+        (vec![5], 10, None),
+        (vec![], 0, None),
+        (vec![-3, 4, 3, 90], 0, Some((0, 2))),
+    ];
+    for (numbers, target, expected) in &table {
+        for (name, solver) in SOLVERS {
+            assert_eq!(solver(numbers, *target), *expected, "{name} on {numbers:?}");
+        }
+    }
+}
+
+#[test]
+fn two_sum_does_not_overflow() {
+    let numbers = [i64::MAX, 1, i64::MAX - 1, i64::MIN];
+    assert_eq!(brute_force(&numbers, -1), Some((0, 3)));
+    assert_eq!(hash_map(&numbers, -1), Some((0, 3)));
+    assert_eq!(two_pointer(&numbers, -1), Some((0, 3)));
+    assert_eq!(hash_map(&numbers, i64::MAX), Some((1, 2)));
+    assert_eq!(two_pointer(&[u8::MAX, u8::MAX], 254), None);
+}
+
+#[test]
+fn two_sum_random_against_brute_force() {
+    let mut rng = StdRng::seed_from_u64(26);
+    for _ in 0..1_000 {
+        let len = rng.gen_range(0..20);
+        let numbers: Vec<i32> = (0..len).map(|_| rng.gen_range(-20..20)).collect();
+        let target = rng.gen_range(-40..40);
+        let expected = brute_force(&numbers, target);
+        for (name, solver) in SOLVERS {
+            let answer = solver(&numbers, target);
+            assert_eq!(
+                answer.is_some(),
+                expected.is_some(),
+                "{name} on {numbers:?}"
+            );
+            if let Some(answer) = answer {
+                assert!(is_valid(&numbers, target, answer), "{name} on {numbers:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn three_sum_finds_unique_triplets() {
+    assert_eq!(
+        three_sum(&[-1, 0, 1, 2, -1, -4], 0),
+        vec![vec![-1, -1, 2], vec![-1, 0, 1]]
+    );
+    assert_eq!(three_sum(&[0, 0, 0, 0], 0), vec![vec![0, 0, 0]]);
+    assert!(three_sum(&[1, 2], 3).is_empty());
+}
+
+#[test]
+fn k_sum_generalises() {
+    assert_eq!(
+        k_sum(&[1, 0, -1, 0, -2, 2], 4, 0),
+        vec![vec![-2, -1, 1, 2], vec![-2, 0, 0, 2], vec![-1, 0, 0, 1]]
+    );
+    assert_eq!(k_sum(&[4, 1, 4], 1, 4), vec![vec![4]]);
+    assert!(k_sum(&[1, 2, 3], 0, 0).is_empty());
+    assert_eq!(
+        k_sum(&[i8::MAX, i8::MAX, i8::MIN], 3, 126),
+        vec![vec![i8::MIN, i8::MAX, i8::MAX]]
+    );
+}
+
+#[test]
+fn k_sum_random_against_combinations() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..200 {
+        let len = rng.gen_range(0..9);
+        let numbers: Vec<i32> = (0..len).map(|_| rng.gen_range(-5..5)).collect();
+        let target = rng.gen_range(-6..6);
+        // brute force every triple of positions
+        let mut expected: Vec<Vec<i32>> = Vec::new();
+        for i in 0..len {
+            for j in (i + 1)..len {
+                for k in (j + 1)..len {
+                    let mut triple = vec![numbers[i], numbers[j], numbers[k]];
+                    triple.sort();
+                    if triple.iter().sum::<i32>() == target && !expected.contains(&triple) {
+                        expected.push(triple);
+                    }
+                }
+            }
+        }
+        expected.sort();
+        assert_eq!(
+            three_sum(&numbers, target),
+            expected,
+            "{numbers:?} {target}"
+        );
+    }
+}
@@ -1,24 +1,29 @@
 use std::cmp::max;
 
+use describe::Describe;
 use modules::gym::cardio::swim as also_swim;
 #[allow(unused_imports)]
 use modules::one_rebel::{Shake, ShakeFlavour};
 use modules::{main as library_main, swim};
+use output::{Output, Stdout};
 
 fn main() {
     // Run this with `cargo run --bin use_library`
-    println!("Modules examples");
+    let out: &mut dyn Output = &mut Stdout;
+    writeln!(out, "Modules examples");
 
     // Run the library_main function which aliases from the library crate.
-    library_main();
+    library_main(out);
 
     // here we use a re-exported function swim.
-    swim();
-    also_swim();
+    swim(out);
+    also_swim(out);
 
     let mut my_shake: Shake = Shake::chocolate_shake();
+    // the price is skipped when describing, it's private after all
+    writeln!(out, "Drinking {}", my_shake.describe());
     while my_shake.volume > 0 {
-        println!("glug!");
+        writeln!(out, "glug!");
         my_shake.volume = max(0, my_shake.volume - 100);
     }
     // The following would fail because price is private field on the struct
@@ -27,5 +32,5 @@ fn main() {
     //     volume: 500,
     //     price: 350,
     // };
-    println!();
+    writeln!(out);
 }
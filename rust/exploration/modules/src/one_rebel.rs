@@ -1,14 +1,29 @@
+use describe::Describe;
+
+#[derive(Debug, Clone, Describe)]
 pub enum ShakeFlavour {
     Chocolate,
     Vanilla,
     Strawberry,
 }
+#[derive(Debug, Clone, Describe)]
 pub struct Shake {
+    #[describe(nested)]
     pub flavour: ShakeFlavour,
+    #[describe(format = "{}ml")]
     pub volume: u32,
+    #[describe(skip)]
     price: u32, // this field is private
 }
 
+// The fields for audit::Audited. This is in the module so it can see price,
+// which isn't described but changing it is still worth recording.
+audit::fields!(Shake {
+    flavour,
+    volume: "{}ml",
+    price,
+});
+
 impl Shake {
     fn new(flavour: ShakeFlavour) -> Shake {
         Shake {
@@ -1,3 +1,5 @@
-pub fn sprint() {
-    println!("one_rebel::reshape::sprint() -> Sprinting;");
+use output::Output;
+
+pub fn sprint(out: &mut dyn Output) {
+    writeln!(out, "one_rebel::reshape::sprint() -> Sprinting;");
 }
@@ -1,8 +1,10 @@
-pub fn smash() {
-    _smash();
-    println!("tennis_club::smash() -> Smashing the ball publicly");
+use output::Output;
+
+pub fn smash(out: &mut dyn Output) {
+    _smash(out);
+    writeln!(out, "tennis_club::smash() -> Smashing the ball publicly");
 }
 
-fn _smash() {
-    println!("tennis_club::_smash() -> Smashing the ball privately");
+fn _smash(out: &mut dyn Output) {
+    writeln!(out, "tennis_club::_smash() -> Smashing the ball privately");
 }
@@ -0,0 +1,30 @@
+use audit::Audited;
+use describe::Describe;
+use modules::one_rebel::{Shake, ShakeFlavour};
+
+#[test]
+fn shake_is_described_without_its_price() {
+    let shake = Shake::chocolate_shake();
+    assert_eq!(shake.describe(), "Shake: flavour chocolate, volume 500ml");
+    assert_eq!(shake.price(), 650);
+}
+
+#[test]
+fn shake_changes_are_audited() {
+    let mut shake = Audited::new(Shake::chocolate_shake());
+    shake.edit().volume = 300;
+    shake.edit().flavour = ShakeFlavour::Strawberry;
+    let changes: Vec<String> = shake.changes().map(ToString::to_string).collect();
+    assert_eq!(
+        changes,
+        [
+            "#1 volume: 500ml -> 300ml",
+            "#2 flavour: Chocolate -> Strawberry"
+        ]
+    );
+    shake.undo();
+    assert_eq!(shake.describe(), "Shake: flavour chocolate, volume 300ml");
+    // price can't be changed from here but is still one of the fields
+    let price = audit::diff(shake.original(), &Shake::chocolate_shake());
+    assert!(price.is_empty());
+}
@@ -0,0 +1,27 @@
+// Everything modules::main prints, checked against tests/snapshots.
+// After a deliberate change run `UPDATE_SNAPSHOTS=1 cargo test` to update it.
+use modules::gym::cardio;
+use output::{assert_snapshot, Buffer};
+
+#[test]
+fn full_output() {
+    let mut out = Buffer::new();
+    modules::main(&mut out);
+    assert_snapshot(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/modules.txt"),
+        out.as_str(),
+    );
+}
+
+#[test]
+fn re_exported_swim_is_the_same_function() {
+    let (mut a, mut b) = (Buffer::new(), Buffer::new());
+    modules::swim(&mut a);
+    cardio::swim(&mut b);
+    assert_eq!(a.as_str(), b.as_str());
+    cardio::walk(&mut a);
+    assert_eq!(
+        a.lines()[1],
+        "gym::cardio::walk() -> Walking on the treadmill."
+    );
+}
@@ -0,0 +1,112 @@
+// Somewhere for the examples to write to instead of println!.
+//
+// The binaries pass Stdout so nothing changes when running them, the tests
+// pass a Buffer and can then look at (or snapshot) everything that was written.
+//
+//   fn shape_example(out: &mut dyn Output) {
+//       writeln!(out, "Shape example");
+//   }
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub trait Output {
+    // Named like io::Write::write_fmt so that write! and writeln! work on an
+    // Output, but without a Result to unwrap every time.
+    fn write_fmt(&mut self, args: fmt::Arguments);
+}
+
+// println! for the binaries
+pub struct Stdout;
+
+impl Output for Stdout {
+    fn write_fmt(&mut self, args: fmt::Arguments) {
+        print!("{args}");
+    }
+}
+
+// Keeps everything in memory for the tests.
+#[derive(Debug, Default)]
+pub struct Buffer {
+    text: String,
+}
+
+impl Buffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Vec<&str> {
+        self.text.lines().collect()
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+impl Output for Buffer {
+    fn write_fmt(&mut self, args: fmt::Arguments) {
+        fmt::Write::write_fmt(&mut self.text, args).expect("a String can always be written to");
+    }
+}
+
+// Set this to update the snapshots instead of checking them, e.g.
+//   UPDATE_SNAPSHOTS=1 cargo test
+pub const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+// Checks actual matches the golden file at path, or writes the file instead
+// when UPDATE_SNAPSHOTS is set.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    check_snapshot(path, actual, std::env::var_os(UPDATE_SNAPSHOTS).is_some())
+}
+
+pub fn check_snapshot(path: impl AsRef<Path>, actual: &str, update: bool) {
+    let path = path.as_ref();
+    if update {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("could not create the snapshot folder");
+        }
+        fs::write(path, actual).expect("could not write the snapshot");
+        return;
+    }
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(error) => panic!(
+            "could not read snapshot {} ({error}), run with {UPDATE_SNAPSHOTS}=1 to create it",
+            path.display()
+        ),
+    };
+    if expected != actual {
+        panic!(
+            "snapshot {} doesn't match, run with {UPDATE_SNAPSHOTS}=1 if the change is expected\n{}",
+            path.display(),
+            first_difference(&expected, actual)
+        );
+    }
+}
+
+// Just the first line that differs, a whole diff of two long outputs is
+// hard to read.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(a), Some(b)) if a == b => line += 1,
+            (None, None) => return "the line endings differ".to_string(),
+            (a, b) => {
+                return format!(
+                    "line {line}\nexpected: {}\n  actual: {}",
+                    a.unwrap_or("<end of snapshot>"),
+                    b.unwrap_or("<end of output>")
+                )
+            }
+        }
+    }
+}
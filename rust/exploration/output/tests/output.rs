@@ -0,0 +1,55 @@
+use output::{check_snapshot, Buffer, Output};
+use std::fs;
+use std::path::PathBuf;
+
+fn snapshot(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("output-{}-{name}.txt", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn write_macros_work_on_an_output() {
+    let mut buffer = Buffer::new();
+    let out: &mut dyn Output = &mut buffer;
+    writeln!(out, "one {}", 1);
+    write!(out, "two ");
+    writeln!(out);
+    assert_eq!(buffer.as_str(), "one 1\ntwo \n");
+    assert_eq!(buffer.lines(), ["one 1", "two "]);
+}
+
+#[test]
+fn matching_snapshot_passes() {
+    let path = snapshot("matching", "a\nb\n");
+    check_snapshot(&path, "a\nb\n", false);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "line 2\nexpected: b\n  actual: x")]
+fn mismatch_points_at_the_line() {
+    let path = snapshot("mismatch", "a\nb\nc\n");
+    check_snapshot(&path, "a\nx\nc\n", false);
+}
+
+#[test]
+#[should_panic(expected = "expected: <end of snapshot>\n  actual: b")]
+fn extra_output_is_a_mismatch() {
+    let path = snapshot("extra", "a\n");
+    check_snapshot(&path, "a\nb\n", false);
+}
+
+#[test]
+#[should_panic(expected = "to create it")]
+fn missing_snapshot_says_how_to_create_it() {
+    check_snapshot("/this/snapshot/does/not/exist.txt", "a", false);
+}
+
+#[test]
+fn update_mode_writes_the_snapshot() {
+    let path = snapshot("update", "old\n");
+    check_snapshot(&path, "new\n", true);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,94 @@
+// Various examples from the ownership chapter.
+use crate::words;
+use output::Output;
+use std::io;
+
+// Everything main runs, in order (the REPL needs typing into so isn't here).
+pub fn run_all(out: &mut dyn Output) {
+    ownership_example(out);
+    reference_example(out);
+    reference_scope_example(out);
+    slice_example(out);
+}
+
+// returns the clone, the original was moved
+pub fn ownership_example(out: &mut dyn Output) -> String {
+    writeln!(out, "String example");
+    // we use a String as these are stored on the heap (and a reference to it on the stack)
+    let mut example: String = String::from("Hello");
+    example.push_str(" world!"); // push_str() appends a literal to a String
+    writeln!(out, "example = {example}");
+    let moved_example: String = example; // value moved to moved_example
+                                         // writeln!(out, "example = {example}"); // this would raise a compile error
+    let cloned_example: String = moved_example.clone(); // value cloned to cloned_example
+    writeln!(
+        out,
+        "moved_example = {moved_example} and cloned_example = {cloned_example}"
+    );
+    let x = 1;
+    let y = x;
+    writeln!(
+        out,
+        "x ({x}) and y ({y}) are both valid as stack values are copied."
+    );
+    writeln!(out);
+    cloned_example
+}
+
+pub fn reference_example(out: &mut dyn Output) -> String {
+    // &str rather than &String accepts both, clippy insists
+    fn exclaim(out: &mut dyn Output, to_exclaim: &str) {
+        writeln!(out, "{to_exclaim}!!!!");
+    }
+    fn add_exclaim(to_exclaim: &mut String) -> String {
+        to_exclaim.push_str("!!");
+        to_exclaim.to_string()
+    }
+
+    writeln!(out, "Reference example");
+    let mut issie: String = String::from("Issie");
+    exclaim(out, &issie); // we can create an immutable reference.
+    let exclaimed_issie: String = add_exclaim(&mut issie); // we can create one mutable reference.
+    writeln!(out, "exclaimed_issie = {exclaimed_issie}");
+    writeln!(out);
+    exclaimed_issie
+}
+
+pub fn reference_scope_example(out: &mut dyn Output) -> String {
+    // In this example the order of the last
+    // two lines is important to whether it will compile
+    // This is because a reference exists until it is last used!
+    // and we can only have 1 mutable reference!
+    writeln!(out, "Reference scope example");
+    let mut simon: String = String::from("Simon");
+    let simon_copy: &mut String = &mut simon;
+    writeln!(out, "simon_mut_copy = {simon_copy}");
+    writeln!(out, "simon = { }", simon);
+    writeln!(out);
+    simon
+}
+
+// returns both first names
+pub fn slice_example(out: &mut dyn Output) -> (String, String) {
+    writeln!(out, "Slice example");
+    let simon: String = String::from("Simon Darcy-Jones");
+    let simon_first_name: &str = &simon[0..5];
+    let simon_last_name: &str = &simon[6..];
+    writeln!(out, "simon_first_name = {simon_first_name}");
+    writeln!(out, "simon_last_name = {simon_last_name}");
+    // &zoe[0..3] would panic as the ë is two bytes, so slice by chars instead
+    let zoe: String = String::from("Zoë Darcy-Jones");
+    let zoe_first_name: &str = words::char_slice(&zoe, 0..3).expect("Zoë is 3 chars");
+    writeln!(out, "zoe_first_name = {zoe_first_name}");
+    writeln!(out, "&zoe[0..3] = {:?}", words::byte_slice(&zoe, 0..3));
+    writeln!(out);
+    (simon_first_name.to_string(), zoe_first_name.to_string())
+}
+
+pub fn read_first_word(out: &mut dyn Output) -> io::Result<()> {
+    // Demo from rust book, grown into a REPL in words.rs
+    writeln!(out, "First word example");
+    words::repl(io::stdin().lock(), io::stdout())?;
+    writeln!(out);
+    Ok(())
+}
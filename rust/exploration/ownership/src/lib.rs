@@ -0,0 +1,4 @@
+// The examples from the ownership chapter, main.rs just runs them.
+pub mod examples;
+// The first word demo grown into a little REPL (see words.rs).
+pub mod words;
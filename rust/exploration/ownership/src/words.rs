@@ -0,0 +1,181 @@
+// Picking words and slices out of a line of text.
+//
+// The rust book's first_word only looks for a literal " " so tabs, leading
+// spaces and the "\n" left on by read_line all give the wrong answer. Here we
+// split on any whitespace instead.
+//
+// Slicing a String with &s[0..5] works in *bytes* and panics if that lands in
+// the middle of a multibyte character (e.g. "Zoë"), so byte_slice returns an
+// error instead and char_slice counts in characters.
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+
+pub fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap_or("")
+}
+
+pub fn last_word(text: &str) -> &str {
+    text.split_whitespace().last().unwrap_or("")
+}
+
+// Counting from 0 like everything else.
+pub fn nth_word(text: &str, n: usize) -> Option<&str> {
+    text.split_whitespace().nth(n)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SliceError {
+    OutOfBounds { end: usize, len: usize },
+    NotCharBoundary { index: usize },
+    Backwards { start: usize, end: usize },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SliceError::OutOfBounds { end, len } => {
+                write!(f, "end {end} is past the end (length {len})")
+            }
+            SliceError::NotCharBoundary { index } => {
+                write!(f, "byte {index} is in the middle of a character")
+            }
+            SliceError::Backwards { start, end } => write!(f, "start {start} is after end {end}"),
+        }
+    }
+}
+
+// The same as &text[range] but an error rather than a panic.
+pub fn byte_slice(text: &str, range: Range<usize>) -> Result<&str, SliceError> {
+    if range.start > range.end {
+        return Err(SliceError::Backwards {
+            start: range.start,
+            end: range.end,
+        });
+    }
+    if range.end > text.len() {
+        return Err(SliceError::OutOfBounds {
+            end: range.end,
+            len: text.len(),
+        });
+    }
+    for index in [range.start, range.end] {
+        if !text.is_char_boundary(index) {
+            return Err(SliceError::NotCharBoundary { index });
+        }
+    }
+    Ok(&text[range])
+}
+
+// Like byte_slice but the range counts characters, so it can't split one.
+pub fn char_slice(text: &str, range: Range<usize>) -> Result<&str, SliceError> {
+    if range.start > range.end {
+        return Err(SliceError::Backwards {
+            start: range.start,
+            end: range.end,
+        });
+    }
+    let len = text.chars().count();
+    if range.end > len {
+        return Err(SliceError::OutOfBounds {
+            end: range.end,
+            len,
+        });
+    }
+    // char_indices gives the byte offset of each character.
+    let byte_offset = |char_index: usize| {
+        text.char_indices()
+            .nth(char_index)
+            .map_or(text.len(), |(offset, _)| offset)
+    };
+    Ok(&text[byte_offset(range.start)..byte_offset(range.end)])
+}
+
+const HELP: &str = "\
+commands:
+  first <text>             the first word
+  last <text>              the last word
+  nth <n> <text>           word n (counting from 0)
+  bytes <a>..<b> <text>    slice by byte range
+  chars <a>..<b> <text>    slice by character range
+  help                     show this
+  quit                     stop (so does end of input)";
+
+// Ranges look like 0..5, 6.. or ..5 (open ends go to the end of the text).
+fn parse_range(range: &str, len: usize) -> Option<Range<usize>> {
+    let (start, end) = range.split_once("..")?;
+    let start = if start.is_empty() {
+        0
+    } else {
+        start.parse().ok()?
+    };
+    let end = if end.is_empty() {
+        len
+    } else {
+        end.parse().ok()?
+    };
+    Some(start..end)
+}
+
+// Splits "command rest of line" keeping the rest exactly as typed.
+fn split_command(line: &str) -> (&str, &str) {
+    let line = line.trim_start();
+    match line.find(char::is_whitespace) {
+        Some(index) => (&line[..index], line[index..].trim_start()),
+        None => (line, ""),
+    }
+}
+
+fn respond(line: &str) -> Option<String> {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let (command, rest) = split_command(line);
+    let reply = match command {
+        "" => return Some(String::new()),
+        "quit" | "exit" => return None,
+        "help" => HELP.to_string(),
+        "first" => first_word(rest).to_string(),
+        "last" => last_word(rest).to_string(),
+        "nth" | "bytes" | "chars" => {
+            let (argument, text) = split_command(rest);
+            match command {
+                "nth" => match argument.parse() {
+                    Ok(n) => nth_word(text, n).unwrap_or("<no such word>").to_string(),
+                    Err(_) => format!("error: {argument:?} is not a number"),
+                },
+                _ => {
+                    let len = if command == "bytes" {
+                        text.len()
+                    } else {
+                        text.chars().count()
+                    };
+                    let sliced = match parse_range(argument, len) {
+                        None => Err(format!("{argument:?} is not a range like 0..5")),
+                        Some(range) if command == "bytes" => {
+                            byte_slice(text, range).map_err(|e| e.to_string())
+                        }
+                        Some(range) => char_slice(text, range).map_err(|e| e.to_string()),
+                    };
+                    match sliced {
+                        Ok(slice) => slice.to_string(),
+                        Err(error) => format!("error: {error}"),
+                    }
+                }
+            }
+        }
+        _ => format!("error: unknown command {command:?} (try help)"),
+    };
+    Some(reply)
+}
+
+// Reads commands a line at a time until quit or the input runs out.
+// Taking any BufRead/Write means tests can drive it with in-memory text.
+pub fn repl<R: BufRead, W: Write>(input: R, mut output: W) -> io::Result<()> {
+    writeln!(output, "First word REPL (type help for commands)")?;
+    for line in input.lines() {
+        match respond(&line?) {
+            Some(reply) => writeln!(output, "{reply}")?,
+            None => break,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use output::Buffer;
+use ownership::examples::*;
+
+#[test]
+fn values() {
+    assert_eq!(ownership_example(&mut Buffer::new()), "Hello world!");
+    assert_eq!(reference_example(&mut Buffer::new()), "Issie!!");
+    assert_eq!(reference_scope_example(&mut Buffer::new()), "Simon");
+    assert_eq!(
+        slice_example(&mut Buffer::new()),
+        ("Simon".to_string(), "Zoë".to_string())
+    );
+}
@@ -0,0 +1,90 @@
+use ownership::words::{byte_slice, char_slice, first_word, last_word, nth_word, repl, SliceError};
+use std::ops::Range;
+
+fn run(input: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    repl(input.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    // skip the banner
+    output.lines().skip(1).map(String::from).collect()
+}
+
+#[test]
+fn words_split_on_any_whitespace() {
+    assert_eq!(first_word("hello world"), "hello");
+    assert_eq!(first_word("\t  hello\tworld\n"), "hello");
+    assert_eq!(first_word("hello\n"), "hello");
+    assert_eq!(first_word("   "), "");
+    assert_eq!(last_word("hello big world\n"), "world");
+    assert_eq!(nth_word("a b\tc", 1), Some("b"));
+    assert_eq!(nth_word("a b c", 3), None);
+}
+
+#[test]
+fn byte_slice_does_not_panic() {
+    let zoe = "Zoë Darcy-Jones";
+    assert_eq!(byte_slice(zoe, 0..2), Ok("Zo"));
+    assert_eq!(byte_slice(zoe, 0..4), Ok("Zoë"));
+    assert_eq!(
+        byte_slice(zoe, 0..3),
+        Err(SliceError::NotCharBoundary { index: 3 })
+    );
+    assert_eq!(
+        byte_slice(zoe, 0..100),
+        Err(SliceError::OutOfBounds { end: 100, len: 16 })
+    );
+    assert_eq!(
+        byte_slice(zoe, Range { start: 4, end: 2 }),
+        Err(SliceError::Backwards { start: 4, end: 2 })
+    );
+}
+
+#[test]
+fn char_slice_counts_characters() {
+    let zoe = "Zoë Darcy-Jones";
+    assert_eq!(char_slice(zoe, 0..3), Ok("Zoë"));
+    assert_eq!(char_slice(zoe, 4..15), Ok("Darcy-Jones"));
+    assert_eq!(char_slice("✅✅", 1..2), Ok("✅"));
+    assert_eq!(char_slice("", 0..0), Ok(""));
+    assert_eq!(
+        char_slice(zoe, 0..16),
+        Err(SliceError::OutOfBounds { end: 16, len: 15 })
+    );
+}
+
+#[test]
+fn repl_answers_each_line() {
+    let input = "first   Simon Darcy-Jones\nlast Simon Darcy-Jones\nnth 1 a b c\n\
+                 bytes 0..3 Zoë\nchars 0..3 Zoë\nchars 4.. Zoë Jones\nbytes ..2 Zoë\n";
+    assert_eq!(
+        run(input),
+        vec![
+            "Simon",
+            "Darcy-Jones",
+            "b",
+            "error: byte 3 is in the middle of a character",
+            "Zoë",
+            "Jones",
+            "Zo",
+        ]
+    );
+}
+
+#[test]
+fn repl_reports_bad_input_and_stops_on_quit() {
+    let input = "dance\nnth x a b\nchars 1-2 abc\nnth 5 a\nquit\nfirst never reached\n";
+    assert_eq!(
+        run(input),
+        vec![
+            "error: unknown command \"dance\" (try help)",
+            "error: \"x\" is not a number",
+            "error: \"1-2\" is not a range like 0..5",
+            "<no such word>",
+        ]
+    );
+}
+
+#[test]
+fn repl_handles_windows_line_endings() {
+    assert_eq!(run("first hello world\r\n"), vec!["hello"]);
+}
@@ -0,0 +1,187 @@
+// A small proleptic Gregorian calendar, enough for birthdays and ages without
+// pulling in chrono or a timezone database. Dates have no time or timezone,
+// Date::today() is the date in UTC.
+//
+// Under the hood a date is also a day number, days since 1970-01-01, which
+// makes adding days and counting the days between two dates just arithmetic.
+// The conversions are Howard Hinnant's days_from_civil / civil_from_days
+// (http://howardhinnant.github.io/date_algorithms.html). They count years
+// from March so the leap day is the last day of the year and falls out of
+// the arithmetic rather than needing a special case.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    InvalidMonth(u32),
+    // the day doesn't exist in that month, e.g. 2023-02-29
+    InvalidDay { year: i32, month: u32, day: u32 },
+    // not YYYY-MM-DD
+    Parse(String),
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::InvalidMonth(month) => write!(f, "there is no month {month}"),
+            DateError::InvalidDay { year, month, day } => {
+                write!(f, "there is no day {day} in {year}-{month:02}")
+            }
+            DateError::Parse(text) => write!(f, "expected a date like 2024-02-29, not {text:?}"),
+        }
+    }
+}
+
+impl Error for DateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+// The fields are in year, month, day order so the derived Ord is date order.
+// They're private so every Date is a real one, see new.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Date, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::InvalidMonth(month));
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(DateError::InvalidDay { year, month, day });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    pub fn today() -> Date {
+        // a clock set before 1970 is unlikely enough to just call it 1970
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Date::from_days(i64::try_from(seconds / 86_400).expect("fewer days than i64::MAX"))
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    // Days since 1970-01-01, negative before it
+    pub fn days(&self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        // March is 0 so February, with the leap day, comes last
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let march_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * march_month + 2) / 5 + 1;
+        let month = if march_month < 10 {
+            march_month + 3
+        } else {
+            march_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date {
+            year: i32::try_from(year).expect("year fits in an i32"),
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    pub fn add_days(&self, days: i64) -> Date {
+        Date::from_days(self.days() + days)
+    }
+
+    // Negative if other is earlier
+    pub fn days_until(&self, other: Date) -> i64 {
+        other.days() - self.days()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        const WEEKDAYS: [Weekday; 7] = [
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+        ];
+        WEEKDAYS[self.days().rem_euclid(7) as usize]
+    }
+
+    pub fn is_leap_day(&self) -> bool {
+        self.month == 2 && self.day == 29
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// "2024-02-29"
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(text: &str) -> Result<Date, DateError> {
+        let parse_error = || DateError::Parse(text.to_string());
+        let mut parts = text.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(parse_error);
+        let (year, month, day) = (next()?, next()?, next()?);
+        Date::new(
+            year.parse().map_err(|_| parse_error())?,
+            month.parse().map_err(|_| parse_error())?,
+            day.parse().map_err(|_| parse_error())?,
+        )
+    }
+}
@@ -0,0 +1,76 @@
+// A list of people we can ask date questions of, whose birthday is coming up
+// and how old everyone is.
+use crate::calendar::Date;
+use crate::human::Human;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Birthday<'a> {
+    pub human: &'a Human,
+    pub date: Date,
+    pub turning: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct Directory {
+    pub people: Vec<Human>,
+}
+
+impl Directory {
+    pub fn new(people: Vec<Human>) -> Directory {
+        Directory { people }
+    }
+
+    // Birthdays from today up to and including days later, soonest first (by
+    // name when two share a day)
+    pub fn upcoming_birthdays(&self, today: Date, days: u32) -> Vec<Birthday<'_>> {
+        let last = today.add_days(i64::from(days));
+        let mut birthdays: Vec<Birthday> = self
+            .people
+            .iter()
+            .map(|human| {
+                let date = human.next_birthday(today);
+                Birthday {
+                    human,
+                    date,
+                    turning: (date.year() - human.born.year()) as u32,
+                }
+            })
+            .filter(|birthday| birthday.date <= last)
+            .collect();
+        birthdays.sort_by(|a, b| a.date.cmp(&b.date).then(a.human.name.cmp(&b.human.name)));
+        birthdays
+    }
+
+    // Everyone's age on date, leaving out anyone not yet born
+    pub fn ages_on(&self, date: Date) -> Vec<u32> {
+        self.people
+            .iter()
+            .filter_map(|human| human.age_on(date))
+            .collect()
+    }
+
+    // How many people are in each band of bucket years, keyed by where the band
+    // starts, so with a bucket of 10 the 0 key counts ages 0 to 9. Empty bands
+    // are left out.
+    pub fn age_distribution(&self, date: Date, bucket: u32) -> BTreeMap<u32, usize> {
+        assert!(bucket > 0, "a bucket has to hold at least one age");
+        let mut distribution = BTreeMap::new();
+        for age in self.ages_on(date) {
+            *distribution.entry(age / bucket * bucket).or_insert(0) += 1;
+        }
+        distribution
+    }
+
+    pub fn average_age(&self, date: Date) -> Option<f64> {
+        let ages = self.ages_on(date);
+        if ages.is_empty() {
+            return None;
+        }
+        Some(ages.iter().map(|age| f64::from(*age)).sum::<f64>() / ages.len() as f64)
+    }
+
+    pub fn oldest(&self) -> Option<&Human> {
+        self.people.iter().min_by_key(|human| human.born)
+    }
+}
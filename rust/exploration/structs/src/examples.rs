@@ -0,0 +1,298 @@
+// Examples which create instances and call some methods. I show how to make
+// a mutable instance too.
+use crate::calendar::Date;
+use crate::directory::Directory;
+use crate::family::{gedcom, FamilyTree};
+use crate::gate::runtime::RuntimeGate;
+use crate::gate::{Closed, Gate};
+use crate::geometry::{Coordinate, Rectangle};
+use crate::human::Human;
+use audit::Audited;
+use describe::Describe;
+use output::Output;
+
+// Everything main runs, in order.
+pub fn run_all(out: &mut dyn Output) {
+    writeln!(out, "Running structs examples");
+    instantiating_structs_example(out);
+    field_init_shorthand_example(out);
+    struct_update_syntax_example(out);
+    audited_example(out);
+    birthday_example(out);
+    family_example(out);
+    tuple_structs_example(out);
+    unit_struct_example(out);
+    geometry_example(out);
+    typestate_example(out);
+    writeln!(out);
+}
+
+// The examples pretend today is always this date so what they print (and the
+// snapshot of it) doesn't change as the real days go by.
+pub fn today() -> Date {
+    Date::new(2024, 2, 28).expect("a real date")
+}
+
+fn date(text: &str) -> Date {
+    text.parse().expect("a real date")
+}
+
+// returns simon and the baby
+pub fn instantiating_structs_example(out: &mut dyn Output) -> (Human, Human) {
+    let simon = Human {
+        name: String::from("Simon Darcy-Jones"),
+        born: date("1992-07-16"),
+        email: String::from("simonwardjones16@gmail.com"),
+    };
+    writeln!(out, "{:?}", simon);
+    writeln!(out, "Email field {} ", simon.email);
+    // the pretty Debug that dbg!(&simon) prints, dbg! itself would write to
+    // stderr for everyone calling this
+    writeln!(out, "{:#?}", simon);
+    writeln!(out, "{}", simon.describe());
+    let mut baby = Human::baby("no-name".to_string(), today());
+    baby.set_name(String::from("Jack"));
+    baby.name = String::from("Tom"); // same as using setter
+    writeln!(out, "{}", baby.describe());
+    baby.name += "as";
+    writeln!(out, "{}", baby.describe());
+    if simon.has_same_name(&baby) {
+        writeln!(out, "Simon and baby have the same name");
+    } else {
+        writeln!(out, "Simon and baby have different names");
+    }
+    (simon, baby)
+}
+
+pub fn field_init_shorthand_example(out: &mut dyn Output) -> Human {
+    let name = String::from("Simon Darcy-Jones");
+    let born = date("1992-07-16");
+    let email = String::from("simonwardjones16@gmail.com");
+    let simon = Human { name, born, email };
+    // writeln!(out, "{:?}", name); This would error as name is moved into struct
+    writeln!(out, "{:?}", simon);
+    simon
+}
+
+pub fn struct_update_syntax_example(out: &mut dyn Output) -> Human {
+    let simon = Human {
+        name: String::from("Simon Darcy-Jones"),
+        born: date("1992-07-16"),
+        email: String::from("simonwardjones16@gmail.com"),
+    };
+    // no need to fake a birthday with age: 32 any more, so a new email instead
+    let yahoo_simon = Human {
+        email: String::from("simon@yahoo.com"),
+        ..simon
+    };
+    writeln!(out, "{:?}", yahoo_simon);
+    yahoo_simon
+}
+
+// The baby from instantiating_structs_example again, but this time every
+// rename is recorded (see audit), returns the audited baby
+pub fn audited_example(out: &mut dyn Output) -> Audited<Human> {
+    writeln!(out, "Audited");
+    let mut baby = Audited::new(Human::baby("no-name".to_string(), today()));
+    baby.edit().set_name(String::from("Jack"));
+    baby.edit().name = String::from("Tom");
+    baby.edit().name += "as";
+    // baby.name = String::from("Tom") doesn't compile, there's no way to
+    // change the Human without it being recorded
+    baby.update(|human| human.email = String::from("thomas@yahoo.com"));
+    for change in baby.changes() {
+        writeln!(out, "{change}");
+    }
+    baby.undo();
+    baby.undo();
+    writeln!(out, "Undone twice: {}", baby.describe());
+    baby.redo();
+    writeln!(out, "Redone: {}", baby.describe());
+    writeln!(out, "Before change 2 it was {}", baby.as_of(1).name);
+    let simon = Human::baby(String::from("Simon"), date("1992-07-16"));
+    for difference in audit::diff(&*baby, &simon) {
+        writeln!(out, "Tomas and Simon differ on {difference}");
+    }
+    baby
+}
+
+// returns the directory of people it asks about
+pub fn birthday_example(out: &mut dyn Output) -> Directory {
+    writeln!(
+        out,
+        "Birthdays, today is {} a {:?}",
+        today(),
+        today().weekday()
+    );
+    let directory = Directory::new(vec![
+        Human::baby(String::from("Ada"), date("1990-03-10")),
+        Human::baby(String::from("Leap"), date("2000-02-29")),
+        Human::baby(String::from("Simon"), date("1992-07-16")),
+        Human::baby(String::from("Grace"), date("1956-03-01")),
+        Human::baby(String::from("Alan"), date("2012-06-23")),
+    ]);
+    for human in &directory.people {
+        let age = human.age_on(today()).expect("born before today");
+        writeln!(out, "{} is {age}", human.name);
+    }
+    // 2024 is a leap year so Leap gets a real birthday tomorrow
+    for birthday in directory.upcoming_birthdays(today(), 14) {
+        writeln!(
+            out,
+            "{} turns {} on {}",
+            birthday.human.name, birthday.turning, birthday.date
+        );
+    }
+    for (start, count) in directory.age_distribution(today(), 20) {
+        writeln!(out, "{:>2}-{:<2} {}", start, start + 19, "#".repeat(count));
+    }
+    if let Some(average) = directory.average_age(today()) {
+        writeln!(out, "The average age is {average:.1}");
+    }
+    directory
+}
+
+// Babies linked to their parents (see family.rs), returns the tree
+pub fn family_example(out: &mut dyn Output) -> FamilyTree {
+    writeln!(out, "Family tree");
+    let mut tree = FamilyTree::new();
+    let grace = tree.add(Human::baby(String::from("Grace"), date("1956-03-01")));
+    let alan = tree.add(Human::baby(String::from("Alan"), date("1954-06-23")));
+    let ada = tree
+        .baby("Ada", date("1978-03-10"), &[&grace, &alan])
+        .expect("a first child");
+    let bob = tree
+        .baby("Bob", date("1980-11-02"), &[&grace, &alan])
+        .expect("a second child");
+    let simon = tree
+        .baby("Simon", date("1992-07-16"), &[&bob])
+        .expect("a grandchild");
+    let leap = tree
+        .baby("Leap", date("2000-02-29"), &[&ada])
+        .expect("a grandchild");
+    for (ancestor, generations) in simon.ancestors() {
+        writeln!(out, "{} is {generations} up from Simon", ancestor.name());
+    }
+    for person in [&grace, &ada, &leap] {
+        let relationship = simon.relationship_to(person).expect("related");
+        writeln!(out, "{} is Simon's {relationship}", person.name());
+    }
+    if let Err(error) = tree.add_parent(&grace, &leap) {
+        writeln!(out, "{error}");
+    }
+    // everyone is held by the tree and their parents, never by their children
+    writeln!(
+        out,
+        "Ada has {} strong and {} weak references",
+        ada.strong_count(),
+        ada.weak_count()
+    );
+    let text = gedcom::export(&tree);
+    writeln!(out, "As GEDCOM that's {} lines", text.lines().count());
+    tree
+}
+
+pub fn tuple_structs_example(out: &mut dyn Output) -> Coordinate {
+    // same value - differing types, a Size can't be passed as a Coordinate
+    #[derive(Debug)]
+    #[allow(dead_code)] // the fields are only printed
+    struct Size(i32, i32);
+    let square = Size(10, 10);
+    let ten_ten = Coordinate(10, 10);
+    writeln!(out, "square = {square:?}, ten_ten = {ten_ten:?}");
+    ten_ten
+}
+
+// returns where we walk to and where the kitchen and hall overlap
+pub fn geometry_example(out: &mut dyn Output) -> (Coordinate, Option<Rectangle>) {
+    writeln!(out, "Geometry");
+    // the operators are implemented in geometry.rs
+    let start = Coordinate(1, 2);
+    let step = Coordinate(3, -1);
+    let end = start + step * 2;
+    writeln!(out, "{start:?} + {step:?} * 2 = {end:?}");
+    writeln!(
+        out,
+        "That's {} blocks or {:.3} as the crow flies",
+        start.manhattan_distance(end),
+        start.euclidean_distance(end)
+    );
+    writeln!(
+        out,
+        "dot product {}, cross product {}",
+        start.dot(step),
+        start.cross(step)
+    );
+
+    let kitchen = Rectangle::from_size(4, 3);
+    let hall = Rectangle::new(Coordinate(10, 2), Coordinate(3, 0));
+    let overlap = kitchen.intersection(&hall);
+    writeln!(out, "kitchen {kitchen:?}");
+    writeln!(out, "hall {hall:?}");
+    writeln!(out, "They overlap in {overlap:?}");
+    writeln!(
+        out,
+        "Together they fit in an area of {}",
+        kitchen.union(&hall).area()
+    );
+    writeln!(
+        out,
+        "The kitchen contains {:?}: {}, {:?}: {}",
+        start,
+        kitchen.contains(start),
+        end,
+        kitchen.contains(end)
+    );
+
+    // and the same with floats
+    let half = Coordinate(0.5, 1.5) * 0.5;
+    writeln!(out, "{half:?} is {:.3} from the origin", half.length());
+    (end, overlap)
+}
+
+pub fn unit_struct_example(out: &mut dyn Output) -> bool {
+    #[derive(PartialEq)]
+    struct Sentinel;
+    let x = Sentinel;
+    let is_sentinel = x == Sentinel;
+    if is_sentinel {
+        writeln!(out, "Sentinel is a unit struct");
+    }
+    is_sentinel
+}
+
+// Unit structs as zero-sized states (see gate.rs), returns the closed gate
+pub fn typestate_example(out: &mut dyn Output) -> Gate<Closed> {
+    writeln!(out, "Typestate");
+    let gate = Gate::new(1234);
+    writeln!(out, "The gate is {}", gate.state());
+    let gate = gate.open();
+    writeln!(out, "The gate is {}", gate.state());
+    let gate = gate.pass_through().open().pass_through();
+    writeln!(
+        out,
+        "{} cars through and the gate is {}",
+        gate.cars_through(),
+        gate.state()
+    );
+    // gate.pass_through() here wouldn't compile, a closed gate has no such method
+    let gate = match gate.lock().unlock(1111) {
+        Ok(gate) => gate,
+        Err(locked) => {
+            writeln!(out, "Wrong code, the gate is still {}", locked.state());
+            locked.unlock(1234).expect("the right code")
+        }
+    };
+    writeln!(
+        out,
+        "The states are zero sized, a gate is {} bytes whatever its state",
+        std::mem::size_of_val(&gate)
+    );
+
+    // the runtime version compiles the mistake and tells us when it runs
+    let mut runtime = RuntimeGate::new(1234);
+    if let Err(error) = runtime.pass_through() {
+        writeln!(out, "Runtime gate: {error}");
+    }
+    gate
+}
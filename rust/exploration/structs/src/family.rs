@@ -0,0 +1,437 @@
+// A family tree of Humans, linked both ways.
+//
+// A parent holds its children with Rc (strong) and a child points back at its
+// parents with Weak. If both directions were Rc each parent and child would
+// keep the other alive forever, a reference cycle the counts never get out
+// of, so we'd leak the whole family. With Weak going up the tree owns everyone
+// through its list and dropping it frees every member.
+//
+// RefCell lets us add a child to a parent that other people also point at,
+// the borrow rules are checked when we run instead of when we compile.
+use crate::calendar::Date;
+use crate::human::Human;
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+pub mod gedcom;
+
+#[derive(Debug)]
+struct Member {
+    human: Human,
+    parents: Vec<Weak<RefCell<Member>>>,
+    children: Vec<Rc<RefCell<Member>>>,
+}
+
+// A handle on someone in a tree. Cloning it clones the Rc, not the Human.
+#[derive(Clone)]
+pub struct Person(Rc<RefCell<Member>>);
+
+// A handle that doesn't keep them alive, for checking nothing leaks.
+#[derive(Clone)]
+pub struct WeakPerson(Weak<RefCell<Member>>);
+
+impl WeakPerson {
+    pub fn upgrade(&self) -> Option<Person> {
+        self.0.upgrade().map(Person)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FamilyError {
+    TooManyParents(String),
+    OwnParent(String),
+    AlreadyParent { parent: String, child: String },
+    // the parent is already a descendant of the child
+    Cycle { parent: String, child: String },
+    // someone from another tree, or never added to one
+    NotInTree(String),
+}
+
+impl fmt::Display for FamilyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FamilyError::TooManyParents(name) => write!(f, "{name} already has two parents"),
+            FamilyError::OwnParent(name) => write!(f, "{name} can't be their own parent"),
+            FamilyError::AlreadyParent { parent, child } => {
+                write!(f, "{parent} is already a parent of {child}")
+            }
+            FamilyError::Cycle { parent, child } => {
+                write!(
+                    f,
+                    "{parent} is a descendant of {child} so can't be their parent"
+                )
+            }
+            FamilyError::NotInTree(name) => write!(f, "{name} isn't in this family tree"),
+        }
+    }
+}
+
+impl Error for FamilyError {}
+
+// What someone is to someone else, see Person::relationship_to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relationship {
+    Same,
+    // 1 is a parent, 2 a grandparent, 3 a great-grandparent...
+    Ancestor { generations: u32 },
+    Descendant { generations: u32 },
+    Sibling { half: bool },
+    // 1 is an aunt or uncle, 2 a great-aunt or great-uncle...
+    AuntOrUncle { generations: u32 },
+    NieceOrNephew { generations: u32 },
+    // degree 1 is a first cousin, removed counts the generations between
+    Cousin { degree: u32, removed: u32 },
+}
+
+// "", "grand", "great-grand", "great-great-grand" for 1, 2, 3, 4 generations
+fn grand(generations: u32) -> String {
+    match generations {
+        0 | 1 => String::new(),
+        n => "great-".repeat(n as usize - 2) + "grand",
+    }
+}
+
+// "", "great-", "great-great-" for 1, 2, 3 generations
+fn great(generations: u32) -> String {
+    "great-".repeat(generations.saturating_sub(1) as usize)
+}
+
+fn ordinal(n: u32) -> String {
+    match n {
+        1 => "first".to_string(),
+        2 => "second".to_string(),
+        3 => "third".to_string(),
+        n if n % 10 == 1 && n % 100 != 11 => format!("{n}st"),
+        n if n % 10 == 2 && n % 100 != 12 => format!("{n}nd"),
+        n if n % 10 == 3 && n % 100 != 13 => format!("{n}rd"),
+        n => format!("{n}th"),
+    }
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Relationship::Same => write!(f, "the same person"),
+            Relationship::Ancestor { generations } => write!(f, "{}parent", grand(generations)),
+            Relationship::Descendant { generations } => write!(f, "{}child", grand(generations)),
+            Relationship::Sibling { half: false } => write!(f, "sibling"),
+            Relationship::Sibling { half: true } => write!(f, "half-sibling"),
+            Relationship::AuntOrUncle { generations } => {
+                let great = great(generations);
+                write!(f, "{great}aunt or {great}uncle")
+            }
+            Relationship::NieceOrNephew { generations } => {
+                let great = great(generations);
+                write!(f, "{great}niece or {great}nephew")
+            }
+            Relationship::Cousin { degree, removed } => {
+                write!(f, "{} cousin", ordinal(degree))?;
+                match removed {
+                    0 => Ok(()),
+                    1 => write!(f, " once removed"),
+                    2 => write!(f, " twice removed"),
+                    n => write!(f, " {n} times removed"),
+                }
+            }
+        }
+    }
+}
+
+impl Person {
+    fn new(human: Human) -> Person {
+        Person(Rc::new(RefCell::new(Member {
+            human,
+            parents: vec![],
+            children: vec![],
+        })))
+    }
+
+    // Borrowed from the RefCell, so don't hold on to it while changing links
+    pub fn human(&self) -> Ref<'_, Human> {
+        Ref::map(self.0.borrow(), |member| &member.human)
+    }
+
+    pub fn human_mut(&self) -> RefMut<'_, Human> {
+        RefMut::map(self.0.borrow_mut(), |member| &mut member.human)
+    }
+
+    pub fn name(&self) -> String {
+        self.human().name.clone()
+    }
+
+    // The same person, not just someone equal
+    pub fn is(&self, other: &Person) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    // Parents that have been dropped are skipped
+    pub fn parents(&self) -> Vec<Person> {
+        self.0
+            .borrow()
+            .parents
+            .iter()
+            .filter_map(|parent| parent.upgrade().map(Person))
+            .collect()
+    }
+
+    pub fn children(&self) -> Vec<Person> {
+        self.0
+            .borrow()
+            .children
+            .iter()
+            .cloned()
+            .map(Person)
+            .collect()
+    }
+
+    pub fn downgrade(&self) -> WeakPerson {
+        WeakPerson(Rc::downgrade(&self.0))
+    }
+
+    // How many Rcs and Weaks point at this person, for checking for leaks
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(&self.0)
+    }
+
+    pub fn weak_count(&self) -> usize {
+        Rc::weak_count(&self.0)
+    }
+
+    // Walks the tree breadth first so everyone is found at their nearest
+    // generation (cousins who marry make some people ancestors twice over).
+    fn walk(&self, next: fn(&Person) -> Vec<Person>) -> Vec<(Person, u32)> {
+        let mut seen: HashSet<*const RefCell<Member>> = HashSet::from([Rc::as_ptr(&self.0)]);
+        let mut found = vec![];
+        let mut queue = VecDeque::from([(self.clone(), 0)]);
+        while let Some((person, generation)) = queue.pop_front() {
+            for relative in next(&person) {
+                if seen.insert(Rc::as_ptr(&relative.0)) {
+                    found.push((relative.clone(), generation + 1));
+                    queue.push_back((relative, generation + 1));
+                }
+            }
+        }
+        found
+    }
+
+    // With how many generations back they are, parents (1) first
+    pub fn ancestors(&self) -> Vec<(Person, u32)> {
+        self.walk(Person::parents)
+    }
+
+    pub fn descendants(&self) -> Vec<(Person, u32)> {
+        self.walk(Person::children)
+    }
+
+    pub fn is_ancestor_of(&self, other: &Person) -> bool {
+        other
+            .ancestors()
+            .iter()
+            .any(|(ancestor, _)| ancestor.is(self))
+    }
+
+    // Anyone sharing a parent, half-siblings included
+    pub fn siblings(&self) -> Vec<Person> {
+        let mut siblings: Vec<Person> = vec![];
+        for parent in self.parents() {
+            for child in parent.children() {
+                if !child.is(self) && !siblings.iter().any(|sibling| sibling.is(&child)) {
+                    siblings.push(child);
+                }
+            }
+        }
+        siblings
+    }
+
+    // First cousins, the children of our parents' siblings
+    pub fn cousins(&self) -> Vec<Person> {
+        let mut cousins: Vec<Person> = vec![];
+        for parent in self.parents() {
+            for aunt_or_uncle in parent.siblings() {
+                for cousin in aunt_or_uncle.children() {
+                    let is_sibling = self.siblings().iter().any(|sibling| sibling.is(&cousin));
+                    if !cousin.is(self)
+                        && !is_sibling
+                        && !cousins.iter().any(|known| known.is(&cousin))
+                    {
+                        cousins.push(cousin);
+                    }
+                }
+            }
+        }
+        cousins
+    }
+
+    // What other is to self, e.g. Relationship::AuntOrUncle { generations: 1 }
+    // if other is our parent's sibling. None if they share no ancestor.
+    //
+    // We find the closest ancestor we have in common (counting ourselves as
+    // our own generation 0 ancestor) and how many generations up it is from
+    // each of us. Up 1 from both is a sibling, 2 from both a first cousin and
+    // so on.
+    pub fn relationship_to(&self, other: &Person) -> Option<Relationship> {
+        let with_self = |person: &Person| {
+            let mut ancestors = vec![(person.clone(), 0)];
+            ancestors.extend(person.ancestors());
+            ancestors
+        };
+        let mine = with_self(self);
+        let theirs = with_self(other);
+        let mut common: Vec<(u32, u32)> = vec![];
+        for (ancestor, up_from_me) in &mine {
+            for (their_ancestor, up_from_them) in &theirs {
+                if ancestor.is(their_ancestor) {
+                    common.push((*up_from_me, *up_from_them));
+                }
+            }
+        }
+        let (mine, theirs) = *common
+            .iter()
+            .min_by_key(|(mine, theirs)| (mine + theirs, *mine))?;
+        Some(match (mine, theirs) {
+            (0, 0) => Relationship::Same,
+            (generations, 0) => Relationship::Ancestor { generations },
+            (0, generations) => Relationship::Descendant { generations },
+            (1, 1) => {
+                // full siblings share both parents, so two common ancestors a
+                // generation up
+                let shared = common.iter().filter(|pair| **pair == (1, 1)).count();
+                Relationship::Sibling { half: shared < 2 }
+            }
+            (mine, 1) => Relationship::AuntOrUncle {
+                generations: mine - 1,
+            },
+            (1, theirs) => Relationship::NieceOrNephew {
+                generations: theirs - 1,
+            },
+            (mine, theirs) => Relationship::Cousin {
+                degree: mine.min(theirs) - 1,
+                removed: mine.abs_diff(theirs),
+            },
+        })
+    }
+}
+
+impl PartialEq for Person {
+    fn eq(&self, other: &Person) -> bool {
+        self.is(other)
+    }
+}
+
+// Just the name, the derived Debug would print the whole family
+impl fmt::Debug for Person {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Person({:?})", self.human().name)
+    }
+}
+
+// Owns everyone in the family, in the order they were added.
+#[derive(Default)]
+pub struct FamilyTree {
+    people: Vec<Person>,
+}
+
+impl FamilyTree {
+    pub fn new() -> FamilyTree {
+        FamilyTree::default()
+    }
+
+    pub fn add(&mut self, human: Human) -> Person {
+        let person = Person::new(human);
+        self.people.push(person.clone());
+        person
+    }
+
+    // Human::baby, with the parents linked up. The parents are all checked
+    // first so a mistake doesn't leave a baby in the tree with only one.
+    pub fn baby(
+        &mut self,
+        name: &str,
+        born: Date,
+        parents: &[&Person],
+    ) -> Result<Person, FamilyError> {
+        if parents.len() > 2 {
+            return Err(FamilyError::TooManyParents(name.to_string()));
+        }
+        for parent in parents {
+            self.check_in_tree(parent)?;
+        }
+        if let [first, second] = parents {
+            if first.is(second) {
+                return Err(FamilyError::AlreadyParent {
+                    parent: first.name(),
+                    child: name.to_string(),
+                });
+            }
+        }
+        // someone new can't be anyone's ancestor, so nothing else can go wrong
+        let baby = self.add(Human::baby(name.to_string(), born));
+        for parent in parents {
+            link(&baby, parent);
+        }
+        Ok(baby)
+    }
+
+    pub fn contains(&self, person: &Person) -> bool {
+        self.people.iter().any(|known| known.is(person))
+    }
+
+    fn check_in_tree(&self, person: &Person) -> Result<(), FamilyError> {
+        if self.contains(person) {
+            Ok(())
+        } else {
+            Err(FamilyError::NotInTree(person.name()))
+        }
+    }
+
+    pub fn add_parent(&mut self, child: &Person, parent: &Person) -> Result<(), FamilyError> {
+        let names = || (parent.name(), child.name());
+        // otherwise one tree would hold links into another
+        self.check_in_tree(child)?;
+        self.check_in_tree(parent)?;
+        if child.is(parent) {
+            return Err(FamilyError::OwnParent(child.name()));
+        }
+        if child.parents().iter().any(|known| known.is(parent)) {
+            let (parent, child) = names();
+            return Err(FamilyError::AlreadyParent { parent, child });
+        }
+        if child.parents().len() == 2 {
+            return Err(FamilyError::TooManyParents(child.name()));
+        }
+        if child.is_ancestor_of(parent) {
+            let (parent, child) = names();
+            return Err(FamilyError::Cycle { parent, child });
+        }
+        link(child, parent);
+        Ok(())
+    }
+
+    pub fn people(&self) -> &[Person] {
+        &self.people
+    }
+
+    pub fn len(&self) -> usize {
+        self.people.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.people.is_empty()
+    }
+
+    // The first person with that name
+    pub fn find(&self, name: &str) -> Option<Person> {
+        self.people
+            .iter()
+            .find(|person| person.human().name == name)
+            .cloned()
+    }
+}
+
+fn link(child: &Person, parent: &Person) {
+    child.0.borrow_mut().parents.push(Rc::downgrade(&parent.0));
+    parent.0.borrow_mut().children.push(Rc::clone(&child.0));
+}
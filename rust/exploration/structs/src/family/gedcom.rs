@@ -0,0 +1,307 @@
+// GEDCOM-lite, just enough of the genealogy file format to save a FamilyTree
+// and load it again (or load a simple one from another program).
+//
+// A GEDCOM file is lines of "level [@id@] TAG [value]", where a line belongs
+// to the closest line above it with a lower level:
+//
+//   0 @I1@ INDI
+//   1 NAME Grace
+//   1 BIRT
+//   2 DATE 1 MAR 1956
+//   1 EMAIL Grace@gmail.com
+//   0 @F1@ FAM
+//   1 _PARE @I1@
+//   1 CHIL @I2@
+//
+// Real GEDCOM names the parents of a family HUSB and WIFE. A Human doesn't
+// have a sex for us to pick one, so we write _PARE (tags starting with an
+// underscore are the format's way of adding your own) and read all three.
+// Anything else we don't know, like the FAMC/FAMS links from people back to
+// their families, is skipped.
+use super::{FamilyError, FamilyTree, Person};
+use crate::calendar::Date;
+use crate::human::Human;
+use std::error::Error;
+use std::fmt;
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GedcomError {
+    // not "level [@id@] TAG [value]"
+    Syntax { line: usize, text: String },
+    // not a date like "16 JUL 1992"
+    BadDate { line: usize, text: String },
+    MissingName(String),
+    MissingBirth(String),
+    // a family mentions an id no INDI record has
+    UnknownPerson { line: usize, id: String },
+    Family(FamilyError),
+}
+
+impl fmt::Display for GedcomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GedcomError::Syntax { line, text } => write!(f, "line {line}: can't read {text:?}"),
+            GedcomError::BadDate { line, text } => {
+                write!(
+                    f,
+                    "line {line}: expected a date like 16 JUL 1992, not {text:?}"
+                )
+            }
+            GedcomError::MissingName(id) => write!(f, "@{id}@ has no NAME"),
+            GedcomError::MissingBirth(id) => write!(f, "@{id}@ has no BIRT DATE"),
+            GedcomError::UnknownPerson { line, id } => write!(f, "line {line}: no one is {id}"),
+            GedcomError::Family(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for GedcomError {}
+
+impl From<FamilyError> for GedcomError {
+    fn from(error: FamilyError) -> GedcomError {
+        GedcomError::Family(error)
+    }
+}
+
+fn format_date(date: Date) -> String {
+    let month = MONTHS[date.month() as usize - 1];
+    format!("{} {month} {}", date.day(), date.year())
+}
+
+fn parse_date(text: &str) -> Option<Date> {
+    let mut parts = text.split_whitespace();
+    let day = parts.next()?.parse().ok()?;
+    let month = parts.next()?.to_uppercase();
+    let month = MONTHS.iter().position(|name| *name == month)? as u32 + 1;
+    let year = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Date::new(year, month, day).ok()
+}
+
+// Surnames are written between slashes, "Simon /Jones/ Jr". Only a pair
+// around a word (or words) are the surname's, so "AC/DC" keeps its slash. Of
+// those the last pair wins, we don't split names into their parts so there's
+// only ever one surname.
+fn read_name(value: &str) -> String {
+    let name = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    let is_gap = |at: Option<char>| at.is_none_or(|at| at == ' ');
+    let close = name
+        .char_indices()
+        .filter(|(at, slash)| *slash == '/' && is_gap(name[at + 1..].chars().next()))
+        .map(|(at, _)| at)
+        .next_back();
+    let Some(close) = close else {
+        return name;
+    };
+    match name[..close].rfind('/') {
+        Some(open) if is_gap(name[..open].chars().next_back()) => {
+            let before = name[..open].trim_end();
+            let after = name[close + 1..].trim_start();
+            let surname = &name[open + 1..close];
+            [before, surname, after]
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+        _ => name,
+    }
+}
+
+// Our names have no surname marked, but one that looks like it has is given
+// an empty surname at the end so it's read back as it was.
+fn write_name(name: &str) -> String {
+    if read_name(name) == name {
+        name.to_string()
+    } else {
+        format!("{name} //")
+    }
+}
+
+// People are @I1@, @I2@... in tree order. A family is each set of parents
+// that has children, in the order we first meet one of those children.
+pub fn export(tree: &FamilyTree) -> String {
+    let people = tree.people();
+    let id = |person: &Person| {
+        people
+            .iter()
+            .position(|known| known.is(person))
+            .expect("parents are in the tree too")
+            + 1
+    };
+    let mut lines = vec![
+        "0 HEAD".to_string(),
+        "1 GEDC".to_string(),
+        "2 VERS 5.5.1".to_string(),
+        "2 FORM LINEAGE-LINKED".to_string(),
+        "1 CHAR UTF-8".to_string(),
+    ];
+    let mut families: Vec<(Vec<usize>, Vec<usize>)> = vec![];
+    for (index, person) in people.iter().enumerate() {
+        let human = person.human();
+        lines.push(format!("0 @I{}@ INDI", index + 1));
+        lines.push(format!("1 NAME {}", write_name(&human.name)));
+        lines.push("1 BIRT".to_string());
+        lines.push(format!("2 DATE {}", format_date(human.born)));
+        if !human.email.is_empty() {
+            lines.push(format!("1 EMAIL {}", human.email));
+        }
+        let mut parents: Vec<usize> = person.parents().iter().map(id).collect();
+        if parents.is_empty() {
+            continue;
+        }
+        parents.sort();
+        match families.iter_mut().find(|(known, _)| *known == parents) {
+            Some((_, children)) => children.push(index + 1),
+            None => families.push((parents, vec![index + 1])),
+        }
+    }
+    for (index, (parents, children)) in families.iter().enumerate() {
+        lines.push(format!("0 @F{}@ FAM", index + 1));
+        for parent in parents {
+            lines.push(format!("1 _PARE @I{parent}@"));
+        }
+        for child in children {
+            lines.push(format!("1 CHIL @I{child}@"));
+        }
+    }
+    lines.push("0 TRLR".to_string());
+    lines.join("\n") + "\n"
+}
+
+#[derive(Default)]
+struct Individual {
+    id: String,
+    name: Option<String>,
+    born: Option<Date>,
+    email: Option<String>,
+}
+
+#[derive(Default)]
+struct Family {
+    // (line, id) so a missing person can say where they were mentioned
+    parents: Vec<(usize, String)>,
+    children: Vec<(usize, String)>,
+}
+
+enum Record {
+    Individual,
+    Family,
+    // HEAD, TRLR and anything we don't read
+    Other,
+}
+
+// Reads everything first then builds the tree, as a family can come before
+// the people in it.
+pub fn import(text: &str) -> Result<FamilyTree, GedcomError> {
+    let mut individuals: Vec<Individual> = vec![];
+    let mut families: Vec<Family> = vec![];
+    let mut record = Record::Other;
+    // the level 1 tag the level 2 lines are under
+    let mut under = String::new();
+    for (index, text) in text.lines().enumerate() {
+        let line = index + 1;
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let syntax_error = || GedcomError::Syntax {
+            line,
+            text: text.to_string(),
+        };
+        let (level, rest) = text.split_once(' ').ok_or_else(syntax_error)?;
+        let level: u32 = level.parse().map_err(|_| syntax_error())?;
+        let (id, rest) = match rest.strip_prefix('@') {
+            Some(_) => {
+                let (id, rest) = rest.split_once(' ').ok_or_else(syntax_error)?;
+                (Some(id.trim_matches('@')), rest)
+            }
+            None => (None, rest),
+        };
+        let (tag, value) = rest.split_once(' ').unwrap_or((rest, ""));
+        let value = value.trim();
+        match (level, &record, tag) {
+            (0, _, "INDI") => {
+                individuals.push(Individual {
+                    id: id.ok_or_else(syntax_error)?.to_string(),
+                    ..Individual::default()
+                });
+                record = Record::Individual;
+            }
+            (0, _, "FAM") => {
+                families.push(Family::default());
+                record = Record::Family;
+            }
+            (0, _, _) => record = Record::Other,
+            (1, Record::Individual, _) => {
+                let individual = individuals.last_mut().expect("in an INDI record");
+                match tag {
+                    "NAME" => individual.name = Some(read_name(value)),
+                    "EMAIL" => individual.email = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            (1, Record::Family, _) => {
+                let family = families.last_mut().expect("in a FAM record");
+                match tag {
+                    "HUSB" | "WIFE" | "_PARE" => family.parents.push((line, value.to_string())),
+                    "CHIL" => family.children.push((line, value.to_string())),
+                    _ => {}
+                }
+            }
+            (2, Record::Individual, "DATE") if under == "BIRT" => {
+                let born = parse_date(value).ok_or_else(|| GedcomError::BadDate {
+                    line,
+                    text: value.to_string(),
+                })?;
+                individuals.last_mut().expect("in an INDI record").born = Some(born);
+            }
+            _ => {}
+        }
+        if level == 1 {
+            under = tag.to_string();
+        }
+    }
+
+    let mut tree = FamilyTree::new();
+    let mut ids: Vec<(String, Person)> = vec![];
+    for individual in individuals {
+        let name = individual
+            .name
+            .ok_or_else(|| GedcomError::MissingName(individual.id.clone()))?;
+        let born = individual
+            .born
+            .ok_or_else(|| GedcomError::MissingBirth(individual.id.clone()))?;
+        let person = tree.add(Human {
+            name,
+            born,
+            email: individual.email.unwrap_or_default(),
+        });
+        ids.push((individual.id, person));
+    }
+    let find = |(line, id): &(usize, String)| {
+        let id = id.trim_matches('@');
+        ids.iter()
+            .find(|(known, _)| known == id)
+            .map(|(_, person)| person.clone())
+            .ok_or_else(|| GedcomError::UnknownPerson {
+                line: *line,
+                id: format!("@{id}@"),
+            })
+    };
+    for family in &families {
+        for child in &family.children {
+            let child = find(child)?;
+            for parent in &family.parents {
+                tree.add_parent(&child, &find(parent)?)?;
+            }
+        }
+    }
+    Ok(tree)
+}
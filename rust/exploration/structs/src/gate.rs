@@ -0,0 +1,120 @@
+// The typestate pattern, a car park barrier whose state is part of its type.
+//
+// Closed, Open and Locked are unit structs, zero-sized, so a Gate<Open> is
+// exactly as big as a Gate<Closed> and the state costs nothing at runtime.
+// What it buys is that each state only has the methods that make sense in it,
+// there is no pass_through on a Gate<Closed>, so driving through a closed
+// gate doesn't compile rather than being an error we have to handle:
+//
+//   Gate<Closed> --open--> Gate<Open> --pass_through / close--> Gate<Closed>
+//   Gate<Closed> --lock--> Gate<Locked> --unlock (right code)--> Gate<Closed>
+//
+// Every transition takes self by value, so the old gate is moved and can't be
+// used again. See gate/runtime.rs for the same barrier checked at runtime.
+use std::marker::PhantomData;
+
+pub mod runtime;
+
+#[derive(Debug)]
+pub struct Closed;
+#[derive(Debug)]
+pub struct Open;
+#[derive(Debug)]
+pub struct Locked;
+
+// Sealed so only our three states can be used, a Gate<String> won't compile.
+// The trait is public (it's in Gate's bounds) but lives in a private module,
+// so no other crate can name it to implement it.
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Closed {}
+    impl Sealed for super::Open {}
+    impl Sealed for super::Locked {}
+}
+
+pub trait State: sealed::Sealed {
+    const NAME: &'static str;
+}
+
+impl State for Closed {
+    const NAME: &'static str = "closed";
+}
+
+impl State for Open {
+    const NAME: &'static str = "open";
+}
+
+impl State for Locked {
+    const NAME: &'static str = "locked";
+}
+
+// The fields are private so a Gate<Open> can only be made by opening one.
+#[derive(Debug)]
+pub struct Gate<S: State> {
+    cars_through: u32,
+    code: u32,
+    state: PhantomData<S>,
+}
+
+// Methods every gate has whatever its state
+impl<S: State> Gate<S> {
+    pub fn cars_through(&self) -> u32 {
+        self.cars_through
+    }
+
+    pub fn state(&self) -> &'static str {
+        S::NAME
+    }
+
+    // Same gate, new type. Only used by the transitions below.
+    fn into_state<T: State>(self) -> Gate<T> {
+        Gate {
+            cars_through: self.cars_through,
+            code: self.code,
+            state: PhantomData,
+        }
+    }
+}
+
+impl Gate<Closed> {
+    // New gates start closed, code is what unlocks it
+    pub fn new(code: u32) -> Gate<Closed> {
+        Gate {
+            cars_through: 0,
+            code,
+            state: PhantomData,
+        }
+    }
+
+    pub fn open(self) -> Gate<Open> {
+        self.into_state()
+    }
+
+    pub fn lock(self) -> Gate<Locked> {
+        self.into_state()
+    }
+}
+
+impl Gate<Open> {
+    // One car drives through and the barrier comes down behind it
+    pub fn pass_through(mut self) -> Gate<Closed> {
+        self.cars_through += 1;
+        self.into_state()
+    }
+
+    pub fn close(self) -> Gate<Closed> {
+        self.into_state()
+    }
+}
+
+impl Gate<Locked> {
+    // The wrong code hands back the still locked gate, we've moved self so
+    // there'd be no gate left otherwise
+    pub fn unlock(self, code: u32) -> Result<Gate<Closed>, Gate<Locked>> {
+        if code == self.code {
+            Ok(self.into_state())
+        } else {
+            Err(self)
+        }
+    }
+}
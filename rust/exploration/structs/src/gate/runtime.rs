@@ -0,0 +1,105 @@
+// The same barrier with the state as an enum field. Every method has to check
+// the state when it runs and return an error when it's the wrong one, and
+// it's on the caller to handle (or ignore) that error. In exchange the state
+// can be decided at runtime, say read from a file, and a Vec<RuntimeGate> can
+// hold gates in different states, which Gate<S> can't do without an enum
+// wrapped round it anyway.
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateState {
+    Closed,
+    Open,
+    Locked,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GateError {
+    // e.g. pass_through when the gate is Closed
+    InvalidTransition {
+        action: &'static str,
+        state: GateState,
+    },
+    WrongCode,
+}
+
+impl fmt::Display for GateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GateError::InvalidTransition { action, state } => {
+                write!(f, "can't {action} a gate that is {state:?}")
+            }
+            GateError::WrongCode => write!(f, "wrong code"),
+        }
+    }
+}
+
+impl Error for GateError {}
+
+#[derive(Debug)]
+pub struct RuntimeGate {
+    state: GateState,
+    cars_through: u32,
+    code: u32,
+}
+
+impl RuntimeGate {
+    pub fn new(code: u32) -> RuntimeGate {
+        RuntimeGate {
+            state: GateState::Closed,
+            cars_through: 0,
+            code,
+        }
+    }
+
+    pub fn state(&self) -> GateState {
+        self.state
+    }
+
+    pub fn cars_through(&self) -> u32 {
+        self.cars_through
+    }
+
+    // Moves from one state to the next, or errors if we aren't in `from`
+    fn transition(
+        &mut self,
+        action: &'static str,
+        from: GateState,
+        to: GateState,
+    ) -> Result<(), GateError> {
+        if self.state != from {
+            return Err(GateError::InvalidTransition {
+                action,
+                state: self.state,
+            });
+        }
+        self.state = to;
+        Ok(())
+    }
+
+    pub fn open(&mut self) -> Result<(), GateError> {
+        self.transition("open", GateState::Closed, GateState::Open)
+    }
+
+    pub fn lock(&mut self) -> Result<(), GateError> {
+        self.transition("lock", GateState::Closed, GateState::Locked)
+    }
+
+    pub fn pass_through(&mut self) -> Result<(), GateError> {
+        self.transition("pass through", GateState::Open, GateState::Closed)?;
+        self.cars_through += 1;
+        Ok(())
+    }
+
+    pub fn close(&mut self) -> Result<(), GateError> {
+        self.transition("close", GateState::Open, GateState::Closed)
+    }
+
+    pub fn unlock(&mut self, code: u32) -> Result<(), GateError> {
+        if self.state == GateState::Locked && code != self.code {
+            return Err(GateError::WrongCode);
+        }
+        self.transition("unlock", GateState::Locked, GateState::Closed)
+    }
+}
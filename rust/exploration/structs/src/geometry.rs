@@ -0,0 +1,245 @@
+// Tuple structs, the fields have no names just positions (.0, .1).
+//
+// Coordinate is a 2D point (or vector, the maths is the same) and Rectangle an
+// axis-aligned box between two corners. Both are generic over the number type
+// so the same code does whole-number grids (i32, i64) and real positions (f32,
+// f64). T defaults to i32 so plain Coordinate(1, 2) still works.
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// What we need from a number. The standard library has no trait for this (the
+// num-traits crate does) but ours is only a few lines. Neg means the unsigned
+// types are left out, a u32 difference of two points could underflow anyway.
+pub trait Number:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            const ZERO: $t = 0 as $t;
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+number!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+// PartialOrd has no min and max as NaN can't be ordered, for us the first
+// argument wins when they can't be compared.
+fn smaller<T: Number>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn larger<T: Number>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn abs<T: Number>(a: T) -> T {
+    if a < T::ZERO {
+        -a
+    } else {
+        a
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Coordinate<T = i32>(pub T, pub T);
+
+impl<T: Number> Coordinate<T> {
+    pub const ORIGIN: Coordinate<T> = Coordinate(T::ZERO, T::ZERO);
+
+    pub fn dot(self, other: Coordinate<T>) -> T {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    // The z of the 3D cross product (the x and y are 0 in the plane). Positive
+    // when other is anticlockwise from self, 0 when they are in line.
+    pub fn cross(self, other: Coordinate<T>) -> T {
+        self.0 * other.1 - self.1 * other.0
+    }
+
+    // How far a taxi drives on a grid of streets
+    pub fn manhattan_distance(self, other: Coordinate<T>) -> T {
+        abs(self.0 - other.0) + abs(self.1 - other.1)
+    }
+
+    // How far a crow flies, f64 as the square root of a whole number rarely is one
+    pub fn euclidean_distance(self, other: Coordinate<T>) -> f64 {
+        (self - other).length()
+    }
+
+    pub fn length(self) -> f64 {
+        self.dot(self).to_f64().sqrt()
+    }
+}
+
+impl<T: Number> Add for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn add(self, other: Coordinate<T>) -> Coordinate<T> {
+        Coordinate(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl<T: Number> Sub for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn sub(self, other: Coordinate<T>) -> Coordinate<T> {
+        Coordinate(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+// Scaling by a number, Coordinate(1, 2) * 3. We can't write 3 * Coordinate(1, 2)
+// for every T as the impl would be on the foreign type T.
+impl<T: Number> Mul<T> for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn mul(self, scale: T) -> Coordinate<T> {
+        Coordinate(self.0 * scale, self.1 * scale)
+    }
+}
+
+impl<T: Number> Neg for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn neg(self) -> Coordinate<T> {
+        Coordinate(-self.0, -self.1)
+    }
+}
+
+impl<T: Number> AddAssign for Coordinate<T> {
+    fn add_assign(&mut self, other: Coordinate<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Number> SubAssign for Coordinate<T> {
+    fn sub_assign(&mut self, other: Coordinate<T>) {
+        *self = *self - other;
+    }
+}
+
+// The box from min up to (but not including) max, so the one from (0, 0) to
+// (2, 2) holds the four whole points (0, 0), (1, 0), (0, 1) and (1, 1) and two
+// rectangles sharing an edge don't overlap. The fields are private as min has
+// to stay below max, new sorts out whichever corners it's given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rectangle<T = i32> {
+    min: Coordinate<T>,
+    max: Coordinate<T>,
+}
+
+impl<T: Number> Rectangle<T> {
+    pub fn new(a: Coordinate<T>, b: Coordinate<T>) -> Rectangle<T> {
+        Rectangle {
+            min: Coordinate(smaller(a.0, b.0), smaller(a.1, b.1)),
+            max: Coordinate(larger(a.0, b.0), larger(a.1, b.1)),
+        }
+    }
+
+    // width by height with the bottom left corner at the origin
+    pub fn from_size(width: T, height: T) -> Rectangle<T> {
+        Rectangle::new(Coordinate::ORIGIN, Coordinate(width, height))
+    }
+
+    pub fn min(&self) -> Coordinate<T> {
+        self.min
+    }
+
+    pub fn max(&self) -> Coordinate<T> {
+        self.max
+    }
+
+    pub fn width(&self) -> T {
+        self.max.0 - self.min.0
+    }
+
+    pub fn height(&self) -> T {
+        self.max.1 - self.min.1
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == T::ZERO
+    }
+
+    pub fn contains(&self, point: Coordinate<T>) -> bool {
+        self.min.0 <= point.0
+            && point.0 < self.max.0
+            && self.min.1 <= point.1
+            && point.1 < self.max.1
+    }
+
+    pub fn contains_rectangle(&self, other: &Rectangle<T>) -> bool {
+        self.min.0 <= other.min.0
+            && other.max.0 <= self.max.0
+            && self.min.1 <= other.min.1
+            && other.max.1 <= self.max.1
+    }
+
+    // The overlap, None if they only touch or don't meet at all
+    pub fn intersection(&self, other: &Rectangle<T>) -> Option<Rectangle<T>> {
+        let min = Coordinate(
+            larger(self.min.0, other.min.0),
+            larger(self.min.1, other.min.1),
+        );
+        let max = Coordinate(
+            smaller(self.max.0, other.max.0),
+            smaller(self.max.1, other.max.1),
+        );
+        if min.0 < max.0 && min.1 < max.1 {
+            Some(Rectangle { min, max })
+        } else {
+            None
+        }
+    }
+
+    // The smallest rectangle holding both (which can cover points neither does)
+    pub fn union(&self, other: &Rectangle<T>) -> Rectangle<T> {
+        Rectangle {
+            min: Coordinate(
+                smaller(self.min.0, other.min.0),
+                smaller(self.min.1, other.min.1),
+            ),
+            max: Coordinate(
+                larger(self.max.0, other.max.0),
+                larger(self.max.1, other.max.1),
+            ),
+        }
+    }
+}
+
+// Moving a rectangle, rectangle + Coordinate(1, 0) is one to the right
+impl<T: Number> Add<Coordinate<T>> for Rectangle<T> {
+    type Output = Rectangle<T>;
+
+    fn add(self, offset: Coordinate<T>) -> Rectangle<T> {
+        Rectangle {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+}
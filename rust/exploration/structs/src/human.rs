@@ -0,0 +1,91 @@
+// In this file I create a struct similar to defining a class in python
+// I use the derive to implement the string formatting to print
+// Then I implement some methods (baby is a factory method).
+// describe() comes from the Describe derive and its format (bring
+// describe::Describe into scope to call it).
+//
+// A Human keeps the date they were born, and their age is worked out for
+// whichever date we're asked about.
+//
+// audit::Audited<Human> keeps a record of every change (and can undo them),
+// the fields! below is what it diffs.
+use crate::calendar::{is_leap_year, Date};
+use describe::Describe;
+
+#[derive(Debug, Clone, PartialEq, Eq, Describe)]
+#[describe(format = "{name}, born {born} with email {email}.")]
+pub struct Human {
+    pub name: String,
+    pub born: Date,
+    pub email: String,
+}
+
+audit::fields!(Human {
+    name,
+    born: "{}",
+    email,
+});
+
+impl Human {
+    pub fn baby(name: String, born: Date) -> Human {
+        Human {
+            email: String::from(&name) + "@gmail.com",
+            name,
+            born,
+        }
+    }
+
+    // The day they celebrate in year. Someone born on 29 February has the
+    // birthday on 1 March when there isn't a 29th (as UK law has it, other
+    // places say 28 February).
+    pub fn birthday_in(&self, year: i32) -> Date {
+        let (month, day) = if self.born.is_leap_day() && !is_leap_year(year) {
+            (3, 1)
+        } else {
+            (self.born.month(), self.born.day())
+        };
+        Date::new(year, month, day).expect("a birthday is a real date")
+    }
+
+    // Whole years old on date, None if they hadn't been born
+    pub fn age_on(&self, date: Date) -> Option<u32> {
+        if date < self.born {
+            return None;
+        }
+        let years = date.year() - self.born.year();
+        let had_birthday = date >= self.birthday_in(date.year());
+        Some((years - i32::from(!had_birthday)) as u32)
+    }
+
+    pub fn is_birthday(&self, date: Date) -> bool {
+        date > self.born && date == self.birthday_in(date.year())
+    }
+
+    // The first birthday on or after from (the day they were born doesn't count)
+    pub fn next_birthday(&self, from: Date) -> Date {
+        let from = from.max(self.born.add_days(1));
+        let birthday = self.birthday_in(from.year());
+        if birthday >= from {
+            birthday
+        } else {
+            self.birthday_in(from.year() + 1)
+        }
+    }
+
+    pub fn uses_gmail(&self) -> bool {
+        self.email.ends_with("gmail.com")
+    }
+
+    pub fn uses_yahoo(&self) -> bool {
+        self.email.ends_with("yahoo.com")
+    }
+
+    pub fn set_name(&mut self, new_name: String) {
+        // This is a setter overwriting the name field
+        self.name = new_name;
+    }
+
+    pub fn has_same_name(&self, other: &Human) -> bool {
+        self.name == other.name
+    }
+}
@@ -0,0 +1,9 @@
+// The structs and examples live in the library so they can be used (and
+// tested) elsewhere, main.rs just runs the examples.
+pub mod calendar;
+pub mod directory;
+pub mod examples;
+pub mod family;
+pub mod gate;
+pub mod geometry;
+pub mod human;
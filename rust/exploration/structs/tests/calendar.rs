@@ -0,0 +1,164 @@
+use structs::calendar::{days_in_month, is_leap_year, Date, DateError, Weekday};
+use structs::directory::Directory;
+use structs::human::Human;
+
+fn date(text: &str) -> Date {
+    text.parse().unwrap()
+}
+
+fn born(name: &str, on: &str) -> Human {
+    Human::baby(name.to_string(), date(on))
+}
+
+#[test]
+fn leap_years() {
+    assert!(is_leap_year(2024));
+    assert!(!is_leap_year(2023));
+    assert!(!is_leap_year(1900));
+    assert!(is_leap_year(2000));
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2100, 2), 28);
+    assert_eq!(days_in_month(2023, 12), 31);
+}
+
+#[test]
+fn only_real_dates() {
+    assert_eq!(
+        Date::new(2023, 2, 29),
+        Err(DateError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 29
+        })
+    );
+    assert!(Date::new(2024, 2, 29).is_ok());
+    assert_eq!(Date::new(2024, 13, 1), Err(DateError::InvalidMonth(13)));
+    assert!(Date::new(2024, 4, 0).is_err());
+    assert!(Date::new(2024, 4, 31).is_err());
+    assert_eq!(
+        "2024-2".parse::<Date>(),
+        Err(DateError::Parse("2024-2".to_string()))
+    );
+    assert!("2024-xx-01".parse::<Date>().is_err());
+    assert_eq!(
+        DateError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 29
+        }
+        .to_string(),
+        "there is no day 29 in 2023-02"
+    );
+}
+
+#[test]
+fn day_numbers() {
+    assert_eq!(date("1970-01-01").days(), 0);
+    assert_eq!(date("1969-12-31").days(), -1);
+    assert_eq!(date("2000-03-01").days(), 11_017);
+    assert_eq!(Date::from_days(19_782), date("2024-02-29"));
+    // every day for a few centuries either side of 1970 survives the round
+    // trip and the next day is one more
+    let mut previous = Date::from_days(-150_000);
+    for days in -149_999..150_000 {
+        let date = Date::from_days(days);
+        assert_eq!(date.days(), days);
+        assert!(date > previous);
+        assert_eq!(Date::new(date.year(), date.month(), date.day()), Ok(date));
+        previous = date;
+    }
+}
+
+#[test]
+fn arithmetic_and_weekdays() {
+    assert_eq!(date("2024-02-28").add_days(1), date("2024-02-29"));
+    assert_eq!(date("2023-02-28").add_days(1), date("2023-03-01"));
+    assert_eq!(date("2023-12-31").add_days(1), date("2024-01-01"));
+    assert_eq!(date("2024-01-01").days_until(date("2025-01-01")), 366);
+    assert_eq!(date("2025-01-01").days_until(date("2024-01-01")), -366);
+    assert_eq!(date("1970-01-01").weekday(), Weekday::Thursday);
+    assert_eq!(date("2024-02-29").weekday(), Weekday::Thursday);
+    assert_eq!(date("1969-12-28").weekday(), Weekday::Sunday);
+    assert_eq!(date("2024-02-29").to_string(), "2024-02-29");
+    assert_eq!(Date::new(5, 1, 2).unwrap().to_string(), "0005-01-02");
+    assert!(Date::today() > date("2024-01-01"));
+}
+
+#[test]
+fn ages() {
+    let simon = born("Simon", "1992-07-16");
+    assert_eq!(simon.age_on(date("1992-07-15")), None);
+    assert_eq!(simon.age_on(date("1992-07-16")), Some(0));
+    assert_eq!(simon.age_on(date("2024-07-15")), Some(31));
+    assert_eq!(simon.age_on(date("2024-07-16")), Some(32));
+    assert!(simon.is_birthday(date("2024-07-16")));
+    assert!(!simon.is_birthday(date("1992-07-16")));
+}
+
+#[test]
+fn leap_day_birthdays() {
+    let leap = born("Leap", "2000-02-29");
+    // in common years the birthday is 1 March
+    assert_eq!(leap.birthday_in(2023), date("2023-03-01"));
+    assert_eq!(leap.birthday_in(2024), date("2024-02-29"));
+    assert_eq!(leap.age_on(date("2023-02-28")), Some(22));
+    assert_eq!(leap.age_on(date("2023-03-01")), Some(23));
+    assert_eq!(leap.age_on(date("2024-02-28")), Some(23));
+    assert_eq!(leap.age_on(date("2024-02-29")), Some(24));
+    assert!(!leap.is_birthday(date("2023-02-28")));
+    assert!(leap.is_birthday(date("2023-03-01")));
+    // 2100 isn't a leap year
+    assert_eq!(leap.next_birthday(date("2100-01-01")), date("2100-03-01"));
+}
+
+#[test]
+fn next_birthdays() {
+    let simon = born("Simon", "1992-07-16");
+    assert_eq!(simon.next_birthday(date("2024-07-16")), date("2024-07-16"));
+    assert_eq!(simon.next_birthday(date("2024-07-17")), date("2025-07-16"));
+    // the day they're born isn't a birthday, nor is anything before it
+    assert_eq!(simon.next_birthday(date("1992-07-16")), date("1993-07-16"));
+    assert_eq!(simon.next_birthday(date("1980-01-01")), date("1993-07-16"));
+}
+
+#[test]
+fn directory_queries() {
+    let directory = Directory::new(vec![
+        born("Cat", "1999-12-31"),
+        born("Bob", "2010-01-01"),
+        born("Amy", "1985-01-01"),
+        born("Dan", "2030-06-01"),
+    ]);
+    let today = date("2023-12-25");
+    let upcoming: Vec<(&str, Date, u32)> = directory
+        .upcoming_birthdays(today, 7)
+        .iter()
+        .map(|birthday| {
+            (
+                birthday.human.name.as_str(),
+                birthday.date,
+                birthday.turning,
+            )
+        })
+        .collect();
+    assert_eq!(
+        upcoming,
+        [
+            ("Cat", date("2023-12-31"), 24),
+            ("Amy", date("2024-01-01"), 39),
+            ("Bob", date("2024-01-01"), 14)
+        ]
+    );
+    assert_eq!(directory.upcoming_birthdays(today, 5), []);
+
+    // Dan isn't born yet so isn't counted
+    assert_eq!(directory.ages_on(today), [23, 13, 38]);
+    let distribution = directory.age_distribution(today, 10);
+    assert_eq!(
+        distribution.into_iter().collect::<Vec<_>>(),
+        [(10, 1), (20, 1), (30, 1)]
+    );
+    assert_eq!(directory.average_age(today), Some(74.0 / 3.0));
+    assert_eq!(Directory::default().average_age(today), None);
+    assert_eq!(directory.oldest().unwrap().name, "Amy");
+}
@@ -0,0 +1,8 @@
+// Each file in compile_fail/ tries a gate transition the types rule out and
+// the matching .stderr is the error we expect. After a deliberate change
+// regenerate them with TRYBUILD=overwrite cargo test
+#[test]
+fn invalid_transitions_do_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
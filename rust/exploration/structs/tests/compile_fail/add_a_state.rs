@@ -0,0 +1,12 @@
+use structs::gate::{Gate, State};
+
+// State is sealed, only gate.rs can add states
+struct Ajar;
+
+impl State for Ajar {
+    const NAME: &'static str = "ajar";
+}
+
+fn main() {
+    let _gate: Option<Gate<Ajar>> = None;
+}
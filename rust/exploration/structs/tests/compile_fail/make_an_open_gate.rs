@@ -0,0 +1,11 @@
+use std::marker::PhantomData;
+use structs::gate::{Gate, Open};
+
+fn main() {
+    // the fields are private, the only way to an open gate is to open one
+    let _gate: Gate<Open> = Gate {
+        cars_through: 0,
+        code: 1234,
+        state: PhantomData,
+    };
+}
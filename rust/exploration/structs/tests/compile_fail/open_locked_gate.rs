@@ -0,0 +1,6 @@
+use structs::gate::Gate;
+
+fn main() {
+    let gate = Gate::new(1234).lock();
+    let _gate = gate.open();
+}
@@ -0,0 +1,6 @@
+use structs::gate::Gate;
+
+fn main() {
+    let gate = Gate::new(1234);
+    let _gate = gate.pass_through();
+}
@@ -0,0 +1,8 @@
+use structs::gate::Gate;
+
+fn main() {
+    let gate = Gate::new(1234);
+    let _open = gate.open();
+    // the closed gate was moved into open(), there's only one barrier
+    let _locked = gate.lock();
+}
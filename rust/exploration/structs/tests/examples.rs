@@ -0,0 +1,78 @@
+use describe::Describe;
+use output::Buffer;
+use structs::examples::*;
+use structs::geometry::{Coordinate, Rectangle};
+use structs::human::Human;
+
+#[test]
+fn humans() {
+    let (simon, baby) = instantiating_structs_example(&mut Buffer::new());
+    assert_eq!(
+        simon.describe(),
+        "Simon Darcy-Jones, born 1992-07-16 with email simonwardjones16@gmail.com."
+    );
+    assert_eq!(simon.age_on(today()), Some(31));
+    // the email was made from the name before it changed
+    assert_eq!(
+        baby.describe(),
+        "Tomas, born 2024-02-28 with email no-name@gmail.com."
+    );
+    assert_eq!(baby.age_on(today()), Some(0));
+    assert!(baby.uses_gmail());
+    assert!(!baby.uses_yahoo());
+    assert!(!simon.has_same_name(&baby));
+
+    assert_eq!(field_init_shorthand_example(&mut Buffer::new()), simon);
+    let yahoo = struct_update_syntax_example(&mut Buffer::new());
+    assert!(yahoo.uses_yahoo());
+    assert_eq!(
+        yahoo,
+        Human {
+            email: String::from("simon@yahoo.com"),
+            ..simon
+        }
+    );
+
+    let directory = birthday_example(&mut Buffer::new());
+    assert_eq!(directory.people.len(), 5);
+    assert_eq!(directory.oldest().unwrap().name, "Grace");
+}
+
+#[test]
+fn audited_baby() {
+    let mut baby = audited_example(&mut Buffer::new());
+    assert_eq!(baby.name, "Tomas");
+    assert_eq!(baby.changes().count(), 3);
+    assert!(baby.can_redo());
+    baby.redo();
+    assert!(baby.uses_yahoo());
+}
+
+#[test]
+fn family() {
+    let tree = family_example(&mut Buffer::new());
+    assert_eq!(tree.len(), 6);
+    let simon = tree.find("Simon").unwrap();
+    assert_eq!(simon.cousins(), [tree.find("Leap").unwrap()]);
+}
+
+#[test]
+fn tuple_and_unit_structs() {
+    assert_eq!(
+        tuple_structs_example(&mut Buffer::new()),
+        Coordinate(10, 10)
+    );
+    assert!(unit_struct_example(&mut Buffer::new()));
+    let gate = typestate_example(&mut Buffer::new());
+    assert_eq!((gate.state(), gate.cars_through()), ("closed", 2));
+}
+
+#[test]
+fn geometry() {
+    let (end, overlap) = geometry_example(&mut Buffer::new());
+    assert_eq!(end, Coordinate(7, 0));
+    assert_eq!(
+        overlap,
+        Some(Rectangle::new(Coordinate(3, 0), Coordinate(4, 2)))
+    );
+}
@@ -0,0 +1,443 @@
+use structs::calendar::Date;
+use structs::family::gedcom::{self, GedcomError};
+use structs::family::{FamilyError, FamilyTree, Person, Relationship};
+use structs::human::Human;
+
+fn date(text: &str) -> Date {
+    text.parse().unwrap()
+}
+
+fn names(people: &[Person]) -> Vec<String> {
+    let mut names: Vec<String> = people.iter().map(Person::name).collect();
+    names.sort();
+    names
+}
+
+// Four generations:
+//
+//   Grace + Alan
+//        |
+//   Ada + Bob,  Cat + Dan,  Eve (Grace's with Zed)
+//    |           |
+//   Fay, Gus    Hal
+//    |
+//   Ivy
+fn family() -> FamilyTree {
+    let mut tree = FamilyTree::new();
+    let grace = tree.add(Human::baby("Grace".to_string(), date("1930-03-01")));
+    let alan = tree.add(Human::baby("Alan".to_string(), date("1928-06-23")));
+    let zed = tree.add(Human::baby("Zed".to_string(), date("1929-01-01")));
+    let bob = tree.add(Human::baby("Bob".to_string(), date("1955-01-01")));
+    let dan = tree.add(Human::baby("Dan".to_string(), date("1956-01-01")));
+    let ada = tree
+        .baby("Ada", date("1957-01-01"), &[&grace, &alan])
+        .unwrap();
+    let cat = tree
+        .baby("Cat", date("1959-01-01"), &[&grace, &alan])
+        .unwrap();
+    tree.baby("Eve", date("1965-01-01"), &[&grace, &zed])
+        .unwrap();
+    let fay = tree.baby("Fay", date("1980-01-01"), &[&ada, &bob]).unwrap();
+    tree.baby("Gus", date("1982-01-01"), &[&ada, &bob]).unwrap();
+    tree.baby("Hal", date("1983-01-01"), &[&cat, &dan]).unwrap();
+    tree.baby("Ivy", date("2005-01-01"), &[&fay]).unwrap();
+    tree
+}
+
+fn find(tree: &FamilyTree, name: &str) -> Person {
+    tree.find(name).unwrap()
+}
+
+#[test]
+fn parents_and_children_are_linked_both_ways() {
+    let tree = family();
+    let ada = find(&tree, "Ada");
+    assert_eq!(names(&ada.parents()), ["Alan", "Grace"]);
+    assert_eq!(names(&ada.children()), ["Fay", "Gus"]);
+    assert_eq!(ada.human().email, "Ada@gmail.com");
+    assert_eq!(tree.len(), 12);
+}
+
+#[test]
+fn ancestors_and_descendants() {
+    let tree = family();
+    let ivy = find(&tree, "Ivy");
+    let ancestors: Vec<(String, u32)> = ivy
+        .ancestors()
+        .iter()
+        .map(|(person, generations)| (person.name(), *generations))
+        .collect();
+    assert_eq!(
+        ancestors,
+        [
+            ("Fay".to_string(), 1),
+            ("Ada".to_string(), 2),
+            ("Bob".to_string(), 2),
+            ("Grace".to_string(), 3),
+            ("Alan".to_string(), 3),
+        ]
+    );
+    let grace = find(&tree, "Grace");
+    let descendants: Vec<Person> = grace.descendants().into_iter().map(|(p, _)| p).collect();
+    assert_eq!(
+        names(&descendants),
+        ["Ada", "Cat", "Eve", "Fay", "Gus", "Hal", "Ivy"]
+    );
+    assert!(grace.is_ancestor_of(&ivy));
+    assert!(!ivy.is_ancestor_of(&grace));
+}
+
+#[test]
+fn siblings_and_cousins() {
+    let tree = family();
+    assert_eq!(names(&find(&tree, "Ada").siblings()), ["Cat", "Eve"]);
+    assert_eq!(names(&find(&tree, "Fay").siblings()), ["Gus"]);
+    assert_eq!(names(&find(&tree, "Fay").cousins()), ["Hal"]);
+    assert_eq!(names(&find(&tree, "Hal").cousins()), ["Fay", "Gus"]);
+    assert!(find(&tree, "Grace").siblings().is_empty());
+}
+
+#[test]
+fn relationships() {
+    let tree = family();
+    let between = |a: &str, b: &str| find(&tree, a).relationship_to(&find(&tree, b));
+    assert_eq!(between("Ada", "Ada"), Some(Relationship::Same));
+    assert_eq!(
+        between("Ivy", "Grace"),
+        Some(Relationship::Ancestor { generations: 3 })
+    );
+    assert_eq!(
+        between("Ada", "Fay"),
+        Some(Relationship::Descendant { generations: 1 })
+    );
+    assert_eq!(
+        between("Ada", "Cat"),
+        Some(Relationship::Sibling { half: false })
+    );
+    assert_eq!(
+        between("Ada", "Eve"),
+        Some(Relationship::Sibling { half: true })
+    );
+    assert_eq!(
+        between("Hal", "Ada"),
+        Some(Relationship::AuntOrUncle { generations: 1 })
+    );
+    assert_eq!(
+        between("Ivy", "Cat"),
+        Some(Relationship::AuntOrUncle { generations: 2 })
+    );
+    assert_eq!(
+        between("Eve", "Ivy"),
+        Some(Relationship::NieceOrNephew { generations: 2 })
+    );
+    assert_eq!(
+        between("Fay", "Hal"),
+        Some(Relationship::Cousin {
+            degree: 1,
+            removed: 0
+        })
+    );
+    assert_eq!(
+        between("Hal", "Ivy"),
+        Some(Relationship::Cousin {
+            degree: 1,
+            removed: 1
+        })
+    );
+    // Bob and Dan only married in
+    assert_eq!(between("Bob", "Dan"), None);
+    assert_eq!(between("Bob", "Cat"), None);
+}
+
+#[test]
+fn relationship_names() {
+    let named = |relationship: Relationship| relationship.to_string();
+    assert_eq!(named(Relationship::Ancestor { generations: 1 }), "parent");
+    assert_eq!(
+        named(Relationship::Ancestor { generations: 2 }),
+        "grandparent"
+    );
+    assert_eq!(
+        named(Relationship::Descendant { generations: 4 }),
+        "great-great-grandchild"
+    );
+    assert_eq!(named(Relationship::Sibling { half: true }), "half-sibling");
+    assert_eq!(
+        named(Relationship::AuntOrUncle { generations: 2 }),
+        "great-aunt or great-uncle"
+    );
+    assert_eq!(
+        named(Relationship::NieceOrNephew { generations: 1 }),
+        "niece or nephew"
+    );
+    assert_eq!(
+        named(Relationship::Cousin {
+            degree: 2,
+            removed: 1
+        }),
+        "second cousin once removed"
+    );
+    assert_eq!(
+        named(Relationship::Cousin {
+            degree: 11,
+            removed: 3
+        }),
+        "11th cousin 3 times removed"
+    );
+}
+
+#[test]
+fn links_that_make_no_sense_are_refused() {
+    let mut tree = family();
+    let ada = find(&tree, "Ada");
+    let ivy = find(&tree, "Ivy");
+    let bob = find(&tree, "Bob");
+    assert_eq!(
+        tree.add_parent(&ada, &ada),
+        Err(FamilyError::OwnParent("Ada".to_string()))
+    );
+    assert_eq!(
+        tree.add_parent(&ada, &bob),
+        Err(FamilyError::TooManyParents("Ada".to_string()))
+    );
+    assert_eq!(
+        tree.add_parent(&ivy, &find(&tree, "Fay")),
+        Err(FamilyError::AlreadyParent {
+            parent: "Fay".to_string(),
+            child: "Ivy".to_string()
+        })
+    );
+    // Ivy is Bob's granddaughter so can't also be his mother
+    assert_eq!(
+        tree.add_parent(&bob, &ivy),
+        Err(FamilyError::Cycle {
+            parent: "Ivy".to_string(),
+            child: "Bob".to_string()
+        })
+    );
+    let three = [&ada, &bob, &ivy];
+    assert!(matches!(
+        tree.baby("Jo", date("2020-01-01"), &three),
+        Err(FamilyError::TooManyParents(_))
+    ));
+}
+
+#[test]
+fn a_refused_baby_is_not_added() {
+    let mut tree = family();
+    let ivy = find(&tree, "Ivy");
+    let size = tree.len();
+    assert_eq!(
+        tree.baby("Jo", date("2020-01-01"), &[&ivy, &ivy]),
+        Err(FamilyError::AlreadyParent {
+            parent: "Ivy".to_string(),
+            child: "Jo".to_string()
+        })
+    );
+    assert_eq!(tree.len(), size);
+    assert!(tree.find("Jo").is_none());
+    assert_eq!(ivy.children().len(), 0);
+}
+
+#[test]
+fn people_from_another_tree_are_refused() {
+    let mut tree = family();
+    let mut other = FamilyTree::new();
+    let stranger = other.add(Human::baby("Sam".to_string(), date("1990-05-05")));
+    let ivy = find(&tree, "Ivy");
+    let size = tree.len();
+    assert_eq!(
+        tree.add_parent(&ivy, &stranger),
+        Err(FamilyError::NotInTree("Sam".to_string()))
+    );
+    assert_eq!(
+        tree.add_parent(&stranger, &ivy),
+        Err(FamilyError::NotInTree("Sam".to_string()))
+    );
+    assert_eq!(
+        tree.baby("Jo", date("2020-01-01"), &[&ivy, &stranger]),
+        Err(FamilyError::NotInTree("Sam".to_string()))
+    );
+    assert_eq!(tree.len(), size);
+    assert!(tree.contains(&ivy));
+    assert!(!tree.contains(&stranger));
+    // so every parent has an id when exporting
+    assert!(gedcom::export(&tree).contains("1 NAME Ivy"));
+}
+
+#[test]
+fn counts_follow_the_links() {
+    let tree = family();
+    let grace = find(&tree, "Grace");
+    let ada = find(&tree, "Ada");
+    // the tree, our handle, and Grace and Alan each hold Ada's children list entry
+    assert_eq!(ada.strong_count(), 4);
+    // Fay and Gus look back at Ada weakly
+    assert_eq!(ada.weak_count(), 2);
+    // nobody holds Grace but the tree and us, her three children point up weakly
+    assert_eq!(grace.strong_count(), 2);
+    assert_eq!(grace.weak_count(), 3);
+}
+
+#[test]
+fn dropping_the_tree_frees_everyone() {
+    let tree = family();
+    let everyone: Vec<_> = tree.people().iter().map(Person::downgrade).collect();
+    drop(tree);
+    assert!(everyone.iter().all(|person| person.upgrade().is_none()));
+}
+
+#[test]
+fn a_child_keeps_no_parent_alive() {
+    let tree = family();
+    let ivy = find(&tree, "Ivy");
+    let fay = find(&tree, "Fay").downgrade();
+    drop(tree);
+    // Fay held Ivy strongly, Ivy only points back weakly, so Fay is gone
+    assert!(fay.upgrade().is_none());
+    assert!(ivy.parents().is_empty());
+    assert_eq!(ivy.strong_count(), 1);
+    assert_eq!(ivy.name(), "Ivy");
+}
+
+#[test]
+fn a_parent_keeps_their_children_alive() {
+    let tree = family();
+    let ada = find(&tree, "Ada");
+    let ivy = find(&tree, "Ivy").downgrade();
+    drop(tree);
+    // Ada holds Fay who holds Ivy
+    assert_eq!(ivy.upgrade().unwrap().name(), "Ivy");
+    drop(ada);
+    assert!(ivy.upgrade().is_none());
+}
+
+#[test]
+fn changing_someone_through_any_handle() {
+    let tree = family();
+    find(&tree, "Ada").human_mut().email = "ada@yahoo.com".to_string();
+    let fay = find(&tree, "Fay");
+    let mother = fay
+        .parents()
+        .into_iter()
+        .find(|p| p.name() == "Ada")
+        .unwrap();
+    assert!(mother.human().uses_yahoo());
+}
+
+#[test]
+fn gedcom_round_trip() {
+    let tree = family();
+    let text = gedcom::export(&tree);
+    assert!(text.starts_with("0 HEAD\n"));
+    assert!(text.ends_with("0 TRLR\n"));
+    assert!(text.contains("0 @I1@ INDI\n1 NAME Grace\n1 BIRT\n2 DATE 1 MAR 1930\n"));
+    // Ada and Cat share a family, Eve has her own
+    assert!(text.contains("0 @F1@ FAM\n1 _PARE @I1@\n1 _PARE @I2@\n1 CHIL @I6@\n1 CHIL @I7@\n"));
+    assert!(text.contains("0 @F2@ FAM\n1 _PARE @I1@\n1 _PARE @I3@\n1 CHIL @I8@\n"));
+
+    let copy = gedcom::import(&text).unwrap();
+    assert_eq!(copy.len(), tree.len());
+    for (original, copied) in tree.people().iter().zip(copy.people()) {
+        assert_eq!(*original.human(), *copied.human());
+        assert_eq!(names(&original.parents()), names(&copied.parents()));
+    }
+    assert_eq!(gedcom::export(&copy), text);
+}
+
+#[test]
+fn gedcom_names_with_slashes() {
+    let names = [
+        "AC/DC",
+        "Either/Or Smith",
+        "Ann /Lee/",
+        "Bo /Li/ Jr",
+        "/",
+        "//",
+        "a / b",
+    ];
+    let mut tree = FamilyTree::new();
+    for name in names {
+        tree.add(Human::baby(name.to_string(), date("2000-01-01")));
+    }
+    let text = gedcom::export(&tree);
+    assert!(text.contains("1 NAME AC/DC\n"));
+    assert!(text.contains("1 NAME Ann /Lee/ //\n"));
+    let copy = gedcom::import(&text).unwrap();
+    let copied: Vec<String> = copy
+        .people()
+        .iter()
+        .map(|person| person.human().name.clone())
+        .collect();
+    assert_eq!(copied, names);
+}
+
+#[test]
+fn gedcom_from_elsewhere() {
+    // HUSB and WIFE, a surname in slashes, the family first and tags we skip
+    let text = "\
+0 HEAD
+1 SOUR SomeOtherProgram
+0 @F1@ FAM
+1 HUSB @DAD@
+1 WIFE @MUM@
+1 CHIL @KID@
+1 MARR
+2 DATE 4 JUN 1990
+0 @DAD@ INDI
+1 NAME Tom /Smith/
+1 SEX M
+1 BIRT
+2 DATE 12 jan 1960
+2 PLAC Leeds
+1 FAMS @F1@
+0 @MUM@ INDI
+1 NAME Sue /Smith/
+1 BIRT
+2 DATE 3 FEB 1962
+0 @KID@ INDI
+1 NAME Amy /Smith/
+1 BIRT
+2 DATE 29 FEB 1992
+1 FAMC @F1@
+0 TRLR
+";
+    let tree = gedcom::import(text).unwrap();
+    let amy = find(&tree, "Amy Smith");
+    assert_eq!(names(&amy.parents()), ["Sue Smith", "Tom Smith"]);
+    assert_eq!(amy.human().born, date("1992-02-29"));
+    assert_eq!(amy.human().email, "");
+    assert_eq!(find(&tree, "Tom Smith").human().born, date("1960-01-12"));
+}
+
+#[test]
+fn gedcom_errors() {
+    assert_eq!(
+        gedcom::import("0 HEAD\nnonsense\n").err(),
+        Some(GedcomError::Syntax {
+            line: 2,
+            text: "nonsense".to_string()
+        })
+    );
+    assert_eq!(
+        gedcom::import("0 @I1@ INDI\n1 NAME Ada\n1 BIRT\n2 DATE 30 FEB 1990\n").err(),
+        Some(GedcomError::BadDate {
+            line: 4,
+            text: "30 FEB 1990".to_string()
+        })
+    );
+    assert_eq!(
+        gedcom::import("0 @I1@ INDI\n1 NAME Ada\n").err(),
+        Some(GedcomError::MissingBirth("I1".to_string()))
+    );
+    let orphan = "0 @I1@ INDI\n1 NAME Ada\n1 BIRT\n2 DATE 1 JAN 1990\n0 @F1@ FAM\n1 CHIL @I1@\n1 HUSB @I9@\n";
+    let error = gedcom::import(orphan).err().unwrap();
+    assert_eq!(error.to_string(), "line 7: no one is @I9@");
+    let own_parent = "0 @I1@ INDI\n1 NAME Ada\n1 BIRT\n2 DATE 1 JAN 1990\n0 @F1@ FAM\n1 _PARE @I1@\n1 CHIL @I1@\n";
+    assert_eq!(
+        gedcom::import(own_parent).err(),
+        Some(GedcomError::Family(FamilyError::OwnParent(
+            "Ada".to_string()
+        )))
+    );
+}
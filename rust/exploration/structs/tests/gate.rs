@@ -0,0 +1,72 @@
+use std::mem::size_of;
+use structs::gate::runtime::{GateError, GateState, RuntimeGate};
+use structs::gate::{Closed, Gate, Locked, Open};
+
+#[test]
+fn states_are_free() {
+    assert_eq!(size_of::<Closed>(), 0);
+    assert_eq!(size_of::<Gate<Closed>>(), size_of::<Gate<Open>>());
+    assert_eq!(size_of::<Gate<Open>>(), size_of::<Gate<Locked>>());
+    assert_eq!(size_of::<Gate<Closed>>(), 2 * size_of::<u32>());
+}
+
+#[test]
+fn typestate_gate() {
+    let gate = Gate::new(42);
+    assert_eq!(gate.state(), "closed");
+    let gate = gate.open().pass_through().open().close().open();
+    assert_eq!(gate.state(), "open");
+    let gate = gate.pass_through();
+    assert_eq!(gate.cars_through(), 2);
+
+    let locked = gate.lock();
+    let locked = locked.unlock(41).expect_err("wrong code");
+    assert_eq!(locked.state(), "locked");
+    let gate = locked.unlock(42).expect("right code");
+    assert_eq!(gate.state(), "closed");
+    // the count survives all the changes of type
+    assert_eq!(gate.cars_through(), 2);
+}
+
+#[test]
+fn runtime_gate_follows_the_same_rules() {
+    let mut gate = RuntimeGate::new(42);
+    gate.open().unwrap();
+    gate.pass_through().unwrap();
+    gate.open().unwrap();
+    gate.close().unwrap();
+    assert_eq!(gate.state(), GateState::Closed);
+    assert_eq!(gate.cars_through(), 1);
+
+    gate.lock().unwrap();
+    assert_eq!(gate.unlock(41), Err(GateError::WrongCode));
+    assert_eq!(gate.state(), GateState::Locked);
+    gate.unlock(42).unwrap();
+    assert_eq!(gate.state(), GateState::Closed);
+}
+
+#[test]
+fn runtime_gate_rejects_what_the_typestate_gate_cannot_express() {
+    let mut gate = RuntimeGate::new(42);
+    let error = gate.pass_through().unwrap_err();
+    assert_eq!(
+        error,
+        GateError::InvalidTransition {
+            action: "pass through",
+            state: GateState::Closed
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "can't pass through a gate that is Closed"
+    );
+    assert!(gate.close().is_err());
+    assert!(gate.unlock(42).is_err());
+
+    gate.lock().unwrap();
+    assert!(gate.open().is_err());
+    assert!(gate.lock().is_err());
+    // a failed transition leaves the state alone
+    assert_eq!(gate.state(), GateState::Locked);
+    assert_eq!(gate.cars_through(), 0);
+}
@@ -0,0 +1,183 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use structs::geometry::{Coordinate, Rectangle};
+
+// Property tests: the algebraic laws checked on lots of seeded random values
+// (small enough that nothing overflows) rather than a few picked by hand.
+const CASES: usize = 1_000;
+
+fn coordinate(rng: &mut StdRng) -> Coordinate<i64> {
+    Coordinate(rng.gen_range(-1_000..1_000), rng.gen_range(-1_000..1_000))
+}
+
+fn rectangle(rng: &mut StdRng) -> Rectangle<i64> {
+    // small so random pairs often overlap
+    Rectangle::new(
+        Coordinate(rng.gen_range(-20..20), rng.gen_range(-20..20)),
+        Coordinate(rng.gen_range(-20..20), rng.gen_range(-20..20)),
+    )
+}
+
+fn check(mut law: impl FnMut(&mut StdRng)) {
+    let mut rng = StdRng::seed_from_u64(45);
+    for _ in 0..CASES {
+        law(&mut rng);
+    }
+}
+
+#[test]
+fn addition_is_a_group() {
+    check(|rng| {
+        let (a, b, c) = (coordinate(rng), coordinate(rng), coordinate(rng));
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + Coordinate::ORIGIN, a);
+        assert_eq!(a + -a, Coordinate::ORIGIN);
+        assert_eq!(a - b, a + -b);
+        let mut d = a;
+        d += b;
+        d -= c;
+        assert_eq!(d, a + b - c);
+    });
+}
+
+#[test]
+fn scaling_distributes() {
+    check(|rng| {
+        let (a, b) = (coordinate(rng), coordinate(rng));
+        let (j, k) = (rng.gen_range(-100..100), rng.gen_range(-100..100));
+        assert_eq!((a + b) * k, a * k + b * k);
+        assert_eq!(a * (j + k), a * j + a * k);
+        assert_eq!((a * j) * k, a * (j * k));
+        assert_eq!(a * 1, a);
+        assert_eq!(a * -1, -a);
+    });
+}
+
+#[test]
+fn dot_and_cross_products() {
+    check(|rng| {
+        let (a, b, c) = (coordinate(rng), coordinate(rng), coordinate(rng));
+        let k = rng.gen_range(-100..100);
+        assert_eq!(a.dot(b), b.dot(a));
+        assert_eq!((a + b).dot(c), a.dot(c) + b.dot(c));
+        assert_eq!((a * k).dot(b), a.dot(b) * k);
+        assert!(a.dot(a) >= 0);
+        assert_eq!(a.cross(b), -b.cross(a));
+        assert_eq!(a.cross(a), 0);
+        assert_eq!((a + b).cross(c), a.cross(c) + b.cross(c));
+        // a and a * k are in line
+        assert_eq!(a.cross(a * k), 0);
+    });
+}
+
+#[test]
+fn distances_are_metrics() {
+    check(|rng| {
+        let (a, b, c) = (coordinate(rng), coordinate(rng), coordinate(rng));
+        assert_eq!(a.manhattan_distance(b), b.manhattan_distance(a));
+        assert_eq!(a.manhattan_distance(a), 0);
+        assert!(a.manhattan_distance(c) <= a.manhattan_distance(b) + b.manhattan_distance(c));
+        assert_eq!((a + c).manhattan_distance(b + c), a.manhattan_distance(b));
+
+        let (ab, bc, ac) = (
+            a.euclidean_distance(b),
+            b.euclidean_distance(c),
+            a.euclidean_distance(c),
+        );
+        assert_eq!(ab, b.euclidean_distance(a));
+        assert!(ac <= ab + bc + 1e-9);
+        // the crow never flies further than the taxi drives
+        assert!(ab <= a.manhattan_distance(b) as f64 + 1e-9);
+        assert_eq!(a.euclidean_distance(Coordinate::ORIGIN), a.length());
+    });
+}
+
+#[test]
+fn floats_too() {
+    check(|rng| {
+        let a = Coordinate(rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0));
+        let k: f64 = rng.gen_range(0.0..10.0);
+        assert!(((a * k).length() - a.length() * k).abs() < 1e-9);
+        assert!((a.dot(a) - a.length() * a.length()).abs() < 1e-9);
+    });
+}
+
+#[test]
+fn new_sorts_the_corners() {
+    check(|rng| {
+        let (a, b) = (coordinate(rng), coordinate(rng));
+        let rectangle = Rectangle::new(a, b);
+        assert_eq!(rectangle, Rectangle::new(b, a));
+        assert_eq!(
+            rectangle,
+            Rectangle::new(Coordinate(a.0, b.1), Coordinate(b.0, a.1))
+        );
+        assert!(rectangle.width() >= 0 && rectangle.height() >= 0);
+        assert_eq!(rectangle.area(), rectangle.width() * rectangle.height());
+    });
+}
+
+#[test]
+fn intersection_is_the_points_in_both() {
+    check(|rng| {
+        let (r, s) = (rectangle(rng), rectangle(rng));
+        let p = Coordinate(rng.gen_range(-25..25), rng.gen_range(-25..25));
+        let overlap = r.intersection(&s);
+        assert_eq!(overlap, s.intersection(&r));
+        let in_overlap = overlap.is_some_and(|overlap| overlap.contains(p));
+        assert_eq!(in_overlap, r.contains(p) && s.contains(p));
+        if let Some(overlap) = overlap {
+            assert!(r.contains_rectangle(&overlap) && s.contains_rectangle(&overlap));
+            assert!(overlap.area() <= r.area().min(s.area()));
+            assert!(!overlap.is_empty());
+            // intersecting again changes nothing
+            assert_eq!(overlap.intersection(&r), Some(overlap));
+        }
+    });
+}
+
+#[test]
+fn union_holds_both() {
+    check(|rng| {
+        let (r, s, t) = (rectangle(rng), rectangle(rng), rectangle(rng));
+        let p = Coordinate(rng.gen_range(-25..25), rng.gen_range(-25..25));
+        let union = r.union(&s);
+        assert_eq!(union, s.union(&r));
+        assert_eq!(union.union(&t), r.union(&s.union(&t)));
+        assert_eq!(r.union(&r), r);
+        assert!(union.contains_rectangle(&r) && union.contains_rectangle(&s));
+        if r.contains(p) || s.contains(p) {
+            assert!(union.contains(p));
+        }
+        assert!(union.area() >= r.area().max(s.area()));
+    });
+}
+
+#[test]
+fn moving_a_rectangle() {
+    check(|rng| {
+        let (r, offset) = (rectangle(rng), coordinate(rng));
+        let p = Coordinate(rng.gen_range(-25..25), rng.gen_range(-25..25));
+        let moved = r + offset;
+        assert_eq!(moved.area(), r.area());
+        assert_eq!(moved.min(), r.min() + offset);
+        assert_eq!(moved.contains(p + offset), r.contains(p));
+    });
+}
+
+#[test]
+fn edges() {
+    let square = Rectangle::from_size(2, 2);
+    assert!(square.contains(Coordinate(0, 0)));
+    assert!(square.contains(Coordinate(1, 1)));
+    // max is outside
+    assert!(!square.contains(Coordinate(2, 1)));
+    // sharing an edge isn't overlapping
+    let next_door = square + Coordinate(2, 0);
+    assert_eq!(square.intersection(&next_door), None);
+    assert_eq!(square.union(&next_door), Rectangle::from_size(4, 2));
+    assert!(Rectangle::from_size(0, 5).is_empty());
+    assert_eq!(Coordinate(3, 4).length(), 5.0);
+    assert_eq!(Coordinate(1, 0).cross(Coordinate(0, 1)), 1);
+}